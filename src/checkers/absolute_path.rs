@@ -3,9 +3,10 @@ use std::sync::LazyLock;
 
 use crate::emit;
 use crate::engine::cross_ref::CheckerContext;
+use crate::parser::inside_inline_code;
 use crate::types::{Category, CheckResult, RuleMeta, Severity};
 
-use super::utils::{inside_inline_code, ScopeFilter};
+use super::utils::{ScopeFilter, Span};
 use super::Checker;
 
 pub(crate) struct AbsolutePathChecker {
@@ -38,62 +39,73 @@ fn is_system_path(line: &str, match_start: usize) -> bool {
         .any(|prefix| from_match.starts_with(prefix))
 }
 
-/// Returns true if ALL `~/` on this line are inside URLs or markdown link text.
-fn all_tildes_in_urls(line: &str) -> bool {
-    for (i, _) in line.match_indices("~/") {
-        let before_tilde = &line[..i];
+/// Returns true if the `~/` at byte `i` is inside a URL or markdown link text.
+fn tilde_in_url(line: &str, i: usize) -> bool {
+    let before_tilde = &line[..i];
 
-        let open_brackets = before_tilde.matches('[').count();
-        let close_brackets = before_tilde.matches(']').count();
-        if open_brackets > close_brackets {
-            continue;
-        }
+    let open_brackets = before_tilde.matches('[').count();
+    let close_brackets = before_tilde.matches(']').count();
+    if open_brackets > close_brackets {
+        return true;
+    }
 
-        if let Some(http_pos) = before_tilde
-            .rfind("http://")
-            .or_else(|| before_tilde.rfind("https://"))
-        {
-            if !before_tilde[http_pos..].contains(' ') {
-                let url_portion = &line[http_pos..];
-                if let Some(end_pos) = url_portion.find([' ', ')', ']']) {
-                    if i < http_pos + end_pos {
-                        continue;
-                    }
-                } else {
-                    continue;
-                }
-            }
+    if let Some(http_pos) = before_tilde
+        .rfind("http://")
+        .or_else(|| before_tilde.rfind("https://"))
+    {
+        if !before_tilde[http_pos..].contains(' ') {
+            let url_portion = &line[http_pos..];
+            return match url_portion.find([' ', ')', ']']) {
+                Some(end_pos) => i < http_pos + end_pos,
+                None => true,
+            };
         }
-
-        return false;
     }
-    true
+
+    false
+}
+
+/// Byte offset where the tilde path starting at `start` ends.
+fn path_end(line: &str, start: usize) -> usize {
+    let rest = &line[start..];
+    let path = rest
+        .find(|c: char| c.is_whitespace() || matches!(c, ')' | ']' | '`' | '"' | '\''))
+        .map_or(rest, |end| &rest[..end]);
+    start + path.trim_end_matches(['.', ',', ';', ':']).len()
 }
 
 /// Detect a personal or tilde path on a single line. Returns a message fragment
-/// if found, or `None` if the line is clean.
-fn detect_personal_path(line: &str) -> Option<String> {
+/// and the path's span if found, or `None` if the line is clean.
+fn detect_personal_path(line: &str) -> Option<(String, Span)> {
     if let Some(m) = UNIX_PERSONAL.find(line) {
         if !is_system_path(line, m.start()) && !inside_inline_code(line, m.start()) {
-            return Some(format!("Hardcoded personal path: {}", m.as_str()));
+            return Some((
+                format!("Hardcoded personal path: {}", m.as_str()),
+                Span::of_match(line, &m),
+            ));
         }
     }
     if let Some(m) = WINDOWS_PERSONAL.find(line) {
         if !inside_inline_code(line, m.start()) {
-            return Some(format!("Hardcoded personal path: {}", m.as_str()));
-        }
-    }
-    if line.contains("~/") && !all_tildes_in_urls(line) {
-        // Check if all tilde occurrences are inside inline code or reference
-        // well-known hidden config dirs (~/.config/, ~/.claude/, etc.)
-        let all_benign = line
-            .match_indices("~/")
-            .all(|(i, _)| inside_inline_code(line, i) || line[i..].starts_with("~/."));
-        if !all_benign {
-            return Some("Tilde home path detected".to_string());
+            return Some((
+                format!("Hardcoded personal path: {}", m.as_str()),
+                Span::of_match(line, &m),
+            ));
         }
     }
-    None
+    // Tildes inside URLs, inline code, or well-known hidden config dirs
+    // (~/.config/, ~/.claude/, etc.) are fine.
+    line.match_indices("~/")
+        .map(|(i, _)| i)
+        .find(|&i| {
+            !tilde_in_url(line, i) && !inside_inline_code(line, i) && !line[i..].starts_with("~/.")
+        })
+        .map(|i| {
+            (
+                "Tilde home path detected".to_string(),
+                Span::in_line(line, i, path_end(line, i)),
+            )
+        })
 }
 
 impl Checker for AbsolutePathChecker {
@@ -115,13 +127,14 @@ impl Checker for AbsolutePathChecker {
             }
 
             for (idx, line) in file.non_code_lines() {
-                if let Some(msg) = detect_personal_path(line) {
+                if let Some((msg, span)) = detect_personal_path(line) {
                     emit!(
                         result,
                         file.path,
                         idx + 1,
                         Severity::Warning,
                         Category::AbsolutePath,
+                        span: span,
                         suggest: "Replace with a relative path or environment variable",
                         "{}",
                         msg
@@ -149,6 +162,13 @@ mod tests {
         let result = run_check(&["Use /home/john/project/src for the source"]);
         assert_eq!(result.diagnostics.len(), 1);
         assert!(result.diagnostics[0].message.contains("/home/john"));
+        assert_eq!(
+            (
+                result.diagnostics[0].column,
+                result.diagnostics[0].end_column
+            ),
+            (Some(5), Some(15))
+        );
     }
 
     #[test]
@@ -223,6 +243,13 @@ mod tests {
     fn test_tilde_not_in_url_still_flags() {
         let result = run_check(&["Save to ~/projects and check https://example.com"]);
         assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(
+            (
+                result.diagnostics[0].column,
+                result.diagnostics[0].end_column
+            ),
+            (Some(9), Some(19))
+        );
     }
 
     #[test]
@@ -267,6 +294,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_span_points_at_offending_tilde() {
+        let result = run_check(&["Keep `~/notes` private, then copy ~/Documents/report.pdf."]);
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(
+            (
+                result.diagnostics[0].column,
+                result.diagnostics[0].end_column
+            ),
+            (Some(35), Some(57))
+        );
+    }
+
     #[test]
     fn test_url_containing_users_path() {
         // /Users/ inside a URL is still flagged (it's a personal path pattern)
@@ -280,6 +280,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         }
     }
@@ -301,6 +303,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines,
             in_code_block,
+            frontmatter: None,
         }
     }
 
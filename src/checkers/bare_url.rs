@@ -3,10 +3,10 @@ use std::sync::LazyLock;
 
 use crate::emit;
 use crate::engine::cross_ref::CheckerContext;
-use crate::parser::non_code_lines_masked;
+use crate::parser::{inside_inline_code, non_code_lines_masked};
 use crate::types::{Category, CheckResult, RuleMeta, Severity};
 
-use super::utils::{inside_inline_code, is_heading, ScopeFilter};
+use super::utils::{is_heading, ScopeFilter, Span};
 use super::Checker;
 
 /// Bare URL pattern: http(s) URLs not inside markdown link syntax.
@@ -84,6 +84,7 @@ impl Checker for BareUrlChecker {
                         line_num,
                         Severity::Info,
                         Category::BareUrl,
+                        span: Span::in_line(line, start, start + url.len()),
                         suggest: "Wrap in markdown link: [descriptive text](url)",
                         "bare URL not wrapped in markdown link syntax: {url}"
                     );
@@ -146,6 +147,12 @@ mod tests {
     fn test_multiple_bare_urls() {
         let result = check(&["See https://foo.com and https://bar.com"]);
         assert_eq!(result.diagnostics.len(), 2);
+        let spans: Vec<_> = result
+            .diagnostics
+            .iter()
+            .map(|d| (d.column, d.end_column))
+            .collect();
+        assert_eq!(spans, vec![(Some(5), Some(20)), (Some(25), Some(40))]);
     }
 
     #[test]
@@ -157,5 +164,6 @@ mod tests {
             .contains("https://example.com"));
         // Trailing period should be trimmed from the URL in the message
         assert!(!result.diagnostics[0].message.ends_with('.'));
+        assert_eq!(result.diagnostics[0].end_column, Some(26));
     }
 }
@@ -4,10 +4,11 @@ use std::sync::Arc;
 
 use crate::emit;
 use crate::engine::cross_ref::CheckerContext;
+use crate::parser::types::FileRefKind;
 use crate::types::{Category, CheckResult, RuleMeta, Severity};
 
 use super::utils::{is_template_ref, ScopeFilter};
-use super::Checker;
+use super::{Checker, ProjectInputs};
 
 pub(crate) struct CircularReferenceChecker {
     scope: ScopeFilter,
@@ -50,6 +51,69 @@ enum DfsState {
     Done,
 }
 
+/// A cycle found by [`find_cycles`]: the back edge `node → nodes[0]` closes it.
+pub(super) struct Cycle {
+    /// Node whose outgoing edge closes the cycle.
+    pub node: usize,
+    /// Edge payload of the closing edge (e.g. index into `file_refs`).
+    pub edge: usize,
+    /// Nodes on the cycle, starting at the back-edge target.
+    pub nodes: Vec<usize>,
+}
+
+/// Iterative DFS over an adjacency list of `(target, edge payload)` pairs,
+/// returning one [`Cycle`] per back edge. Shared by `circular_reference`
+/// and `import_chain`.
+pub(super) fn find_cycles(adj: &[Vec<(usize, usize)>]) -> Vec<Cycle> {
+    let n = adj.len();
+    let mut cycles = Vec::new();
+    let mut state = vec![DfsState::Unvisited; n];
+    let mut path: Vec<usize> = Vec::new();
+    let mut call_stack: Vec<(usize, usize)> = Vec::new();
+
+    for start in 0..n {
+        if state[start] != DfsState::Unvisited {
+            continue;
+        }
+
+        state[start] = DfsState::InProgress;
+        path.push(start);
+        call_stack.push((start, 0));
+
+        while let Some((node, next_idx)) = call_stack.last_mut() {
+            let node = *node;
+            if *next_idx >= adj[node].len() {
+                call_stack.pop();
+                path.pop();
+                state[node] = DfsState::Done;
+                continue;
+            }
+
+            let (target, edge) = adj[node][*next_idx];
+            *next_idx += 1;
+
+            match state[target] {
+                DfsState::InProgress => {
+                    let cycle_start = path.iter().position(|&n| n == target).unwrap();
+                    cycles.push(Cycle {
+                        node,
+                        edge,
+                        nodes: path[cycle_start..].to_vec(),
+                    });
+                }
+                DfsState::Unvisited => {
+                    state[target] = DfsState::InProgress;
+                    path.push(target);
+                    call_stack.push((target, 0));
+                }
+                DfsState::Done => {}
+            }
+        }
+    }
+
+    cycles
+}
+
 impl Checker for CircularReferenceChecker {
     fn meta(&self) -> RuleMeta {
         RuleMeta {
@@ -60,6 +124,14 @@ impl Checker for CircularReferenceChecker {
         }
     }
 
+    fn is_project_wide(&self) -> bool {
+        true
+    }
+
+    fn project_inputs(&self) -> ProjectInputs {
+        ProjectInputs::FILES
+    }
+
     fn check(&self, ctx: &CheckerContext) -> CheckResult {
         let mut result = CheckResult::default();
 
@@ -78,7 +150,8 @@ impl Checker for CircularReferenceChecker {
 
         for (src_idx, file) in ctx.files.iter().enumerate() {
             for (ref_idx, file_ref) in file.file_refs.iter().enumerate() {
-                if is_template_ref(&file_ref.path) {
+                // Import cycles are reported by import-chain.
+                if file_ref.kind == FileRefKind::Import || is_template_ref(&file_ref.path) {
                     continue;
                 }
                 if let Some(target_idx) = resolve_ref(
@@ -95,73 +168,37 @@ impl Checker for CircularReferenceChecker {
             }
         }
 
-        let mut state = vec![DfsState::Unvisited; n];
-        let mut path: Vec<usize> = Vec::new();
-        let mut call_stack: Vec<(usize, usize)> = Vec::new();
-
-        for start in 0..n {
-            if state[start] != DfsState::Unvisited {
+        for cycle in find_cycles(&adj) {
+            let file = &ctx.files[cycle.node];
+            if !self.scope.includes(&file.path, &ctx.project_root) {
                 continue;
             }
-
-            state[start] = DfsState::InProgress;
-            path.push(start);
-            call_stack.push((start, 0));
-
-            while let Some((node, next_idx)) = call_stack.last_mut() {
-                let node = *node;
-                if *next_idx >= adj[node].len() {
-                    call_stack.pop();
-                    path.pop();
-                    state[node] = DfsState::Done;
-                    continue;
-                }
-
-                let (target, ref_idx) = adj[node][*next_idx];
-                *next_idx += 1;
-
-                match state[target] {
-                    DfsState::InProgress => {
-                        let file = &ctx.files[node];
-                        if !self.scope.includes(&file.path, &ctx.project_root) {
-                            continue;
-                        }
-                        let file_ref = &file.file_refs[ref_idx];
-
-                        let cycle_start = path.iter().position(|&n| n == target).unwrap();
-                        let cycle_nodes: Vec<String> = path[cycle_start..]
-                            .iter()
-                            .map(|&i| {
-                                ctx.files[i]
-                                    .path
-                                    .file_name()
-                                    .unwrap_or_default()
-                                    .to_string_lossy()
-                                    .to_string()
-                            })
-                            .collect();
-                        let cycle_desc =
-                            format!("{} → {}", cycle_nodes.join(" → "), cycle_nodes[0]);
-
-                        emit!(
-                            result,
-                            Arc::new(file_ref.source_file.clone()),
-                            file_ref.line,
-                            Severity::Warning,
-                            Category::CircularReference,
-                            suggest: "Break the cycle by removing or restructuring one of the references",
-                            "Circular reference chain: {}",
-                            cycle_desc
-                        );
-                    }
-                    DfsState::Unvisited => {
-                        state[target] = DfsState::InProgress;
-                        path.push(target);
-                        call_stack.push((target, 0));
-                    }
-                    DfsState::Done => {}
-                }
-            }
+            let file_ref = &file.file_refs[cycle.edge];
+
+            let cycle_nodes: Vec<String> = cycle
+                .nodes
+                .iter()
+                .map(|&i| {
+                    ctx.files[i]
+                        .path
+                        .file_name()
+                        .unwrap_or_default()
+                        .to_string_lossy()
+                        .to_string()
+                })
+                .collect();
+            let cycle_desc = format!("{} → {}", cycle_nodes.join(" → "), cycle_nodes[0]);
+
+            emit!(
+                result,
+                Arc::new(file_ref.source_file.clone()),
+                file_ref.line,
+                Severity::Warning,
+                Category::CircularReference,
+                suggest: "Break the cycle by removing or restructuring one of the references",
+                "Circular reference chain: {}",
+                cycle_desc
+            );
         }
 
         result
@@ -183,6 +220,7 @@ mod tests {
                 path: r.to_string(),
                 line,
                 source_file: path.clone(),
+                kind: FileRefKind::Mention,
             })
             .collect();
         ParsedFile {
@@ -194,6 +232,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         }
     }
 
@@ -215,6 +254,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -249,6 +290,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -279,6 +322,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -305,6 +350,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -331,6 +378,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -361,6 +410,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -395,6 +446,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -431,6 +484,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -464,11 +519,13 @@ mod tests {
                     path: "sub/b.md".to_string(),
                     line: 2,
                     source_file: a_path.clone(),
+                    kind: FileRefKind::Mention,
                 }],
                 directives: vec![],
                 suppress_comments: vec![],
                 raw_lines: vec![],
                 in_code_block: vec![],
+                frontmatter: None,
             },
             ParsedFile {
                 path: std::sync::Arc::new(b_path.clone()),
@@ -478,11 +535,13 @@ mod tests {
                     path: "a.md".to_string(),
                     line: 2,
                     source_file: b_path.clone(),
+                    kind: FileRefKind::Mention,
                 }],
                 directives: vec![],
                 suppress_comments: vec![],
                 raw_lines: vec![],
                 in_code_block: vec![],
+                frontmatter: None,
             },
         ];
 
@@ -491,6 +550,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -530,6 +591,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -543,6 +606,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_import_refs_skipped() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+
+        fs::write(root.join("a.md"), "@b.md").unwrap();
+        fs::write(root.join("b.md"), "@a.md").unwrap();
+
+        let mut files = vec![
+            make_file(root, "a.md", vec![("b.md", 1)]),
+            make_file(root, "b.md", vec![("a.md", 1)]),
+        ];
+        for file in &mut files {
+            file.file_refs[0].kind = FileRefKind::Import;
+        }
+
+        let ctx = CheckerContext {
+            files,
+            project_root: root.to_path_buf(),
+            canonical_root: None,
+            filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
+            historical_indices: HashSet::new(),
+        };
+
+        let checker = CircularReferenceChecker::new(&[]);
+        let result = checker.check(&ctx);
+
+        assert!(
+            result.diagnostics.is_empty(),
+            "Import cycles are reported by import-chain"
+        );
+    }
+
     #[test]
     fn test_no_files_no_crash() {
         let dir = tempfile::tempdir().unwrap();
@@ -553,6 +651,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -0,0 +1,460 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::engine::cross_ref::CheckerContext;
+use crate::parser::json::{JsonNode, Position};
+use crate::types::{Category, CheckResult, Diagnostic, RuleMeta, Severity};
+
+use super::utils::{is_known_tool, ScopeFilter};
+use super::{Checker, ProjectInputs};
+
+/// Tools for which an unrestricted `allow` grant hands the agent arbitrary
+/// execution, file writes, or network access.
+const SENSITIVE_TOOLS: &[&str] = &[
+    "Bash",
+    "Edit",
+    "MultiEdit",
+    "NotebookEdit",
+    "Write",
+    "WebFetch",
+];
+
+/// Specifiers that match everything.
+const WILDCARD_SPECIFIERS: &[&str] = &["*", "**", ":*", "/**", "**/*", "./**"];
+
+/// Commands that run a script given as their first non-flag argument.
+const INTERPRETERS: &[&str] = &[
+    "bash", "sh", "zsh", "python", "python3", "node", "ruby", "perl", "bun", "deno", "tsx",
+];
+
+pub(crate) struct ClaudeSettingsChecker {
+    scope: ScopeFilter,
+}
+
+impl ClaudeSettingsChecker {
+    pub(crate) fn new(scope_patterns: &[String]) -> Self {
+        Self {
+            scope: ScopeFilter::new(scope_patterns),
+        }
+    }
+}
+
+/// A permission rule split into `Tool(specifier)`.
+struct PermissionRule<'a> {
+    tool: &'a str,
+    specifier: Option<&'a str>,
+}
+
+fn parse_rule(rule: &str) -> Result<PermissionRule<'_>, &'static str> {
+    let rule = rule.trim();
+    if rule.is_empty() {
+        return Err("rule is empty");
+    }
+    let (tool, specifier) = match rule.split_once('(') {
+        None => (rule, None),
+        Some((tool, rest)) => {
+            let Some(spec) = rest.strip_suffix(')') else {
+                return Err("missing closing parenthesis");
+            };
+            if spec.trim().is_empty() {
+                return Err("empty specifier `()`; omit the parentheses to match every use");
+            }
+            (tool, Some(spec))
+        }
+    };
+    if !tool.starts_with(|c: char| c.is_ascii_alphabetic())
+        || !tool.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return Err("expected `Tool` or `Tool(specifier)`");
+    }
+    if tool == "Bash" && specifier.is_some_and(|s| s.contains(":*") && !s.ends_with(":*")) {
+        return Err("the `:*` prefix wildcard is only valid at the end of a Bash rule");
+    }
+    Ok(PermissionRule { tool, specifier })
+}
+
+/// Extract the project-relative script a hook command runs, if it names one.
+/// Handles `$CLAUDE_PROJECT_DIR`-prefixed paths, `./script.sh`, and
+/// `python3 hooks/check.py`-style interpreter invocations.
+fn hook_script(command: &str) -> Option<String> {
+    let mut tokens = command
+        .split_whitespace()
+        .map(|t| t.replace(['"', '\''], ""))
+        .skip_while(|t| t.split('/').next().is_some_and(|head| head.contains('=')));
+    let first = tokens.next()?;
+    let (candidate, via_interpreter) = if INTERPRETERS.contains(&first.as_str()) {
+        let script = tokens.find(|t| !t.starts_with('-') && t != "run")?;
+        (script, true)
+    } else {
+        (first, false)
+    };
+
+    let rel = ["$CLAUDE_PROJECT_DIR/", "${CLAUDE_PROJECT_DIR}/"]
+        .iter()
+        .find_map(|prefix| candidate.strip_prefix(prefix))
+        .unwrap_or(&candidate);
+    let rel = rel.strip_prefix("./").unwrap_or(rel);
+
+    let looks_like_path = rel.contains('/') || (via_interpreter && rel.contains('.'));
+    if !looks_like_path
+        || rel.starts_with(['/', '~'])
+        || rel.contains(['$', '*', '`', ';', '|', '&'])
+    {
+        return None;
+    }
+    Some(rel.to_string())
+}
+
+fn report(
+    result: &mut CheckResult,
+    file: &Arc<PathBuf>,
+    pos: Position,
+    severity: Severity,
+    suggestion: &str,
+    message: String,
+) {
+    result.diagnostics.push(Diagnostic {
+        file: Arc::clone(file),
+        line: pos.line,
+        column: Some(pos.column),
+        end_line: None,
+        end_column: None,
+        severity,
+        category: Category::ClaudeSettings,
+        message,
+        suggestion: Some(suggestion.to_string()),
+        fix: None,
+        related: Vec::new(),
+    });
+}
+
+fn check_permissions(result: &mut CheckResult, file: &Arc<PathBuf>, permissions: &JsonNode) {
+    let list = |key: &str| -> Vec<(&JsonNode, &str)> {
+        permissions
+            .get(key)
+            .and_then(JsonNode::as_array)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|node| node.as_str().map(|s| (node, s.trim())))
+            .collect()
+    };
+
+    for key in ["allow", "deny", "ask"] {
+        let Some(entries) = permissions.get(key).and_then(JsonNode::as_array) else {
+            continue;
+        };
+        for node in entries {
+            let Some(rule) = node.as_str() else {
+                report(
+                    result,
+                    file,
+                    node.pos,
+                    Severity::Error,
+                    "Write the rule as a string such as \"Bash(npm run test:*)\"",
+                    format!("Permission rule in `{key}` must be a string"),
+                );
+                continue;
+            };
+            match parse_rule(rule) {
+                Err(reason) => report(
+                    result,
+                    file,
+                    node.pos,
+                    Severity::Error,
+                    "Use `Tool` or `Tool(specifier)`, e.g. \"Bash(npm run test:*)\"",
+                    format!("Malformed permission rule \"{rule}\": {reason}"),
+                ),
+                Ok(parsed) if !is_known_tool(parsed.tool) => report(
+                    result,
+                    file,
+                    node.pos,
+                    Severity::Warning,
+                    "Check the tool name spelling; built-in tools are PascalCase (e.g. `Bash`, `Read`)",
+                    format!("Permission rule \"{rule}\" names unknown tool `{}`", parsed.tool),
+                ),
+                Ok(_) => {}
+            }
+        }
+    }
+
+    let deny = list("deny");
+    let denied: HashMap<&str, &JsonNode> = deny.iter().map(|&(node, rule)| (rule, node)).collect();
+    let denied_tools: HashMap<&str, &str> = deny
+        .iter()
+        .filter_map(|&(_, rule)| parse_rule(rule).ok().map(|p| (p, rule)))
+        .filter(|(p, _)| {
+            p.specifier
+                .map_or(true, |s| WILDCARD_SPECIFIERS.contains(&s))
+        })
+        .map(|(p, rule)| (p.tool, rule))
+        .collect();
+
+    for (node, rule) in list("allow") {
+        if let Some(deny_node) = denied.get(rule) {
+            report(
+                result,
+                file,
+                node.pos,
+                Severity::Warning,
+                "Remove the rule from either `allow` or `deny`",
+                format!(
+                    "Permission \"{rule}\" is both allowed and denied (deny at line {}); deny wins",
+                    deny_node.pos.line
+                ),
+            );
+            continue;
+        }
+        let Ok(parsed) = parse_rule(rule) else {
+            continue;
+        };
+        if let Some(deny_rule) = denied_tools.get(parsed.tool) {
+            report(
+                result,
+                file,
+                node.pos,
+                Severity::Warning,
+                "Remove the allow rule or narrow the deny rule",
+                format!("Allow rule \"{rule}\" has no effect: \"{deny_rule}\" denies every use of the tool"),
+            );
+            continue;
+        }
+        if SENSITIVE_TOOLS.contains(&parsed.tool)
+            && parsed
+                .specifier
+                .map_or(true, |s| WILDCARD_SPECIFIERS.contains(&s.trim()))
+        {
+            report(
+                result,
+                file,
+                node.pos,
+                Severity::Warning,
+                "Grant specific commands or paths instead, e.g. \"Bash(npm run test:*)\"",
+                format!(
+                    "Overly broad permission \"{rule}\" allows every use of `{}`",
+                    parsed.tool
+                ),
+            );
+        }
+    }
+}
+
+fn check_hooks(
+    result: &mut CheckResult,
+    file: &Arc<PathBuf>,
+    hooks: &JsonNode,
+    ctx: &CheckerContext,
+) {
+    let commands = hooks
+        .as_object()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|event| event.value.as_array())
+        .flatten()
+        .filter_map(|matcher| matcher.get("hooks").and_then(JsonNode::as_array))
+        .flatten()
+        .filter_map(|hook| hook.get("command"));
+
+    for command in commands {
+        let Some(script) = command.as_str().and_then(hook_script) else {
+            continue;
+        };
+        if !ctx.project_root.join(&script).exists() {
+            report(
+                result,
+                file,
+                command.pos,
+                Severity::Error,
+                "Fix the script path or add the missing script",
+                format!("Hook command runs \"{script}\", which does not exist"),
+            );
+        }
+    }
+}
+
+impl Checker for ClaudeSettingsChecker {
+    fn meta(&self) -> RuleMeta {
+        RuleMeta {
+            name: "claude-settings",
+            description: "Validates permission rules and hook commands in .claude/settings.json",
+            default_severity: Severity::Warning,
+            strict_only: false,
+        }
+    }
+
+    fn is_project_wide(&self) -> bool {
+        true
+    }
+
+    fn project_inputs(&self) -> ProjectInputs {
+        ProjectInputs {
+            files: false,
+            settings: true,
+            listing: true,
+        }
+    }
+
+    fn check(&self, ctx: &CheckerContext) -> CheckResult {
+        let mut result = CheckResult::default();
+
+        for settings in &ctx.settings_files {
+            if !self.scope.includes(&settings.path, &ctx.project_root) {
+                continue;
+            }
+            let root = match &settings.root {
+                Ok(root) => root,
+                Err(e) => {
+                    report(
+                        &mut result,
+                        &settings.path,
+                        e.pos,
+                        Severity::Error,
+                        "Fix the JSON syntax; Claude Code ignores settings it cannot parse",
+                        format!("Invalid JSON: {}", e.message),
+                    );
+                    continue;
+                }
+            };
+            if let Some(permissions) = root.get("permissions") {
+                check_permissions(&mut result, &settings.path, permissions);
+            }
+            if let Some(hooks) = root.get("hooks") {
+                check_hooks(&mut result, &settings.path, hooks, ctx);
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::json::parse_json;
+    use crate::parser::types::SettingsFile;
+    use std::collections::HashSet;
+    use std::path::Path;
+
+    fn make_ctx(root: &Path, json: &str) -> CheckerContext {
+        CheckerContext {
+            files: vec![],
+            settings_files: vec![SettingsFile {
+                path: Arc::new(root.join(".claude/settings.json")),
+                root: parse_json(json),
+            }],
+            project_root: root.to_path_buf(),
+            canonical_root: None,
+            filename_index: HashSet::new(),
+            path_index: vec![],
+            historical_indices: HashSet::new(),
+        }
+    }
+
+    fn check(json: &str) -> Vec<Diagnostic> {
+        ClaudeSettingsChecker::new(&[])
+            .check(&make_ctx(Path::new("/project"), json))
+            .diagnostics
+    }
+
+    #[test]
+    fn test_valid_permissions_pass() {
+        let diags = check(
+            r#"{"permissions": {"allow": ["Bash(npm run test:*)", "Read", "mcp__github__list_issues"], "deny": ["Read(./.env)"]}}"#,
+        );
+        assert!(diags.is_empty(), "unexpected: {diags:?}");
+    }
+
+    #[test]
+    fn test_invalid_json_reports_position() {
+        let diags = check("{\n  \"permissions\": {\n    \"allow\": [\"Read\",]\n  }\n}");
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].severity, Severity::Error);
+        assert_eq!((diags[0].line, diags[0].column), (3, Some(22)));
+        assert!(diags[0].message.starts_with("Invalid JSON"));
+    }
+
+    #[test]
+    fn test_malformed_rules() {
+        let diags = check(
+            "{\"permissions\": {\"allow\": [\n  \"Bash(npm run test:*\",\n  \"Bash()\",\n  \"Bash(git:* push)\",\n  42\n]}}",
+        );
+        assert_eq!(diags.len(), 4);
+        assert!(diags.iter().all(|d| d.severity == Severity::Error));
+        assert!(diags[0].message.contains("missing closing parenthesis"));
+        assert_eq!((diags[0].line, diags[0].column), (2, Some(3)));
+        assert!(diags[1].message.contains("empty specifier"));
+        assert!(diags[2].message.contains("`:*`"));
+        assert!(diags[3].message.contains("must be a string"));
+    }
+
+    #[test]
+    fn test_unknown_tool() {
+        let diags = check(r#"{"permissions": {"deny": ["bash(rm:*)"]}}"#);
+        assert_eq!(diags.len(), 1);
+        assert!(diags[0].message.contains("unknown tool `bash`"));
+    }
+
+    #[test]
+    fn test_allow_and_deny_contradict() {
+        let diags = check(
+            "{\"permissions\": {\n  \"allow\": [\"Bash(git push:*)\"],\n  \"deny\": [\"Bash(git push:*)\"]\n}}",
+        );
+        assert_eq!(diags.len(), 1);
+        assert!(diags[0]
+            .message
+            .contains("both allowed and denied (deny at line 3)"));
+        assert_eq!(diags[0].line, 2);
+    }
+
+    #[test]
+    fn test_allow_shadowed_by_tool_wide_deny() {
+        let diags = check(
+            r#"{"permissions": {"allow": ["WebFetch(domain:docs.rs)"], "deny": ["WebFetch"]}}"#,
+        );
+        assert_eq!(diags.len(), 1);
+        assert!(diags[0].message.contains("has no effect"));
+    }
+
+    #[test]
+    fn test_overly_broad_grants() {
+        let diags =
+            check(r#"{"permissions": {"allow": ["Bash(*)", "Write", "Read", "Bash(:*)"]}}"#);
+        let msgs: Vec<_> = diags.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(msgs.len(), 3, "{msgs:?}");
+        assert!(msgs.iter().all(|m| m.contains("Overly broad")));
+    }
+
+    #[test]
+    fn test_hook_script_missing_and_present() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join(".claude/hooks")).unwrap();
+        std::fs::write(dir.path().join(".claude/hooks/format.sh"), "").unwrap();
+        let json = r#"{"hooks": {"PostToolUse": [{"matcher": "Edit", "hooks": [
+            {"type": "command", "command": "\"$CLAUDE_PROJECT_DIR\"/.claude/hooks/format.sh"},
+            {"type": "command", "command": "python3 .claude/hooks/lint.py --fix"},
+            {"type": "command", "command": "npx prettier --write ."}
+        ]}]}}"#;
+        let diags = ClaudeSettingsChecker::new(&[])
+            .check(&make_ctx(dir.path(), json))
+            .diagnostics;
+        assert_eq!(diags.len(), 1, "{diags:?}");
+        assert!(diags[0].message.contains(".claude/hooks/lint.py"));
+        assert_eq!(diags[0].line, 3);
+    }
+
+    #[test]
+    fn test_hook_script_extraction() {
+        assert_eq!(
+            hook_script("./scripts/check.sh"),
+            Some("scripts/check.sh".into())
+        );
+        assert_eq!(
+            hook_script("FOO=1 bash -e ${CLAUDE_PROJECT_DIR}/hooks/a.sh"),
+            Some("hooks/a.sh".into())
+        );
+        assert_eq!(hook_script("node hook.js"), Some("hook.js".into()));
+        assert_eq!(hook_script("/usr/local/bin/guard"), None);
+        assert_eq!(hook_script("jq -r .tool_input"), None);
+        assert_eq!(hook_script("~/bin/notify.sh"), None);
+    }
+}
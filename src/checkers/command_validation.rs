@@ -7,7 +7,7 @@ use crate::engine::cross_ref::CheckerContext;
 use crate::types::{Category, CheckResult, RuleMeta, Severity};
 
 use super::utils::ScopeFilter;
-use super::Checker;
+use super::{Checker, ProjectInputs};
 
 pub(crate) struct CommandValidationChecker {
     scope: ScopeFilter,
@@ -261,6 +261,14 @@ impl Checker for CommandValidationChecker {
         }
     }
 
+    fn is_project_wide(&self) -> bool {
+        true
+    }
+
+    fn project_inputs(&self) -> ProjectInputs {
+        ProjectInputs::FILES_AND_LISTING
+    }
+
     fn check(&self, ctx: &CheckerContext) -> CheckResult {
         let mut result = CheckResult::default();
 
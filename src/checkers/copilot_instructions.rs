@@ -0,0 +1,190 @@
+use std::sync::Arc;
+
+use globset::Glob;
+
+use crate::emit;
+use crate::engine::cross_ref::CheckerContext;
+use crate::types::{Category, CheckResult, RuleMeta, Severity};
+
+use super::utils::{copilot_apply_to, glob_matches_any, is_copilot_instructions, ScopeFilter};
+use super::{Checker, ProjectInputs};
+
+pub(crate) struct CopilotInstructionsChecker {
+    scope: ScopeFilter,
+}
+
+impl CopilotInstructionsChecker {
+    pub(crate) fn new(scope_patterns: &[String]) -> Self {
+        Self {
+            scope: ScopeFilter::new(scope_patterns),
+        }
+    }
+}
+
+impl Checker for CopilotInstructionsChecker {
+    fn meta(&self) -> RuleMeta {
+        RuleMeta {
+            name: "copilot-instructions",
+            description:
+                "Validates `applyTo` globs in Copilot .github/instructions/*.instructions.md files",
+            default_severity: Severity::Warning,
+            strict_only: false,
+        }
+    }
+
+    fn is_project_wide(&self) -> bool {
+        true
+    }
+
+    fn project_inputs(&self) -> ProjectInputs {
+        ProjectInputs::FILES_AND_LISTING
+    }
+
+    fn check(&self, ctx: &CheckerContext) -> CheckResult {
+        let mut result = CheckResult::default();
+
+        for file in &ctx.files {
+            if !is_copilot_instructions(&file.path, &ctx.project_root)
+                || !self.scope.includes(&file.path, &ctx.project_root)
+            {
+                continue;
+            }
+
+            let Some((line, globs)) =
+                copilot_apply_to(file, &ctx.project_root).filter(|(_, g)| !g.is_empty())
+            else {
+                emit!(
+                    result,
+                    file.path,
+                    1,
+                    Severity::Warning,
+                    Category::CopilotInstructions,
+                    suggest: "Add `applyTo: \"**/*.ts\"` (or `\"**\"` for every file) to the frontmatter",
+                    "Instructions file has no `applyTo` and is never applied automatically"
+                );
+                continue;
+            };
+
+            for pattern in &globs {
+                match Glob::new(pattern) {
+                    Err(e) => {
+                        emit!(
+                            result,
+                            Arc::clone(&file.path),
+                            line,
+                            Severity::Error,
+                            Category::CopilotInstructions,
+                            suggest: "Fix the glob syntax",
+                            "Invalid applyTo glob \"{}\": {}",
+                            pattern,
+                            e.kind()
+                        );
+                    }
+                    Ok(glob) => {
+                        if !glob_matches_any(&glob.compile_matcher(), &ctx.path_index) {
+                            emit!(
+                                result,
+                                Arc::clone(&file.path),
+                                line,
+                                Severity::Warning,
+                                Category::CopilotInstructions,
+                                suggest: "Update the glob to match existing files or remove it",
+                                "applyTo glob \"{}\" matches no files in the project",
+                                pattern
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::types::ParsedFile;
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    fn check(rel: &str, content: &str, path_index: &[&str]) -> Vec<String> {
+        let raw_lines: Vec<String> = content.lines().map(String::from).collect();
+        let in_code_block = crate::parser::build_code_block_mask(&raw_lines);
+        let ctx = CheckerContext {
+            files: vec![ParsedFile {
+                path: Arc::new(PathBuf::from("/project").join(rel)),
+                sections: vec![],
+                tables: vec![],
+                file_refs: vec![],
+                directives: vec![],
+                suppress_comments: vec![],
+                frontmatter: crate::parser::frontmatter::parse_frontmatter(&raw_lines),
+                raw_lines,
+                in_code_block,
+            }],
+            settings_files: vec![],
+            project_root: PathBuf::from("/project"),
+            canonical_root: None,
+            filename_index: HashSet::new(),
+            path_index: path_index.iter().map(PathBuf::from).collect(),
+            historical_indices: HashSet::new(),
+        };
+        CopilotInstructionsChecker::new(&[])
+            .check(&ctx)
+            .diagnostics
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_apply_to_passes() {
+        let msgs = check(
+            ".github/instructions/ts.instructions.md",
+            "---\napplyTo: \"**/*.ts,**/*.{tsx,jsx}\"\n---\nUse strict mode.\n",
+            &["src/app.ts", "src/view.tsx"],
+        );
+        assert!(msgs.is_empty(), "unexpected: {msgs:?}");
+    }
+
+    #[test]
+    fn test_missing_apply_to() {
+        let msgs = check(
+            ".github/instructions/ts.instructions.md",
+            "Use strict mode.\n",
+            &[],
+        );
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("no `applyTo`"));
+    }
+
+    #[test]
+    fn test_apply_to_matches_nothing() {
+        let msgs = check(
+            ".github/instructions/py.instructions.md",
+            "---\napplyTo: \"**/*.py, src/**/*.ts\"\n---\n",
+            &["src/app.ts"],
+        );
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("\"**/*.py\" matches no files"));
+    }
+
+    #[test]
+    fn test_invalid_apply_to_glob() {
+        let msgs = check(
+            ".github/instructions/bad.instructions.md",
+            "---\napplyTo: \"src/[a-\"\n---\n",
+            &["src/a.rs"],
+        );
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("Invalid applyTo glob"));
+    }
+
+    #[test]
+    fn test_other_files_ignored() {
+        let msgs = check(".github/copilot-instructions.md", "# Repo\n", &[]);
+        assert!(msgs.is_empty());
+    }
+}
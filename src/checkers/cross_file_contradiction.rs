@@ -1,10 +1,16 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use globset::{Glob, GlobMatcher};
 use rayon::prelude::*;
 
 use crate::engine::cross_ref::CheckerContext;
-use crate::types::{Category, CheckResult, Diagnostic, RuleMeta, Severity};
+use crate::types::{Category, CheckResult, Diagnostic, RelatedLocation, RuleMeta, Severity};
 
-use super::utils::{match_conflict_patterns, ScopeFilter, CONFLICT_PAIRS};
-use super::Checker;
+use super::utils::{
+    copilot_apply_to, glob_matches_any, match_conflict_patterns, ScopeFilter, CONFLICT_PAIRS,
+};
+use super::{Checker, ProjectInputs};
 
 pub(crate) struct CrossFileContradictionChecker {
     scope: ScopeFilter,
@@ -32,6 +38,17 @@ fn is_ancestor_descendant(ancestor: &std::path::Path, descendant: &std::path::Pa
     descendant_dir != ancestor_dir && descendant_dir.starts_with(ancestor_dir)
 }
 
+/// Indices into `path_index` of the project files an `applyTo` list covers,
+/// i.e. the files Copilot would load the instruction file for.
+fn apply_to_targets(globs: &[GlobMatcher], path_index: &[PathBuf]) -> HashSet<usize> {
+    path_index
+        .iter()
+        .enumerate()
+        .filter(|(_, rel)| globs.iter().any(|g| glob_matches_any(g, [rel])))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
 impl Checker for CrossFileContradictionChecker {
     fn meta(&self) -> RuleMeta {
         RuleMeta {
@@ -42,6 +59,14 @@ impl Checker for CrossFileContradictionChecker {
         }
     }
 
+    fn is_project_wide(&self) -> bool {
+        true
+    }
+
+    fn project_inputs(&self) -> ProjectInputs {
+        ProjectInputs::FILES_AND_LISTING
+    }
+
     fn check(&self, ctx: &CheckerContext) -> CheckResult {
         let mut result = CheckResult::default();
 
@@ -74,6 +99,23 @@ impl Checker for CrossFileContradictionChecker {
             })
             .collect();
 
+        // Project files covered by the `applyTo` globs of Copilot
+        // path-specific instruction files, computed once per file.
+        let apply_to: Vec<Option<HashSet<usize>>> = ctx
+            .files
+            .iter()
+            .map(|f| {
+                copilot_apply_to(f, &ctx.project_root).map(|(_, globs)| {
+                    let matchers: Vec<GlobMatcher> = globs
+                        .iter()
+                        .filter_map(|g| Glob::new(g).ok())
+                        .map(|g| g.compile_matcher())
+                        .collect();
+                    apply_to_targets(&matchers, &ctx.path_index)
+                })
+            })
+            .collect();
+
         // Generate all (i, j) pairs where i < j and both are in scope
         let pairs: Vec<(usize, usize)> = (0..ctx.files.len())
             .filter(|&i| self.scope.includes(&ctx.files[i].path, &ctx.project_root))
@@ -87,8 +129,14 @@ impl Checker for CrossFileContradictionChecker {
         let pair_diagnostics: Vec<Diagnostic> = pairs
             .par_iter()
             .filter_map(|&(i, j)| {
-                // Only compare ancestor-descendant pairs
-                if !is_ancestor_descendant(&ctx.files[i].path, &ctx.files[j].path)
+                // Only compare files that can be loaded together: ancestor-descendant
+                // pairs, and Copilot instructions whose `applyTo` globs overlap.
+                let overlapping = match (&apply_to[i], &apply_to[j]) {
+                    (Some(a), Some(b)) => !a.is_disjoint(b),
+                    _ => false,
+                };
+                if !overlapping
+                    && !is_ancestor_descendant(&ctx.files[i].path, &ctx.files[j].path)
                     && !is_ancestor_descendant(&ctx.files[j].path, &ctx.files[i].path)
                 {
                     return None;
@@ -163,6 +211,11 @@ impl Checker for CrossFileContradictionChecker {
                                     "Resolve the contradiction or add a comment explaining the intentional override".to_string(),
                                 ),
                                 fix: None,
+                                related: vec![RelatedLocation {
+                                    file: ctx.files[j].path.clone(),
+                                    line: *line_j,
+                                    end_line: None,
+                                }],
                             });
                         }
                     }
@@ -194,17 +247,28 @@ mod tests {
             file_refs: vec![],
             directives: vec![],
             suppress_comments: vec![],
+            frontmatter: crate::parser::frontmatter::parse_frontmatter(&raw_lines),
             raw_lines,
             in_code_block,
         }
     }
 
     fn run_check(files: Vec<ParsedFile>, root: &std::path::Path) -> CheckResult {
+        run_check_indexed(files, root, &[])
+    }
+
+    fn run_check_indexed(
+        files: Vec<ParsedFile>,
+        root: &std::path::Path,
+        path_index: &[&str],
+    ) -> CheckResult {
         let ctx = CheckerContext {
             files,
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: path_index.iter().map(PathBuf::from).collect(),
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
         CrossFileContradictionChecker::new(&[]).check(&ctx)
@@ -375,4 +439,65 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_overlapping_apply_to_contradiction() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let files = vec![
+            make_file(
+                root,
+                ".github/instructions/frontend.instructions.md",
+                &[
+                    "---",
+                    "applyTo: \"src/**/*.ts\"",
+                    "---",
+                    "Always use formal tone.",
+                ],
+            ),
+            make_file(
+                root,
+                ".github/instructions/ui.instructions.md",
+                &[
+                    "---",
+                    "applyTo: \"**/*.ts\"",
+                    "---",
+                    "Keep it casual and friendly.",
+                ],
+            ),
+        ];
+        let result = run_check_indexed(files, root, &["src/app.ts"]);
+        assert_eq!(result.diagnostics.len(), 1);
+        assert!(result.diagnostics[0].message.contains("tone"));
+    }
+
+    #[test]
+    fn test_disjoint_apply_to_no_flag() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let files = vec![
+            make_file(
+                root,
+                ".github/instructions/ts.instructions.md",
+                &[
+                    "---",
+                    "applyTo: \"**/*.ts\"",
+                    "---",
+                    "Always use formal tone.",
+                ],
+            ),
+            make_file(
+                root,
+                ".github/instructions/py.instructions.md",
+                &[
+                    "---",
+                    "applyTo: \"**/*.py\"",
+                    "---",
+                    "Keep it casual and friendly.",
+                ],
+            ),
+        ];
+        let result = run_check_indexed(files, root, &["src/app.ts", "tools/gen.py"]);
+        assert!(result.diagnostics.is_empty());
+    }
 }
@@ -0,0 +1,258 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use globset::Glob;
+
+use crate::emit;
+use crate::engine::cross_ref::CheckerContext;
+use crate::types::{Category, CheckResult, RuleMeta, Severity};
+
+use super::utils::{glob_matches_any, ScopeFilter};
+use super::{Checker, ProjectInputs};
+
+pub(crate) struct CursorRuleChecker {
+    scope: ScopeFilter,
+}
+
+impl CursorRuleChecker {
+    pub(crate) fn new(scope_patterns: &[String]) -> Self {
+        Self {
+            scope: ScopeFilter::new(scope_patterns),
+        }
+    }
+}
+
+fn is_mdc(path: &Path) -> bool {
+    path.extension().is_some_and(|e| e == "mdc")
+}
+
+impl Checker for CursorRuleChecker {
+    fn meta(&self) -> RuleMeta {
+        RuleMeta {
+            name: "cursor-rule",
+            description: "Validates Cursor .mdc rule frontmatter (description, globs, alwaysApply)",
+            default_severity: Severity::Warning,
+            strict_only: false,
+        }
+    }
+
+    fn is_project_wide(&self) -> bool {
+        true
+    }
+
+    fn project_inputs(&self) -> ProjectInputs {
+        ProjectInputs::FILES_AND_LISTING
+    }
+
+    fn check(&self, ctx: &CheckerContext) -> CheckResult {
+        let mut result = CheckResult::default();
+
+        for file in &ctx.files {
+            if !is_mdc(&file.path) || !self.scope.includes(&file.path, &ctx.project_root) {
+                continue;
+            }
+
+            let description = file
+                .frontmatter_get("description")
+                .filter(|e| !e.value.is_empty());
+            let globs = file
+                .frontmatter_get("globs")
+                .filter(|e| !e.value.is_empty());
+            let always_apply = file
+                .frontmatter_get("alwaysApply")
+                .filter(|e| e.value.as_bool() == Some(true));
+
+            if description.is_none() && globs.is_none() && always_apply.is_none() {
+                emit!(
+                    result,
+                    file.path.clone(),
+                    1,
+                    Severity::Warning,
+                    Category::CursorRule,
+                    suggest: "Add `alwaysApply: true`, a `globs` pattern, or a `description` so Cursor knows when to apply this rule",
+                    "Rule has no description, globs or alwaysApply and will never be applied"
+                );
+                continue;
+            }
+
+            let Some(globs) = globs else {
+                continue;
+            };
+
+            if let Some(always) = always_apply {
+                emit!(
+                    result,
+                    file.path.clone(),
+                    always.line,
+                    Severity::Warning,
+                    Category::CursorRule,
+                    suggest: "Remove `globs` or set `alwaysApply: false`",
+                    "Rule is alwaysApply but also sets globs; the globs are ignored"
+                );
+            }
+
+            for pattern in globs.value.items() {
+                match Glob::new(pattern) {
+                    Err(e) => {
+                        emit!(
+                            result,
+                            Arc::clone(&file.path),
+                            globs.line,
+                            Severity::Error,
+                            Category::CursorRule,
+                            suggest: "Fix the glob syntax",
+                            "Invalid glob \"{}\": {}",
+                            pattern,
+                            e.kind()
+                        );
+                    }
+                    Ok(glob) => {
+                        if !glob_matches_any(&glob.compile_matcher(), &ctx.path_index) {
+                            emit!(
+                                result,
+                                Arc::clone(&file.path),
+                                globs.line,
+                                Severity::Warning,
+                                Category::CursorRule,
+                                suggest: "Update the glob to match existing files or remove it",
+                                "Glob \"{}\" matches no files in the project",
+                                pattern
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::types::ParsedFile;
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    fn make_ctx(name: &str, content: &str, path_index: &[&str]) -> CheckerContext {
+        let raw_lines: Vec<String> = content.lines().map(String::from).collect();
+        let in_code_block = crate::parser::build_code_block_mask(&raw_lines);
+        CheckerContext {
+            files: vec![ParsedFile {
+                path: Arc::new(PathBuf::from(format!("/project/.cursor/rules/{name}"))),
+                sections: vec![],
+                tables: vec![],
+                file_refs: vec![],
+                directives: vec![],
+                suppress_comments: vec![],
+                frontmatter: crate::parser::frontmatter::parse_frontmatter(&raw_lines),
+                raw_lines,
+                in_code_block,
+            }],
+            project_root: PathBuf::from("/project"),
+            canonical_root: None,
+            filename_index: HashSet::new(),
+            path_index: path_index.iter().map(PathBuf::from).collect(),
+            settings_files: vec![],
+            historical_indices: HashSet::new(),
+        }
+    }
+
+    fn check(name: &str, content: &str, path_index: &[&str]) -> Vec<String> {
+        CursorRuleChecker::new(&[])
+            .check(&make_ctx(name, content, path_index))
+            .diagnostics
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_rule_passes() {
+        let msgs = check(
+            "rust.mdc",
+            "---\ndescription: Rust conventions\nglobs: src/**/*.rs\nalwaysApply: false\n---\n# Rust\n",
+            &["src/main.rs"],
+        );
+        assert!(msgs.is_empty(), "unexpected: {msgs:?}");
+    }
+
+    #[test]
+    fn test_always_apply_only_passes() {
+        let msgs = check("core.mdc", "---\nalwaysApply: true\n---\n# Core\n", &[]);
+        assert!(msgs.is_empty());
+    }
+
+    #[test]
+    fn test_rule_never_applied() {
+        let msgs = check("dead.mdc", "---\nalwaysApply: false\n---\n# Dead\n", &[]);
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("will never be applied"));
+    }
+
+    #[test]
+    fn test_rule_without_frontmatter_never_applied() {
+        let msgs = check("bare.mdc", "# Bare rule\n", &[]);
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("will never be applied"));
+    }
+
+    #[test]
+    fn test_always_apply_with_globs() {
+        let msgs = check(
+            "both.mdc",
+            "---\nglobs: \"*.ts\"\nalwaysApply: true\n---\n",
+            &["index.ts"],
+        );
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("globs are ignored"));
+    }
+
+    #[test]
+    fn test_invalid_glob() {
+        let ctx = make_ctx("bad.mdc", "---\nglobs: src/[abc\n---\n", &["src/a"]);
+        let diags = CursorRuleChecker::new(&[]).check(&ctx).diagnostics;
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].severity, Severity::Error);
+        assert_eq!(diags[0].line, 2);
+        assert!(diags[0].message.contains("Invalid glob \"src/[abc\""));
+    }
+
+    #[test]
+    fn test_brace_alternatives_glob() {
+        let msgs = check(
+            "ts.mdc",
+            "---\nglobs: src/**/*.{ts,tsx}\n---\n",
+            &["src/app/view.tsx"],
+        );
+        assert!(msgs.is_empty(), "{msgs:?}");
+    }
+
+    #[test]
+    fn test_glob_matching_nothing() {
+        let msgs = check(
+            "py.mdc",
+            "---\nglobs: [\"**/*.py\", \"src/**/*.rs\"]\n---\n",
+            &["src/lib.rs"],
+        );
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("Glob \"**/*.py\" matches no files"));
+    }
+
+    #[test]
+    fn test_basename_glob_matches_nested_file() {
+        let msgs = check(
+            "ts.mdc",
+            "---\nglobs: *.tsx\n---\n",
+            &["web/components/App.tsx"],
+        );
+        assert!(msgs.is_empty(), "unexpected: {msgs:?}");
+    }
+
+    #[test]
+    fn test_non_mdc_files_ignored() {
+        let msgs = check("README.md", "# Plain markdown\n", &[]);
+        assert!(msgs.is_empty());
+    }
+}
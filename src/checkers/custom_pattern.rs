@@ -1,11 +1,13 @@
-use regex::RegexBuilder;
+use regex::{Regex, RegexBuilder};
+use std::collections::HashSet;
+use std::sync::Arc;
 
-use crate::config::CustomPattern;
-use crate::emit;
+use crate::config::{CustomPattern, PatternContext};
 use crate::engine::cross_ref::CheckerContext;
-use crate::types::{Category, CheckResult, RuleMeta, Severity};
+use crate::parser::types::ParsedFile;
+use crate::types::{Category, CheckResult, Diagnostic, Fix, Replacement, RuleMeta, Severity};
 
-use super::utils::REGEX_SIZE_LIMIT;
+use super::utils::{is_heading, ScopeFilter, REGEX_SIZE_LIMIT};
 use super::Checker;
 
 pub(crate) struct CustomPatternChecker {
@@ -14,9 +16,35 @@ pub(crate) struct CustomPatternChecker {
 
 struct CompiledPattern {
     name: String,
-    regex: regex::Regex,
+    regex: Regex,
     severity: Severity,
     message: String,
+    frontmatter_key: Option<String>,
+    context: Option<PatternContext>,
+    languages: Vec<String>,
+    files: ScopeFilter,
+    unless: Vec<Regex>,
+    multiline: bool,
+    replace: Option<String>,
+}
+
+/// What a line is, for matching against `where`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineKind<'a> {
+    /// Frontmatter and fence markers, never matched.
+    Skip,
+    Prose,
+    Heading,
+    Table,
+    /// Inside a fenced block tagged with this language (empty if untagged).
+    Code(&'a str),
+}
+
+fn compile(pattern: &str, multiline: bool) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .multi_line(multiline)
+        .build()
 }
 
 impl CustomPatternChecker {
@@ -24,15 +52,27 @@ impl CustomPatternChecker {
         let patterns = configs
             .iter()
             .filter_map(|c| {
-                match RegexBuilder::new(&c.pattern)
-                    .size_limit(REGEX_SIZE_LIMIT)
-                    .build()
-                {
-                    Ok(regex) => Some(CompiledPattern {
+                let compiled = compile(&c.pattern, c.multiline).and_then(|regex| {
+                    let unless = c
+                        .unless
+                        .iter()
+                        .map(|u| compile(u, false))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok((regex, unless))
+                });
+                match compiled {
+                    Ok((regex, unless)) => Some(CompiledPattern {
                         name: c.name.clone(),
                         regex,
                         severity: c.severity,
                         message: c.message.clone(),
+                        frontmatter_key: c.frontmatter_key.clone(),
+                        context: c.context,
+                        languages: c.languages.clone(),
+                        files: ScopeFilter::new(&c.files),
+                        unless,
+                        multiline: c.multiline,
+                        replace: c.replace.clone(),
                     }),
                     Err(e) => {
                         tracing::warn!("Invalid regex pattern for custom rule '{}': {e}", c.name);
@@ -45,6 +85,186 @@ impl CustomPatternChecker {
     }
 }
 
+impl CompiledPattern {
+    fn selects(&self, kind: LineKind) -> bool {
+        match (self.context, kind) {
+            (_, LineKind::Skip) | (None, LineKind::Code(_)) => false,
+            (None, _) => true,
+            (Some(PatternContext::Code), LineKind::Code(lang)) => {
+                self.languages.is_empty()
+                    || self.languages.iter().any(|l| l.eq_ignore_ascii_case(lang))
+            }
+            (Some(PatternContext::Prose), LineKind::Prose)
+            | (Some(PatternContext::Heading), LineKind::Heading)
+            | (Some(PatternContext::Table), LineKind::Table) => true,
+            _ => false,
+        }
+    }
+
+    fn is_excepted(&self, text: &str) -> bool {
+        self.unless.iter().any(|u| u.is_match(text))
+    }
+
+    fn diagnostic(&self, file: &ParsedFile, line: usize) -> Diagnostic {
+        Diagnostic {
+            file: Arc::clone(&file.path),
+            line,
+            column: None,
+            end_line: None,
+            end_column: None,
+            severity: self.severity,
+            category: Category::CustomPattern(self.name.as_str().into()),
+            message: self.message.clone(),
+            suggestion: None,
+            fix: None,
+            related: Vec::new(),
+        }
+    }
+
+    /// Attach the `replace` fix for `replacements`, if there are any.
+    fn with_fix(&self, mut d: Diagnostic, replacements: Vec<Replacement>) -> Diagnostic {
+        if let Some(first) = replacements.first() {
+            d.suggestion = Some(format!("Replace with `{}`", first.new_text));
+            d.fix = Some(Box::new(Fix {
+                description: format!("Apply `{}` replacement", self.name),
+                replacements,
+            }));
+        }
+        d
+    }
+
+    fn check_frontmatter(&self, file: &ParsedFile, key: &str, result: &mut CheckResult) {
+        let Some(entry) = file.frontmatter_get(key) else {
+            return;
+        };
+        let values = match entry.value.as_str() {
+            Some(scalar) => vec![scalar],
+            None => entry.value.items(),
+        };
+        let matched = values.iter().any(|v| {
+            self.regex
+                .find_iter(v)
+                .any(|m| !self.is_excepted(m.as_str()))
+        });
+        if matched {
+            result.diagnostics.push(self.diagnostic(file, entry.line));
+        }
+    }
+
+    /// Match the lines `start..end` of `file` as one text: a single line, or
+    /// a run of lines of the same kind in multi-line mode.
+    fn check_lines(&self, file: &ParsedFile, start: usize, end: usize, result: &mut CheckResult) {
+        let lines = &file.raw_lines[start..end];
+        let text = lines.join("\n");
+        let mut line_starts = Vec::with_capacity(lines.len());
+        let mut offset = 0;
+        for line in lines {
+            line_starts.push(offset);
+            offset += line.len() + 1;
+        }
+        // Line index within the run and byte column of a text offset.
+        let locate = |pos: usize| {
+            let i = line_starts.partition_point(|&s| s <= pos) - 1;
+            (i, pos - line_starts[i])
+        };
+
+        let mut line_hit: Option<Vec<Replacement>> = None;
+        for caps in self.regex.captures_iter(&text) {
+            let m = caps.get(0).expect("group 0 always participates");
+            if self.is_excepted(m.as_str()) {
+                continue;
+            }
+            let (first, start_col) = locate(m.start());
+            let (last, end_col) = locate(m.end());
+            let replacement = self
+                .replace
+                .as_ref()
+                .filter(|_| first == last)
+                .map(|template| {
+                    let mut new_text = String::new();
+                    caps.expand(template, &mut new_text);
+                    Replacement {
+                        line: start + first + 1,
+                        start_col,
+                        end_col,
+                        new_text,
+                    }
+                });
+            if self.multiline {
+                let mut d = self.diagnostic(file, start + first + 1);
+                if last != first {
+                    d.end_line = Some(start + last + 1);
+                }
+                result
+                    .diagnostics
+                    .push(self.with_fix(d, replacement.into_iter().collect()));
+            } else {
+                line_hit.get_or_insert_with(Vec::new).extend(replacement);
+            }
+        }
+        // Line by line, each line is reported once however often it matches.
+        if let Some(replacements) = line_hit {
+            let d = self.diagnostic(file, start + 1);
+            result.diagnostics.push(self.with_fix(d, replacements));
+        }
+    }
+
+    fn check_file(&self, file: &ParsedFile, kinds: &[LineKind], result: &mut CheckResult) {
+        let mut i = 0;
+        while i < kinds.len() {
+            if !self.selects(kinds[i]) {
+                i += 1;
+                continue;
+            }
+            let mut end = i + 1;
+            if self.multiline {
+                while end < kinds.len() && kinds[end] == kinds[i] {
+                    end += 1;
+                }
+            }
+            self.check_lines(file, i, end, result);
+            i = end;
+        }
+    }
+}
+
+/// Classify every line of `file`.
+fn line_kinds(file: &ParsedFile) -> Vec<LineKind<'_>> {
+    let table_lines: HashSet<usize> = file
+        .tables
+        .iter()
+        // Header, delimiter row and data rows, from the 1-based header line.
+        .flat_map(|t| t.line.saturating_sub(1)..=t.line + t.rows.len())
+        .collect();
+    let body_start = file.frontmatter.as_ref().map_or(0, |f| f.end_line);
+
+    let mut kinds = Vec::with_capacity(file.raw_lines.len());
+    let mut fence: Option<&str> = None;
+    for (i, line) in file.raw_lines.iter().enumerate() {
+        let kind = if !file.is_code(i) {
+            if is_heading(line) {
+                LineKind::Heading
+            } else if table_lines.contains(&i) {
+                LineKind::Table
+            } else {
+                LineKind::Prose
+            }
+        } else if i < body_start {
+            LineKind::Skip
+        } else if let Some(info) = line.trim_start().strip_prefix("```") {
+            fence = match fence {
+                Some(_) => None,
+                None => Some(info.split_whitespace().next().unwrap_or("")),
+            };
+            LineKind::Skip
+        } else {
+            fence.map_or(LineKind::Skip, LineKind::Code)
+        };
+        kinds.push(kind);
+    }
+    kinds
+}
+
 impl Checker for CustomPatternChecker {
     fn meta(&self) -> RuleMeta {
         RuleMeta {
@@ -59,19 +279,14 @@ impl Checker for CustomPatternChecker {
         let mut result = CheckResult::default();
 
         for file in &ctx.files {
-            for (i, line) in file.non_code_lines() {
-                for pattern in &self.patterns {
-                    if pattern.regex.is_match(line) {
-                        emit!(
-                            result,
-                            file.path,
-                            i + 1,
-                            pattern.severity,
-                            Category::CustomPattern(pattern.name.as_str().into()),
-                            "{}",
-                            pattern.message
-                        );
-                    }
+            let kinds = line_kinds(file);
+            for pattern in &self.patterns {
+                if !pattern.files.includes(&file.path, &ctx.project_root) {
+                    continue;
+                }
+                match &pattern.frontmatter_key {
+                    Some(key) => pattern.check_frontmatter(file, key, &mut result),
+                    None => pattern.check_file(file, &kinds, &mut result),
                 }
             }
         }
@@ -99,6 +314,8 @@ mod tests {
                 pattern: r"(?i)\bTODO\b".to_string(),
                 severity: Severity::Warning,
                 message: "TODO comment found".to_string(),
+                frontmatter_key: None,
+                ..Default::default()
             }],
         );
         assert_eq!(result.diagnostics.len(), 1);
@@ -119,6 +336,8 @@ mod tests {
                 pattern: r"(?i)\bTODO\b".to_string(),
                 severity: Severity::Warning,
                 message: "TODO found".to_string(),
+                frontmatter_key: None,
+                ..Default::default()
             }],
         );
         assert_eq!(result.diagnostics.len(), 0);
@@ -131,6 +350,8 @@ mod tests {
             pattern: r"[invalid".to_string(),
             severity: Severity::Warning,
             message: "bad pattern".to_string(),
+            frontmatter_key: None,
+            ..Default::default()
         }]);
         assert!(checker.patterns.is_empty());
     }
@@ -145,12 +366,16 @@ mod tests {
                     pattern: r"(?i)\bTODO\b".to_string(),
                     severity: Severity::Warning,
                     message: "TODO found".to_string(),
+                    frontmatter_key: None,
+                    ..Default::default()
                 },
                 CustomPattern {
                     name: "fixme".to_string(),
                     pattern: r"(?i)\bFIXME\b".to_string(),
                     severity: Severity::Error,
                     message: "FIXME found".to_string(),
+                    frontmatter_key: None,
+                    ..Default::default()
                 },
             ],
         );
@@ -170,12 +395,16 @@ mod tests {
                 pattern: r"(unclosed".to_string(),
                 severity: Severity::Warning,
                 message: "bad pattern".to_string(),
+                frontmatter_key: None,
+                ..Default::default()
             },
             CustomPattern {
                 name: "good".to_string(),
                 pattern: r"\bHACK\b".to_string(),
                 severity: Severity::Warning,
                 message: "HACK found".to_string(),
+                frontmatter_key: None,
+                ..Default::default()
             },
         ]);
         assert_eq!(
@@ -209,6 +438,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: raw_a,
             in_code_block: mask_a,
+            frontmatter: None,
         };
         let file_b = ParsedFile {
             path: std::sync::Arc::new(root.join("b.md")),
@@ -219,12 +449,15 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: raw_b,
             in_code_block: mask_b,
+            frontmatter: None,
         };
         let ctx = crate::engine::cross_ref::CheckerContext {
             files: vec![file_a, file_b],
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
         let patterns = vec![CustomPattern {
@@ -232,6 +465,8 @@ mod tests {
             pattern: r"\bHACK\b".to_string(),
             severity: Severity::Warning,
             message: "HACK found".to_string(),
+            frontmatter_key: None,
+            ..Default::default()
         }];
         let result = CustomPatternChecker::new(&patterns).check(&ctx);
         assert_eq!(
@@ -251,6 +486,8 @@ mod tests {
                 pattern: r"\bTODO\b".to_string(),
                 severity: Severity::Warning,
                 message: "uppercase TODO found".to_string(),
+                frontmatter_key: None,
+                ..Default::default()
             }],
         );
         assert_eq!(
@@ -277,6 +514,8 @@ mod tests {
                 pattern: r"\bTODO\b".to_string(),
                 severity: Severity::Warning,
                 message: "TODO found".to_string(),
+                frontmatter_key: None,
+                ..Default::default()
             }],
         );
         assert_eq!(
@@ -295,4 +534,243 @@ mod tests {
             "No patterns configured should produce no diagnostics"
         );
     }
+
+    #[test]
+    fn test_frontmatter_key_pattern() {
+        let pattern = CustomPattern {
+            name: "pinned-model".to_string(),
+            pattern: r"^claude-3".to_string(),
+            severity: Severity::Warning,
+            message: "Pin a current model".to_string(),
+            frontmatter_key: Some("model".to_string()),
+            ..Default::default()
+        };
+        let result = run_check(
+            &[
+                "---",
+                "name: a",
+                "model: claude-3-opus",
+                "---",
+                "model: claude-3 in prose",
+            ],
+            std::slice::from_ref(&pattern),
+        );
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].line, 3);
+
+        let result = run_check(&["---", "model: sonnet", "---"], &[pattern]);
+        assert!(result.diagnostics.is_empty());
+    }
+
+    /// Run `patterns` on `lines` parsed as markdown, so tables are known.
+    fn run_parsed(lines: &[&str], patterns: &[CustomPattern]) -> CheckResult {
+        let (_dir, mut ctx) = single_file_ctx(lines);
+        let path = Arc::clone(&ctx.files[0].path);
+        ctx.files[0] = crate::parser::parse_source(
+            &path,
+            &lines.join("\n"),
+            crate::parser::ParseMode::Markdown,
+        );
+        CustomPatternChecker::new(patterns).check(&ctx)
+    }
+
+    fn lines_of(result: &CheckResult) -> Vec<usize> {
+        result.diagnostics.iter().map(|d| d.line).collect()
+    }
+
+    const DOC: &[&str] = &[
+        "# Deploy with npm",
+        "",
+        "Run npm install first.",
+        "",
+        "| Tool | Use |",
+        "| --- | --- |",
+        "| npm | packages |",
+        "",
+        "```bash",
+        "npm ci",
+        "```",
+        "",
+        "```json",
+        "{\"npm\": true}",
+        "```",
+    ];
+
+    fn npm(context: Option<PatternContext>) -> CustomPattern {
+        CustomPattern {
+            name: "npm".to_string(),
+            pattern: r"\bnpm\b".to_string(),
+            message: "Use pnpm".to_string(),
+            context,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_where_selects_kind_of_line() {
+        assert_eq!(lines_of(&run_parsed(DOC, &[npm(None)])), vec![1, 3, 7]);
+        assert_eq!(
+            lines_of(&run_parsed(DOC, &[npm(Some(PatternContext::Prose))])),
+            vec![3]
+        );
+        assert_eq!(
+            lines_of(&run_parsed(DOC, &[npm(Some(PatternContext::Heading))])),
+            vec![1]
+        );
+        assert_eq!(
+            lines_of(&run_parsed(DOC, &[npm(Some(PatternContext::Table))])),
+            vec![7]
+        );
+        assert_eq!(
+            lines_of(&run_parsed(DOC, &[npm(Some(PatternContext::Code))])),
+            vec![10, 14]
+        );
+    }
+
+    #[test]
+    fn test_code_language_filter() {
+        let pattern = CustomPattern {
+            languages: vec!["Bash".to_string()],
+            ..npm(Some(PatternContext::Code))
+        };
+        assert_eq!(lines_of(&run_parsed(DOC, &[pattern])), vec![10]);
+    }
+
+    #[test]
+    fn test_files_glob_limits_pattern() {
+        let elsewhere = CustomPattern {
+            files: vec!["docs/**".to_string()],
+            ..npm(None)
+        };
+        assert!(run_parsed(DOC, &[elsewhere]).diagnostics.is_empty());
+        let here = CustomPattern {
+            files: vec!["CLAUDE.md".to_string()],
+            ..npm(None)
+        };
+        assert_eq!(run_parsed(DOC, &[here]).diagnostics.len(), 3);
+    }
+
+    #[test]
+    fn test_unless_skips_matched_text() {
+        let pattern = CustomPattern {
+            name: "javascript".to_string(),
+            pattern: r"(?i)\bjavascript\b".to_string(),
+            message: "Spell it JavaScript".to_string(),
+            unless: vec!["^JavaScript$".to_string()],
+            ..Default::default()
+        };
+        let result = run_check(
+            &[
+                "JavaScript is fine.",
+                "javascript is not.",
+                "JavaScript, then Javascript.",
+            ],
+            &[pattern],
+        );
+        assert_eq!(lines_of(&result), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_invalid_unless_regex_drops_pattern() {
+        let checker = CustomPatternChecker::new(&[CustomPattern {
+            unless: vec!["(unclosed".to_string()],
+            ..npm(None)
+        }]);
+        assert!(checker.patterns.is_empty());
+    }
+
+    #[test]
+    fn test_multiline_match_spans_lines() {
+        let pattern = CustomPattern {
+            name: "split-warning".to_string(),
+            pattern: r"(?i)never\s+push$\n^\s*to main".to_string(),
+            message: "Keep the rule on one line".to_string(),
+            multiline: true,
+            ..Default::default()
+        };
+        let result = run_check(
+            &[
+                "# Git",
+                "",
+                "Never push",
+                "to main.",
+                "",
+                "Never push to main.",
+            ],
+            &[pattern],
+        );
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].line, 3);
+        assert_eq!(result.diagnostics[0].end_line, Some(4));
+    }
+
+    #[test]
+    fn test_multiline_does_not_cross_into_code() {
+        let pattern = CustomPattern {
+            name: "run-then-code".to_string(),
+            pattern: r"Run:\n```".to_string(),
+            message: "x".to_string(),
+            multiline: true,
+            ..Default::default()
+        };
+        let result = run_check(&["Run:", "```", "make", "```"], &[pattern]);
+        assert!(result.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_replace_template_builds_fix() {
+        let pattern = CustomPattern {
+            name: "npm-run".to_string(),
+            pattern: r"\bnpm run (?P<script>\w+)".to_string(),
+            message: "Use pnpm".to_string(),
+            replace: Some("pnpm ${script}".to_string()),
+            ..Default::default()
+        };
+        let result = run_check(&["Run `npm run build` and `npm run test`."], &[pattern]);
+        assert_eq!(result.diagnostics.len(), 1);
+        let d = &result.diagnostics[0];
+        assert_eq!(d.suggestion.as_deref(), Some("Replace with `pnpm build`"));
+        let fix = d.fix.as_ref().unwrap();
+        assert_eq!(
+            fix.replacements,
+            vec![
+                Replacement {
+                    line: 1,
+                    start_col: 5,
+                    end_col: 18,
+                    new_text: "pnpm build".to_string(),
+                },
+                Replacement {
+                    line: 1,
+                    start_col: 25,
+                    end_col: 37,
+                    new_text: "pnpm test".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_replace_on_later_line_of_multiline_run() {
+        let pattern = CustomPattern {
+            name: "yarn".to_string(),
+            pattern: r"\byarn\b".to_string(),
+            message: "Use pnpm".to_string(),
+            multiline: true,
+            replace: Some("pnpm".to_string()),
+            ..Default::default()
+        };
+        let result = run_check(&["Install deps", "with yarn."], &[pattern]);
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].line, 2);
+        assert_eq!(
+            result.diagnostics[0].fix.as_ref().unwrap().replacements[0],
+            Replacement {
+                line: 2,
+                start_col: 5,
+                end_col: 9,
+                new_text: "pnpm".to_string(),
+            }
+        );
+    }
 }
@@ -1,13 +1,17 @@
 use regex::Regex;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, LazyLock};
 
 use crate::emit;
 use crate::engine::cross_ref::CheckerContext;
 use crate::types::{Category, CheckResult, RuleMeta, Severity};
 
-use super::utils::{is_template_ref, is_within_project};
-use super::Checker;
+use crate::parser::types::{FileRef, FileRefKind};
+
+use super::utils::{
+    is_command_file, is_skill_manifest, is_template_ref, is_within_project, resolve_import,
+};
+use super::{Checker, ProjectInputs};
 
 /// Lines where the file is being created/written/deleted, not a dependency.
 static ACTION_VERB_LINE: LazyLock<Regex> = LazyLock::new(|| {
@@ -84,6 +88,22 @@ fn resolves_via_dir_context(
         })
 }
 
+/// Resolve a mention relative to its source file's directory first, then the
+/// project root. The resolved path must exist and stay within the project, so
+/// `../../etc/passwd` style traversals do not silently pass.
+pub(crate) fn resolve_mention(file_ref: &FileRef, ctx: &CheckerContext) -> Option<PathBuf> {
+    let source_dir = file_ref.source_file.parent().unwrap_or(&ctx.project_root);
+    [
+        source_dir.join(&file_ref.path),
+        ctx.project_root.join(&file_ref.path),
+    ]
+    .into_iter()
+    .find(|resolved| {
+        resolved.exists()
+            && is_within_project(resolved, ctx.canonical_root.as_deref(), &ctx.project_root)
+    })
+}
+
 pub(crate) struct DeadReferenceChecker;
 
 impl Checker for DeadReferenceChecker {
@@ -96,15 +116,52 @@ impl Checker for DeadReferenceChecker {
         }
     }
 
+    fn is_project_wide(&self) -> bool {
+        true
+    }
+
+    fn project_inputs(&self) -> ProjectInputs {
+        ProjectInputs::FILES_AND_LISTING
+    }
+
     fn check(&self, ctx: &CheckerContext) -> CheckResult {
         let mut result = CheckResult::default();
 
         for (file_idx, file) in ctx.files.iter().enumerate() {
-            if ctx.historical_indices.contains(&file_idx) {
+            // Skill manifests are checked against their own folder by skill-package.
+            if ctx.historical_indices.contains(&file_idx)
+                || is_skill_manifest(&file.path, &ctx.project_root)
+            {
                 continue;
             }
 
             for file_ref in &file.file_refs {
+                // Imports are loaded verbatim, so none of the prose heuristics
+                // below apply: a missing import is always an error. Imports may
+                // legitimately point outside the project. Slash command `@file`
+                // references are reported by slash-command instead.
+                if file_ref.kind == FileRefKind::Import {
+                    if is_command_file(&file_ref.source_file, &ctx.project_root) {
+                        continue;
+                    }
+                    let Some(resolved) = resolve_import(file_ref, &ctx.project_root) else {
+                        continue;
+                    };
+                    if !resolved.exists() {
+                        emit!(
+                            result,
+                            Arc::new(file_ref.source_file.clone()),
+                            file_ref.line,
+                            Severity::Error,
+                            Category::DeadReference,
+                            suggest: "Imports resolve relative to the importing file; fix the path or remove the import",
+                            "Import \"@{}\" does not exist",
+                            file_ref.path
+                        );
+                    }
+                    continue;
+                }
+
                 if is_template_ref(&file_ref.path) {
                     continue;
                 }
@@ -122,25 +179,7 @@ impl Checker for DeadReferenceChecker {
                     continue;
                 }
 
-                // Resolve relative to source dir first, then project root.
-                // After resolving, verify the path stays within the project root
-                // to prevent `../../etc/passwd` style traversals from silently passing.
-                let source_dir = file_ref.source_file.parent().unwrap_or(&ctx.project_root);
-                let resolved_local = source_dir.join(&file_ref.path);
-                let resolved_root = ctx.project_root.join(&file_ref.path);
-                if (resolved_local.exists()
-                    && is_within_project(
-                        &resolved_local,
-                        ctx.canonical_root.as_deref(),
-                        &ctx.project_root,
-                    ))
-                    || (resolved_root.exists()
-                        && is_within_project(
-                            &resolved_root,
-                            ctx.canonical_root.as_deref(),
-                            &ctx.project_root,
-                        ))
-                {
+                if resolve_mention(file_ref, ctx).is_some() {
                     continue;
                 }
 
@@ -223,7 +262,7 @@ impl Checker for DeadReferenceChecker {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::parser::types::{FileRef, ParsedFile};
+    use crate::parser::types::{FileRef, FileRefKind, ParsedFile};
     use std::collections::HashSet;
     use std::fs;
 
@@ -240,11 +279,13 @@ mod tests {
                 path: "agent_definitions/followup_drafter.md".to_string(),
                 line: 56,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -252,6 +293,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
 
             historical_indices: HashSet::new(),
         };
@@ -278,17 +321,20 @@ mod tests {
                     path: "commands/[command].md".to_string(),
                     line: 10,
                     source_file: root.join("CLAUDE.md"),
+                    kind: FileRefKind::Mention,
                 },
                 FileRef {
                     path: "agent_definitions/*.md".to_string(),
                     line: 20,
                     source_file: root.join("CLAUDE.md"),
+                    kind: FileRefKind::Mention,
                 },
             ],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -296,6 +342,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
 
             historical_indices: HashSet::new(),
         };
@@ -326,11 +374,13 @@ mod tests {
                 path: "scout.md".to_string(),
                 line: 5,
                 source_file: root.join("docs/AGENTS.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -338,6 +388,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -362,11 +414,13 @@ mod tests {
                 path: "nonexistent.md".to_string(),
                 line: 5,
                 source_file: root.join("changelog.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let mut historical = HashSet::new();
@@ -377,6 +431,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: historical,
         };
 
@@ -404,11 +460,13 @@ mod tests {
                 path: "agent_definitions/scout.md".to_string(),
                 line: 10,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -416,6 +474,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -443,11 +503,13 @@ mod tests {
                 path: "../sibling.md".to_string(),
                 line: 5,
                 source_file: root.join("docs/AGENTS.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -455,6 +517,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -480,11 +544,13 @@ mod tests {
                 path: "../nonexistent.md".to_string(),
                 line: 5,
                 source_file: root.join("docs/AGENTS.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -492,6 +558,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -517,11 +585,13 @@ mod tests {
                 path: "templates/{name}.md".to_string(),
                 line: 10,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -529,6 +599,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -553,11 +625,13 @@ mod tests {
                 path: ".agent/skills/<skill-name>/SKILL.md".to_string(),
                 line: 10,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -565,6 +639,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -589,11 +665,13 @@ mod tests {
                 path: "~/.claude/CLAUDE.md".to_string(),
                 line: 4,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -601,6 +679,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -625,11 +705,13 @@ mod tests {
                 path: "path/to/agent.md".to_string(),
                 line: 62,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -637,6 +719,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -661,11 +745,13 @@ mod tests {
                 path: "@playbook-name.md".to_string(),
                 line: 10,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -673,6 +759,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -684,6 +772,100 @@ mod tests {
         );
     }
 
+    fn import_ctx(root: &Path, source: &str, import: &str, line: &str) -> CheckerContext {
+        let source_file = root.join(source);
+        let parsed = ParsedFile {
+            path: Arc::new(source_file.clone()),
+            sections: vec![],
+            tables: vec![],
+            file_refs: vec![FileRef {
+                path: import.to_string(),
+                line: 1,
+                source_file,
+                kind: FileRefKind::Import,
+            }],
+            directives: vec![],
+            suppress_comments: vec![],
+            raw_lines: vec![line.to_string()],
+            in_code_block: vec![false],
+            frontmatter: None,
+        };
+        CheckerContext {
+            files: vec![parsed],
+            project_root: root.to_path_buf(),
+            canonical_root: None,
+            filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
+            historical_indices: HashSet::new(),
+        }
+    }
+
+    #[test]
+    fn test_missing_import_flagged() {
+        let dir = tempfile::tempdir().unwrap();
+        let ctx = import_ctx(dir.path(), "CLAUDE.md", "docs/rules.md", "@docs/rules.md");
+
+        let result = DeadReferenceChecker.check(&ctx);
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].severity, Severity::Error);
+        assert!(result.diagnostics[0]
+            .message
+            .contains("Import \"@docs/rules.md\""));
+    }
+
+    #[test]
+    fn test_missing_import_flagged_despite_example_context() {
+        let dir = tempfile::tempdir().unwrap();
+        let ctx = import_ctx(
+            dir.path(),
+            "CLAUDE.md",
+            "examples.md",
+            "For example, @examples.md",
+        );
+
+        let result = DeadReferenceChecker.check(&ctx);
+        assert_eq!(result.diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn test_import_resolves_relative_to_importing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("sub")).unwrap();
+        fs::write(root.join("sub/rules.md"), "# Rules").unwrap();
+
+        let ctx = import_ctx(root, "sub/CLAUDE.md", "rules.md", "@rules.md");
+        assert!(DeadReferenceChecker.check(&ctx).diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_import_not_resolved_against_project_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("sub")).unwrap();
+        fs::write(root.join("rules.md"), "# Rules").unwrap();
+
+        let ctx = import_ctx(root, "sub/CLAUDE.md", "rules.md", "@rules.md");
+        assert_eq!(
+            DeadReferenceChecker.check(&ctx).diagnostics.len(),
+            1,
+            "Imports resolve relative to the importing file only"
+        );
+    }
+
+    #[test]
+    fn test_home_import_skipped() {
+        let dir = tempfile::tempdir().unwrap();
+        let ctx = import_ctx(
+            dir.path(),
+            "CLAUDE.md",
+            "~/.claude/my-project-instructions.md",
+            "@~/.claude/my-project-instructions.md",
+        );
+        assert!(DeadReferenceChecker.check(&ctx).diagnostics.is_empty());
+    }
+
     #[test]
     fn test_convention_reference_bare_filename_in_subdir() {
         let dir = tempfile::tempdir().unwrap();
@@ -704,11 +886,13 @@ mod tests {
                 path: "SKILL.md".to_string(),
                 line: 31,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let canonical_root = root.canonicalize().ok();
@@ -718,6 +902,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root,
             filename_index,
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -743,11 +929,13 @@ mod tests {
                 path: "NONEXISTENT.md".to_string(),
                 line: 5,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -755,6 +943,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -785,11 +975,13 @@ mod tests {
                 path: "agents/scout.md".to_string(),
                 line: 10,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -797,6 +989,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -822,11 +1016,13 @@ mod tests {
                 path: "webkit-changes.md".to_string(),
                 line: 1,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec!["Write up a summary in a file called webkit-changes.md".to_string()],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -834,6 +1030,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -858,11 +1056,13 @@ mod tests {
                 path: "webkit-changes.md".to_string(),
                 line: 1,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec!["delete the webkit-changes.md file".to_string()],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -870,6 +1070,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -894,11 +1096,13 @@ mod tests {
                 path: "/Users/drew/code/basic-memory/CHANGELOG.md".to_string(),
                 line: 135,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -906,6 +1110,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -931,17 +1137,20 @@ mod tests {
                     path: "cli-tool/components/agents/$ARGUMENTS.md".to_string(),
                     line: 27,
                     source_file: root.join("CLAUDE.md"),
+                    kind: FileRefKind::Mention,
                 },
                 FileRef {
                     path: "$MD_OUT=reports/junit-nl-suite.md".to_string(),
                     line: 46,
                     source_file: root.join("CLAUDE.md"),
+                    kind: FileRefKind::Mention,
                 },
             ],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -949,6 +1158,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -974,27 +1185,32 @@ mod tests {
                     path: "FILE.md".to_string(),
                     line: 238,
                     source_file: root.join("CLAUDE.md"),
+                    kind: FileRefKind::Mention,
                 },
                 FileRef {
                     path: "FILE.zh.md".to_string(),
                     line: 238,
                     source_file: root.join("CLAUDE.md"),
+                    kind: FileRefKind::Mention,
                 },
                 FileRef {
                     path: "filename.md".to_string(),
                     line: 33,
                     source_file: root.join("CLAUDE.md"),
+                    kind: FileRefKind::Mention,
                 },
                 FileRef {
                     path: "ref1.md".to_string(),
                     line: 104,
                     source_file: root.join("CLAUDE.md"),
+                    kind: FileRefKind::Mention,
                 },
             ],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -1002,6 +1218,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -1029,11 +1247,13 @@ mod tests {
                 path: "memory/rules.md".to_string(),
                 line: 203,
                 source_file: root.join("templates/claude-code/CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -1041,6 +1261,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -1065,6 +1287,7 @@ mod tests {
                 path: "drafts/srs-sso-authentication-2024-01-15.md".to_string(),
                 line: 1,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
@@ -1072,6 +1295,7 @@ mod tests {
                 "**Examples**: `drafts/srs-sso-authentication-2024-01-15.md`".to_string(),
             ],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -1079,6 +1303,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -1103,6 +1329,7 @@ mod tests {
                 path: "fix-parser-edge-case.md".to_string(),
                 line: 1,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
@@ -1111,6 +1338,7 @@ mod tests {
                     .to_string(),
             ],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -1118,6 +1346,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -1143,11 +1373,13 @@ mod tests {
                     path: "./agent-panel.md".to_string(),
                     line: 1,
                     source_file: root.join("AGENTS.md"),
+                    kind: FileRefKind::Mention,
                 },
                 FileRef {
                     path: "../telemetry.md".to_string(),
                     line: 2,
                     source_file: root.join("AGENTS.md"),
+                    kind: FileRefKind::Mention,
                 },
             ],
             directives: vec![],
@@ -1157,6 +1389,7 @@ mod tests {
                 "- Parent directory: `[Telemetry](../telemetry.md)`".to_string(),
             ],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -1164,6 +1397,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -1194,17 +1429,20 @@ mod tests {
                     path: ".github/copilot-instructions.md".to_string(),
                     line: 6,
                     source_file: root.join("AGENTS.md"),
+                    kind: FileRefKind::Mention,
                 },
                 FileRef {
                     path: "AGENT.md".to_string(),
                     line: 7,
                     source_file: root.join("AGENTS.md"),
+                    kind: FileRefKind::Mention,
                 },
             ],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines,
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -1212,6 +1450,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -1236,11 +1476,13 @@ mod tests {
                 path: "optimize-images.md".to_string(),
                 line: 1,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec!["- **Naming**: Use kebab-case: `optimize-images.md`".to_string()],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -1248,6 +1490,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -1276,6 +1520,7 @@ mod tests {
                 path: "base/skill-content.md".to_string(),
                 line: 3,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
@@ -1285,6 +1530,7 @@ mod tests {
                 "   - `base/skill-content.md` - Common content".to_string(),
             ],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -1292,6 +1538,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -1317,6 +1565,7 @@ mod tests {
                 path: "base/nonexistent.md".to_string(),
                 line: 3,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
@@ -1326,6 +1575,7 @@ mod tests {
                 "   - `base/nonexistent.md`".to_string(),
             ],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -1333,6 +1583,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -1358,11 +1610,13 @@ mod tests {
                 path: "config/setup.md".to_string(),
                 line: 1,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec!["Load config/setup.md for configuration details.".to_string()],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -1370,6 +1624,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -1395,17 +1651,21 @@ mod tests {
                 path: ref_path.to_string(),
                 line: 1,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec![raw_line.to_string()],
             in_code_block: vec![],
+            frontmatter: None,
         };
         CheckerContext {
             files: vec![parsed],
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         }
     }
@@ -1426,11 +1686,13 @@ mod tests {
                 path: "../../../etc/passwd".to_string(),
                 line: 1,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
             directives: vec![],
             suppress_comments: vec![],
             raw_lines: vec!["See ../../../etc/passwd for details".to_string()],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let canonical_root = root.canonicalize().ok();
@@ -1439,6 +1701,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -8,7 +8,7 @@ use crate::parser::types::ParsedFile;
 use crate::types::{Category, CheckResult, Diagnostic, RuleMeta, Severity};
 
 use super::utils::{is_instruction_file, normalize_directive, ScopeFilter, MIN_DIRECTIVE_LINES};
-use super::Checker;
+use super::{Checker, ProjectInputs};
 
 /// Collect normalized directive lines for a file as a pre-computed HashSet.
 fn collect_directive_set(file: &ParsedFile) -> HashSet<String> {
@@ -52,6 +52,14 @@ impl Checker for DuplicateInstructionFileChecker {
         }
     }
 
+    fn is_project_wide(&self) -> bool {
+        true
+    }
+
+    fn project_inputs(&self) -> ProjectInputs {
+        ProjectInputs::FILES
+    }
+
     fn check(&self, ctx: &CheckerContext) -> CheckResult {
         let mut result = CheckResult::default();
 
@@ -138,6 +146,7 @@ impl Checker for DuplicateInstructionFileChecker {
                                 .to_string(),
                         ),
                         fix: None,
+                        related: Vec::new(),
                     })
                 } else {
                     None
@@ -154,7 +163,7 @@ impl Checker for DuplicateInstructionFileChecker {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::parser::types::{FileRef, ParsedFile};
+    use crate::parser::types::{FileRef, FileRefKind, ParsedFile};
 
     fn make_file(
         root: &std::path::Path,
@@ -173,6 +182,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines,
             in_code_block,
+            frontmatter: None,
         }
     }
 
@@ -205,6 +215,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -244,6 +256,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -267,6 +281,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -290,6 +306,7 @@ mod tests {
                 path: "AGENTS.md".to_string(),
                 line: 1,
                 source_file: root.join("CLAUDE.md"),
+                kind: FileRefKind::Mention,
             }],
         );
         let file_b = make_file(root, "AGENTS.md", &lines, vec![]);
@@ -299,6 +316,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -320,6 +339,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -352,6 +373,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -378,6 +401,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -423,6 +448,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -447,6 +474,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -498,6 +527,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -5,10 +5,10 @@ use strsim::jaro_winkler;
 use crate::emit;
 use crate::engine::cross_ref::CheckerContext;
 use crate::parser::types::Table;
-use crate::types::{Category, CheckResult, RuleMeta, Severity};
+use crate::types::{Category, CheckResult, RelatedLocation, RuleMeta, Severity};
 
 use super::utils::{is_instruction_file, normalize, ScopeFilter};
-use super::Checker;
+use super::{Checker, ProjectInputs};
 
 pub(crate) struct EnumDriftChecker {
     scope: ScopeFilter,
@@ -28,6 +28,13 @@ struct TableRef<'a> {
     normalized_headers: Vec<String>,
 }
 
+impl TableRef<'_> {
+    /// Last line of the table: header, separator, then one line per row.
+    fn end_line(&self) -> usize {
+        self.table.line + self.table.rows.len() + 1
+    }
+}
+
 impl Checker for EnumDriftChecker {
     fn meta(&self) -> RuleMeta {
         RuleMeta {
@@ -38,6 +45,14 @@ impl Checker for EnumDriftChecker {
         }
     }
 
+    fn is_project_wide(&self) -> bool {
+        true
+    }
+
+    fn project_inputs(&self) -> ProjectInputs {
+        ProjectInputs::FILES
+    }
+
     fn check(&self, ctx: &CheckerContext) -> CheckResult {
         let mut result = CheckResult::default();
         let mut seen = HashSet::new();
@@ -186,6 +201,14 @@ fn check_drift(
                     suggest: "Align the value sets across files or document why they differ",
                     "{msg}"
                 );
+                if let Some(d) = result.diagnostics.last_mut() {
+                    d.end_line = Some(src.end_line());
+                    d.related = vec![RelatedLocation {
+                        file: ctx.files[other.file_idx].path.clone(),
+                        line: other.table.line,
+                        end_line: Some(other.end_line()),
+                    }];
+                }
             }
         }
     }
@@ -211,6 +234,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines,
             in_code_block,
+            frontmatter: None,
         }
     }
 
@@ -260,6 +284,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
 
             historical_indices: HashSet::new(),
         };
@@ -302,6 +328,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let file2 = ParsedFile {
@@ -321,6 +348,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let mut historical = HashSet::new();
@@ -331,6 +359,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: historical,
         };
 
@@ -365,6 +395,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -376,6 +407,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -417,6 +450,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let file2 = ParsedFile {
@@ -428,6 +462,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -435,6 +470,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
 
             historical_indices: HashSet::new(),
         };
@@ -466,6 +503,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let file2 = ParsedFile {
@@ -485,6 +523,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -492,6 +531,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -525,6 +566,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let file2 = ParsedFile {
@@ -541,6 +583,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -548,6 +591,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -578,6 +623,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let file2 = ParsedFile {
@@ -594,6 +640,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -601,6 +648,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -631,6 +680,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let file2 = ParsedFile {
@@ -647,6 +697,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -654,6 +705,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -711,6 +764,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -769,6 +824,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -817,6 +874,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -824,6 +882,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -3,10 +3,10 @@ use std::sync::LazyLock;
 
 use crate::emit;
 use crate::engine::cross_ref::CheckerContext;
-use crate::parser::non_code_lines_masked;
+use crate::parser::{inside_inline_code, non_code_lines_masked};
 use crate::types::{Category, CheckResult, RuleMeta, Severity};
 
-use super::utils::{inside_inline_code, ScopeFilter};
+use super::utils::ScopeFilter;
 use super::Checker;
 
 static ATTRIBUTION_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
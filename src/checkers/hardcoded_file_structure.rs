@@ -8,7 +8,7 @@ use crate::engine::cross_ref::CheckerContext;
 use crate::types::{Category, CheckResult, RuleMeta, Severity};
 
 use super::utils::{is_heading, is_template_ref, is_within_project, ScopeFilter};
-use super::Checker;
+use super::{Checker, ProjectInputs};
 
 pub(crate) struct HardcodedFileStructureChecker {
     scope: ScopeFilter,
@@ -166,6 +166,14 @@ impl Checker for HardcodedFileStructureChecker {
         }
     }
 
+    fn is_project_wide(&self) -> bool {
+        true
+    }
+
+    fn project_inputs(&self) -> ProjectInputs {
+        ProjectInputs::FILES_AND_LISTING
+    }
+
     fn check(&self, ctx: &CheckerContext) -> CheckResult {
         let mut result = CheckResult::default();
 
@@ -416,6 +424,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines,
             in_code_block,
+            frontmatter: None,
         };
         let canonical_root = root.canonicalize().ok();
         let filename_index = crate::engine::cross_ref::build_filename_index(root);
@@ -424,6 +433,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root,
             filename_index,
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
         let result = HardcodedFileStructureChecker::new(&[]).check(&ctx);
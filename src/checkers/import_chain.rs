@@ -0,0 +1,450 @@
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::emit;
+use crate::engine::cross_ref::CheckerContext;
+use crate::parser::types::{FileRef, FileRefKind};
+use crate::parser::{extract_import_refs, MAX_FILE_SIZE};
+use crate::types::{Category, CheckResult, RuleMeta, Severity};
+
+use super::circular_reference::find_cycles;
+use super::utils::{resolve_import, ScopeFilter};
+use super::{Checker, ProjectInputs};
+
+/// Claude Code stops following `@path` imports after this many hops.
+pub(crate) const MAX_IMPORT_DEPTH: usize = 5;
+
+/// Upper bound on files pulled into the import graph, so a pathological
+/// import fan-out cannot make the lint run unbounded.
+const MAX_GRAPH_NODES: usize = 2000;
+
+pub(crate) struct ImportChainChecker {
+    scope: ScopeFilter,
+    /// Unscanned import targets the last run read or found missing.
+    external: Mutex<Vec<PathBuf>>,
+}
+
+impl ImportChainChecker {
+    pub(crate) fn new(scope_patterns: &[String]) -> Self {
+        Self {
+            scope: ScopeFilter::new(scope_patterns),
+            external: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+/// Import graph over scanned files plus any existing files they transitively
+/// import. Nodes `0..scanned` are `ctx.files` in order.
+struct ImportGraph {
+    paths: Vec<PathBuf>,
+    imports: Vec<Vec<FileRef>>,
+    /// `(target node, index into imports[node])`
+    adj: Vec<Vec<(usize, usize)>>,
+    scanned: usize,
+    /// Resolved import targets outside the scan, read or missing.
+    external: Vec<PathBuf>,
+}
+
+/// Identity of a file for import tracking: its canonical path when it exists,
+/// so `a/../b.md` and `b.md` are the same node.
+pub(crate) fn canonical_key(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Read an unscanned file and extract its imports. Unreadable or oversized
+/// files contribute no edges.
+fn read_imports(path: &Path) -> Vec<FileRef> {
+    if !std::fs::metadata(path).is_ok_and(|m| m.is_file() && m.len() <= MAX_FILE_SIZE) {
+        return vec![];
+    }
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return vec![];
+    };
+    let lines: Vec<String> = content.lines().map(String::from).collect();
+    extract_import_refs(&lines, path)
+}
+
+impl ImportGraph {
+    fn build(ctx: &CheckerContext) -> Self {
+        let mut graph = Self {
+            paths: Vec::with_capacity(ctx.files.len()),
+            imports: Vec::with_capacity(ctx.files.len()),
+            adj: Vec::new(),
+            scanned: ctx.files.len(),
+            external: Vec::new(),
+        };
+        let mut index: HashMap<PathBuf, usize> = HashMap::new();
+
+        for (idx, file) in ctx.files.iter().enumerate() {
+            graph.paths.push((*file.path).clone());
+            graph.imports.push(
+                file.file_refs
+                    .iter()
+                    .filter(|r| r.kind == FileRefKind::Import)
+                    .cloned()
+                    .collect(),
+            );
+            index.entry(canonical_key(&file.path)).or_insert(idx);
+        }
+
+        // Worklist over nodes whose edges have not been resolved yet. Unscanned
+        // targets are appended as new nodes and processed in turn.
+        let mut node = 0;
+        while node < graph.paths.len() {
+            let mut edges = Vec::new();
+            let imports = std::mem::take(&mut graph.imports[node]);
+            for (ref_idx, import) in imports.iter().enumerate() {
+                let Some(resolved) = resolve_import(import, &ctx.project_root) else {
+                    continue;
+                };
+                if !resolved.is_file() {
+                    graph.external.push(resolved);
+                    continue;
+                }
+                let key = canonical_key(&resolved);
+                let target = match index.get(&key) {
+                    Some(&t) => t,
+                    None if graph.paths.len() < MAX_GRAPH_NODES => {
+                        let t = graph.paths.len();
+                        graph.imports.push(read_imports(&resolved));
+                        graph.external.push(resolved.clone());
+                        graph.paths.push(resolved);
+                        index.insert(key, t);
+                        t
+                    }
+                    None => continue,
+                };
+                if target != node {
+                    edges.push((target, ref_idx));
+                }
+            }
+            graph.imports[node] = imports;
+            graph.adj.push(edges);
+            node += 1;
+        }
+
+        graph
+    }
+
+    fn name(&self, node: usize) -> String {
+        self.paths[node]
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string()
+    }
+}
+
+impl Checker for ImportChainChecker {
+    fn meta(&self) -> RuleMeta {
+        RuleMeta {
+            name: "import-chain",
+            description: "Detects circular @-imports and import chains deeper than 5 hops",
+            default_severity: Severity::Warning,
+            strict_only: false,
+        }
+    }
+
+    fn is_project_wide(&self) -> bool {
+        true
+    }
+
+    fn project_inputs(&self) -> ProjectInputs {
+        ProjectInputs::FILES_AND_LISTING
+    }
+
+    fn external_inputs(&self) -> Vec<PathBuf> {
+        self.external
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn check(&self, ctx: &CheckerContext) -> CheckResult {
+        let mut result = CheckResult::default();
+
+        if !ctx
+            .files
+            .iter()
+            .any(|f| f.file_refs.iter().any(|r| r.kind == FileRefKind::Import))
+        {
+            return result;
+        }
+
+        let graph = ImportGraph::build(ctx);
+        self.external
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone_from(&graph.external);
+        let in_scope = |node: usize| {
+            node < graph.scanned
+                && self
+                    .scope
+                    .includes(&ctx.files[node].path, &ctx.project_root)
+        };
+
+        // Cycles: report on the first scanned file in the cycle, at its import
+        // of the next file. Cycles made up solely of unscanned files are out
+        // of reach for the user and skipped.
+        for cycle in find_cycles(&graph.adj) {
+            let Some(pos) = cycle.nodes.iter().position(|&n| in_scope(n)) else {
+                continue;
+            };
+            let node = cycle.nodes[pos];
+            let next = cycle.nodes[(pos + 1) % cycle.nodes.len()];
+            let edge = if pos + 1 == cycle.nodes.len() {
+                cycle.edge
+            } else {
+                match graph.adj[node].iter().find(|&&(t, _)| t == next) {
+                    Some(&(_, e)) => e,
+                    None => continue,
+                }
+            };
+            let import = &graph.imports[node][edge];
+
+            let rotated: Vec<String> = cycle.nodes[pos..]
+                .iter()
+                .chain(&cycle.nodes[..pos])
+                .map(|&n| graph.name(n))
+                .collect();
+
+            emit!(
+                result,
+                Arc::new(import.source_file.clone()),
+                import.line,
+                Severity::Warning,
+                Category::ImportChain,
+                suggest: "Remove one of the imports to break the cycle",
+                "Circular import chain: {} → {}",
+                rotated.join(" → "),
+                rotated[0]
+            );
+        }
+
+        // Depth: BFS from every scanned file that no other file imports.
+        let mut imported = vec![false; graph.paths.len()];
+        for edges in &graph.adj {
+            for &(target, _) in edges {
+                imported[target] = true;
+            }
+        }
+
+        for root in (0..graph.scanned).filter(|&n| !imported[n] && in_scope(n)) {
+            // parent[n] = (previous node, import index in previous node)
+            let mut parent: Vec<Option<(usize, usize)>> = vec![None; graph.paths.len()];
+            let mut dist: Vec<Option<usize>> = vec![None; graph.paths.len()];
+            dist[root] = Some(0);
+            let mut queue = VecDeque::from([root]);
+
+            while let Some(node) = queue.pop_front() {
+                let d = dist[node].unwrap_or_default();
+                for &(target, edge) in &graph.adj[node] {
+                    if dist[target].is_some() {
+                        continue;
+                    }
+                    dist[target] = Some(d + 1);
+                    parent[target] = Some((node, edge));
+                    if d + 1 > MAX_IMPORT_DEPTH {
+                        // Walk back to the root to rebuild the chain and find
+                        // the root's import line that starts it.
+                        let mut chain = vec![target];
+                        let mut first_edge = edge;
+                        let mut cur = node;
+                        while let Some((prev, e)) = parent[cur] {
+                            chain.push(cur);
+                            first_edge = e;
+                            cur = prev;
+                        }
+                        chain.push(root);
+                        chain.reverse();
+
+                        let import = &graph.imports[root][first_edge];
+                        let names: Vec<String> = chain.iter().map(|&n| graph.name(n)).collect();
+                        emit!(
+                            result,
+                            Arc::new(import.source_file.clone()),
+                            import.line,
+                            Severity::Warning,
+                            Category::ImportChain,
+                            suggest: "Flatten the chain by importing the file from a shallower level",
+                            "Import chain exceeds {} hops: {}; {} will not be loaded",
+                            MAX_IMPORT_DEPTH,
+                            names.join(" → "),
+                            graph.name(target)
+                        );
+                    } else {
+                        queue.push_back(target);
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::types::ParsedFile;
+    use std::collections::HashSet;
+    use std::fs;
+
+    fn make_file(root: &Path, name: &str, imports: &[&str]) -> ParsedFile {
+        let path = root.join(name);
+        let file_refs = imports
+            .iter()
+            .enumerate()
+            .map(|(i, r)| FileRef {
+                path: r.to_string(),
+                line: i + 1,
+                source_file: path.clone(),
+                kind: FileRefKind::Import,
+            })
+            .collect();
+        ParsedFile {
+            path: Arc::new(path),
+            sections: vec![],
+            tables: vec![],
+            file_refs,
+            directives: vec![],
+            suppress_comments: vec![],
+            raw_lines: vec![],
+            in_code_block: vec![],
+            frontmatter: None,
+        }
+    }
+
+    fn ctx(root: &Path, files: Vec<ParsedFile>) -> CheckerContext {
+        CheckerContext {
+            files,
+            project_root: root.to_path_buf(),
+            canonical_root: None,
+            filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
+            historical_indices: HashSet::new(),
+        }
+    }
+
+    #[test]
+    fn test_import_cycle_detected() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::write(root.join("a.md"), "@b.md").unwrap();
+        fs::write(root.join("b.md"), "@a.md").unwrap();
+
+        let files = vec![
+            make_file(root, "a.md", &["b.md"]),
+            make_file(root, "b.md", &["a.md"]),
+        ];
+        let result = ImportChainChecker::new(&[]).check(&ctx(root, files));
+
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].category, Category::ImportChain);
+        assert!(result.diagnostics[0]
+            .message
+            .contains("Circular import chain: a.md → b.md → a.md"));
+    }
+
+    #[test]
+    fn test_import_cycle_through_unscanned_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("docs")).unwrap();
+        fs::write(root.join("CLAUDE.md"), "@docs/rules.md").unwrap();
+        fs::write(root.join("docs/rules.md"), "See @../CLAUDE.md\n").unwrap();
+
+        let files = vec![make_file(root, "CLAUDE.md", &["docs/rules.md"])];
+        let checker = ImportChainChecker::new(&[]);
+        let result = checker.check(&ctx(root, files));
+
+        assert_eq!(checker.external_inputs(), vec![root.join("docs/rules.md")]);
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(*result.diagnostics[0].file, root.join("CLAUDE.md"));
+        assert!(result.diagnostics[0]
+            .message
+            .contains("CLAUDE.md → rules.md → CLAUDE.md"));
+    }
+
+    #[test]
+    fn test_mentions_do_not_form_import_cycles() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::write(root.join("a.md"), "").unwrap();
+        fs::write(root.join("b.md"), "").unwrap();
+
+        let mut a = make_file(root, "a.md", &["b.md"]);
+        a.file_refs[0].kind = FileRefKind::Mention;
+        let b = make_file(root, "b.md", &["a.md"]);
+        let result = ImportChainChecker::new(&[]).check(&ctx(root, vec![a, b]));
+
+        assert!(result.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_chain_at_max_depth_ok() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        // CLAUDE.md → 1 → 2 → 3 → 4 → 5: exactly five hops.
+        for i in 1..5 {
+            fs::write(root.join(format!("{i}.md")), format!("@{}.md", i + 1)).unwrap();
+        }
+        fs::write(root.join("5.md"), "# leaf").unwrap();
+
+        let files = vec![make_file(root, "CLAUDE.md", &["1.md"])];
+        let result = ImportChainChecker::new(&[]).check(&ctx(root, files));
+
+        assert!(result.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_chain_too_deep_flagged() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        // CLAUDE.md → 1 → ... → 6: the sixth hop is not loaded.
+        for i in 1..6 {
+            fs::write(root.join(format!("{i}.md")), format!("@{}.md", i + 1)).unwrap();
+        }
+        fs::write(root.join("6.md"), "# leaf").unwrap();
+
+        let files = vec![make_file(root, "CLAUDE.md", &["1.md"])];
+        let result = ImportChainChecker::new(&[]).check(&ctx(root, files));
+
+        assert_eq!(result.diagnostics.len(), 1);
+        let diag = &result.diagnostics[0];
+        assert_eq!(*diag.file, root.join("CLAUDE.md"));
+        assert_eq!(diag.line, 1);
+        assert!(diag.message.contains("exceeds 5 hops"));
+        assert!(diag
+            .message
+            .contains("CLAUDE.md → 1.md → 2.md → 3.md → 4.md → 5.md → 6.md"));
+        assert!(diag.message.contains("6.md will not be loaded"));
+    }
+
+    #[test]
+    fn test_missing_and_home_imports_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+
+        let files = vec![make_file(
+            root,
+            "CLAUDE.md",
+            &["missing.md", "~/.claude/personal.md"],
+        )];
+        let result = ImportChainChecker::new(&[]).check(&ctx(root, files));
+
+        assert!(
+            result.diagnostics.is_empty(),
+            "Missing imports are reported by dead-reference"
+        );
+    }
+
+    #[test]
+    fn test_no_files_no_crash() {
+        let dir = tempfile::tempdir().unwrap();
+        let result = ImportChainChecker::new(&[]).check(&ctx(dir.path(), vec![]));
+        assert!(result.diagnostics.is_empty());
+    }
+}
@@ -1,6 +1,60 @@
 #[doc(hidden)]
 #[macro_export]
 macro_rules! emit {
+    // ── span + fix + suggest ────────────────────────────────────────────
+    ($result:expr, $file:expr, $line:expr, $severity:expr, $category:expr,
+     span: $span:expr, fix: $fix:expr, suggest: $suggestion:expr, $($msg:tt)+) => {{
+        let span = $span;
+        $result.diagnostics.push($crate::types::Diagnostic {
+            file: $file.clone(),
+            line: $line,
+            column: Some(span.column),
+            end_line: None,
+            end_column: Some(span.end_column),
+            severity: $severity,
+            category: $category,
+            message: format!($($msg)+),
+            suggestion: Some($suggestion.to_string()),
+            fix: Some(Box::new($fix)),
+            related: Vec::new(),
+        });
+    }};
+    // ── span + suggest ──────────────────────────────────────────────────
+    ($result:expr, $file:expr, $line:expr, $severity:expr, $category:expr,
+     span: $span:expr, suggest: $suggestion:expr, $($msg:tt)+) => {{
+        let span = $span;
+        $result.diagnostics.push($crate::types::Diagnostic {
+            file: $file.clone(),
+            line: $line,
+            column: Some(span.column),
+            end_line: None,
+            end_column: Some(span.end_column),
+            severity: $severity,
+            category: $category,
+            message: format!($($msg)+),
+            suggestion: Some($suggestion.to_string()),
+            fix: None,
+            related: Vec::new(),
+        });
+    }};
+    // ── span only ───────────────────────────────────────────────────────
+    ($result:expr, $file:expr, $line:expr, $severity:expr, $category:expr,
+     span: $span:expr, $($msg:tt)+) => {{
+        let span = $span;
+        $result.diagnostics.push($crate::types::Diagnostic {
+            file: $file.clone(),
+            line: $line,
+            column: Some(span.column),
+            end_line: None,
+            end_column: Some(span.end_column),
+            severity: $severity,
+            category: $category,
+            message: format!($($msg)+),
+            suggestion: None,
+            fix: None,
+            related: Vec::new(),
+        });
+    }};
     // ── fix + suggest ───────────────────────────────────────────────────
     ($result:expr, $file:expr, $line:expr, $severity:expr, $category:expr,
      fix: $fix:expr, suggest: $suggestion:expr, $($msg:tt)+) => {
@@ -15,6 +69,7 @@ macro_rules! emit {
             message: format!($($msg)+),
             suggestion: Some($suggestion.to_string()),
             fix: Some(Box::new($fix)),
+            related: Vec::new(),
         });
     };
     // ── suggest only ────────────────────────────────────────────────────
@@ -31,6 +86,7 @@ macro_rules! emit {
             message: format!($($msg)+),
             suggestion: Some($suggestion.to_string()),
             fix: None,
+            related: Vec::new(),
         });
     };
     // ── bare (no suggest, no fix) ───────────────────────────────────────
@@ -47,6 +103,7 @@ macro_rules! emit {
             message: format!($($msg)+),
             suggestion: None,
             fix: None,
+            related: Vec::new(),
         });
     };
 }
@@ -0,0 +1,151 @@
+use std::sync::Arc;
+
+use crate::emit;
+use crate::engine::cross_ref::CheckerContext;
+use crate::parser::frontmatter::FrontmatterProblemKind;
+use crate::types::{Category, CheckResult, RuleMeta, Severity};
+
+use super::utils::ScopeFilter;
+use super::Checker;
+
+pub(crate) struct MalformedFrontmatterChecker {
+    scope: ScopeFilter,
+}
+
+impl MalformedFrontmatterChecker {
+    pub(crate) fn new(scope_patterns: &[String]) -> Self {
+        Self {
+            scope: ScopeFilter::new(scope_patterns),
+        }
+    }
+}
+
+impl Checker for MalformedFrontmatterChecker {
+    fn meta(&self) -> RuleMeta {
+        RuleMeta {
+            name: "malformed-frontmatter",
+            description: "Detects invalid YAML, duplicate keys and tab indentation in frontmatter",
+            default_severity: Severity::Error,
+            strict_only: false,
+        }
+    }
+
+    fn check(&self, ctx: &CheckerContext) -> CheckResult {
+        let mut result = CheckResult::default();
+
+        for file in &ctx.files {
+            if !self.scope.includes(&file.path, &ctx.project_root) {
+                continue;
+            }
+            let Some(frontmatter) = &file.frontmatter else {
+                continue;
+            };
+
+            for problem in &frontmatter.problems {
+                match &problem.kind {
+                    FrontmatterProblemKind::Syntax(message) => {
+                        emit!(
+                            result,
+                            Arc::clone(&file.path),
+                            problem.line,
+                            Severity::Error,
+                            Category::MalformedFrontmatter,
+                            suggest: "Fix the YAML so tools reading this frontmatter can parse it",
+                            "Invalid frontmatter: {}",
+                            message
+                        );
+                    }
+                    FrontmatterProblemKind::DuplicateKey { key, first_line } => {
+                        emit!(
+                            result,
+                            Arc::clone(&file.path),
+                            problem.line,
+                            Severity::Warning,
+                            Category::MalformedFrontmatter,
+                            suggest: "Remove one of the duplicate keys; YAML parsers either reject the file or keep only one value",
+                            "Duplicate frontmatter key \"{}\" (first defined on line {})",
+                            key,
+                            first_line
+                        );
+                    }
+                    FrontmatterProblemKind::Tab => {
+                        emit!(
+                            result,
+                            Arc::clone(&file.path),
+                            problem.line,
+                            Severity::Error,
+                            Category::MalformedFrontmatter,
+                            suggest: "Indent with spaces; YAML does not allow tabs for indentation",
+                            "Tab character in frontmatter indentation"
+                        );
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::types::ParsedFile;
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    fn check(content: &str) -> Vec<(usize, Severity, String)> {
+        let raw_lines: Vec<String> = content.lines().map(String::from).collect();
+        let in_code_block = crate::parser::build_code_block_mask(&raw_lines);
+        let ctx = CheckerContext {
+            files: vec![ParsedFile {
+                path: Arc::new(PathBuf::from("/project/.claude/agents/a.md")),
+                sections: vec![],
+                tables: vec![],
+                file_refs: vec![],
+                directives: vec![],
+                suppress_comments: vec![],
+                frontmatter: crate::parser::frontmatter::parse_frontmatter(&raw_lines),
+                raw_lines,
+                in_code_block,
+            }],
+            settings_files: vec![],
+            project_root: PathBuf::from("/project"),
+            canonical_root: None,
+            filename_index: HashSet::new(),
+            path_index: vec![],
+            historical_indices: HashSet::new(),
+        };
+        MalformedFrontmatterChecker::new(&[])
+            .check(&ctx)
+            .diagnostics
+            .into_iter()
+            .map(|d| (d.line, d.severity, d.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_frontmatter_passes() {
+        assert!(check("---\nname: a\ntools:\n  - Read\n---\n# Body\n").is_empty());
+    }
+
+    #[test]
+    fn test_no_frontmatter_passes() {
+        assert!(check("# Title\n\nname: not frontmatter\n").is_empty());
+    }
+
+    #[test]
+    fn test_reports_each_problem() {
+        let diags = check("---\nname: a\nname: b\ntools:\n\t- Read\nsome prose\n---\n");
+        assert_eq!(diags.len(), 3, "{diags:?}");
+        assert_eq!(diags[0].0, 5);
+        assert!(diags[0].2.contains("Tab character"));
+        assert_eq!(diags[1].0, 3);
+        assert_eq!(diags[1].1, Severity::Warning);
+        assert!(diags[1]
+            .2
+            .contains("Duplicate frontmatter key \"name\" (first defined on line 2)"));
+        assert_eq!(diags[2].0, 6);
+        assert!(diags[2].2.contains("expected `key: value`"));
+    }
+}
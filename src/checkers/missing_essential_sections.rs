@@ -292,12 +292,15 @@ mod tests {
                 "Check for issues.".to_string(),
             ],
             in_code_block: vec![],
+            frontmatter: None,
         };
         let ctx = CheckerContext {
             files: vec![file],
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
         let config = MissingEssentialSectionsConfig {
@@ -328,12 +328,15 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: lines.iter().map(|s| s.to_string()).collect(),
             in_code_block: vec![],
+            frontmatter: None,
         };
         let ctx = CheckerContext {
             files: vec![file],
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
         let result = MissingRoleDefinitionChecker::new(&[]).check(&ctx);
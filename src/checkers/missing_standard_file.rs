@@ -4,7 +4,7 @@ use crate::emit;
 use crate::engine::cross_ref::CheckerContext;
 use crate::types::{Category, CheckResult, RuleMeta, Severity};
 
-use super::Checker;
+use super::{Checker, ProjectInputs};
 
 pub(crate) struct MissingStandardFileChecker;
 
@@ -52,6 +52,14 @@ impl Checker for MissingStandardFileChecker {
         }
     }
 
+    fn is_project_wide(&self) -> bool {
+        true
+    }
+
+    fn project_inputs(&self) -> ProjectInputs {
+        ProjectInputs::FILES_AND_LISTING
+    }
+
     fn check(&self, ctx: &CheckerContext) -> CheckResult {
         let mut result = CheckResult::default();
 
@@ -113,6 +121,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec!["# Test".to_string()],
             in_code_block: vec![false],
+            frontmatter: None,
         }
     }
 
@@ -128,6 +137,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
         let result = MissingStandardFileChecker.check(&ctx);
@@ -147,6 +158,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
         let result = MissingStandardFileChecker.check(&ctx);
@@ -166,6 +179,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
         let result = MissingStandardFileChecker.check(&ctx);
@@ -186,6 +201,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
         let result = MissingStandardFileChecker.check(&ctx);
@@ -204,6 +221,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
         let result = MissingStandardFileChecker.check(&ctx);
@@ -224,6 +243,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
         let result = MissingStandardFileChecker.check(&ctx);
@@ -248,6 +269,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
         let result = MissingStandardFileChecker.check(&ctx);
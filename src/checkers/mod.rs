@@ -6,14 +6,17 @@ mod boilerplate_template;
 mod broken_anchor_link;
 mod broken_table;
 mod circular_reference;
+mod claude_settings;
 mod click_here_link;
 mod command_validation;
 mod command_without_codeblock;
 mod conflicting_directives;
 mod context_window_waste;
 mod copied_meta_instructions;
+mod copilot_instructions;
 mod credential_exposure;
 mod cross_file_contradiction;
+mod cursor_rule;
 mod custom_pattern;
 mod dangerous_command;
 mod dead_reference;
@@ -33,12 +36,14 @@ mod hardcoded_file_structure;
 mod hardcoded_windows_path;
 mod heading_hierarchy;
 mod imperative_heading;
+pub(crate) mod import_chain;
 mod inconsistent_command_prefix;
 mod instruction_density;
 mod instruction_without_context;
 mod large_code_block;
 mod long_paragraph;
 mod macros;
+mod malformed_frontmatter;
 mod misordered_steps;
 mod missing_essential_sections;
 mod missing_examples;
@@ -52,15 +57,19 @@ mod orphaned_section;
 mod outdated_model_reference;
 mod placeholder_text;
 mod placeholder_url;
+mod plugin;
 mod prompt_injection_vector;
 mod redundant_directive;
 mod repeated_word;
 mod section_length_imbalance;
 mod session_journal;
+mod skill_package;
+mod slash_command;
 mod stale_file_tree;
 mod stale_reference;
 mod stale_style_rule;
-mod token_budget;
+mod subagent_definition;
+pub(crate) mod token_budget;
 mod unbounded_scope;
 mod unclosed_fence;
 mod undocumented_env_var;
@@ -70,21 +79,77 @@ pub(crate) mod utils;
 mod vague_directive;
 mod xml_document_wrapper;
 
+use std::path::{Path, PathBuf};
+
 use crate::config::Config;
 use crate::engine::cross_ref::CheckerContext;
 use crate::types::{CheckResult, RuleMeta};
 
-pub(crate) trait Checker: Send + Sync {
+/// A lint rule. Built-in checkers are created from the config by
+/// [`all_checkers`]; embedders can add their own through
+/// [`Linter::checker`](crate::Linter::checker).
+pub trait Checker: Send + Sync {
     /// Static metadata about this checker (name, description, severity, strict_only).
-    #[allow(dead_code)]
     fn meta(&self) -> RuleMeta;
 
     fn check(&self, ctx: &CheckerContext) -> CheckResult;
+
+    /// Whether results depend on more than each file's own content: other
+    /// files, settings, or the project tree. Single-file checkers are re-run
+    /// only on changed files and their cached results reused for the rest, so
+    /// override this for any checker that compares files or touches the disk.
+    fn is_project_wide(&self) -> bool {
+        false
+    }
+
+    /// Files outside `ctx.files` and the settings files that the last
+    /// [`check`](Self::check) read from disk, including ones it found
+    /// missing. A project-wide checker that follows references out of the
+    /// scan must report them, or its cached results go stale when they change.
+    fn external_inputs(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
+
+    /// Which parts of the project a project-wide checker reads. Its cached
+    /// results are reused until one of them changes.
+    fn project_inputs(&self) -> ProjectInputs {
+        ProjectInputs::ALL
+    }
+}
+
+/// The parts of a project that project-wide checkers can depend on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProjectInputs {
+    /// Paths and content of the instruction files.
+    pub files: bool,
+    /// Paths and content of the `.claude/settings*.json` files.
+    pub settings: bool,
+    /// Listing of every project file, used to resolve references and globs.
+    pub listing: bool,
+}
+
+impl ProjectInputs {
+    pub const ALL: Self = Self {
+        files: true,
+        settings: true,
+        listing: true,
+    };
+    /// Only the instruction files, e.g. for comparing them to each other.
+    pub const FILES: Self = Self {
+        files: true,
+        settings: false,
+        listing: false,
+    };
+    /// The instruction files and the files they may refer to.
+    pub const FILES_AND_LISTING: Self = Self {
+        files: true,
+        settings: false,
+        listing: true,
+    };
 }
 
 /// Collect metadata from all checkers (using default config to instantiate them).
 /// Useful for generating rule lists and validation.
-#[allow(dead_code)]
 pub(crate) fn all_checker_meta() -> Vec<RuleMeta> {
     let config = Config::default_with_all_enabled();
     all_checkers(&config).iter().map(|c| c.meta()).collect()
@@ -492,6 +557,52 @@ pub(crate) fn all_checkers(config: &Config) -> Vec<Box<dyn Checker>> {
             ),
         ));
     }
+    if config.checkers.import_chain.enabled {
+        checkers.push(Box::new(import_chain::ImportChainChecker::new(
+            &config.checkers.import_chain.scope,
+        )));
+    }
+    if config.checkers.cursor_rule.enabled {
+        checkers.push(Box::new(cursor_rule::CursorRuleChecker::new(
+            &config.checkers.cursor_rule.scope,
+        )));
+    }
+    if config.checkers.claude_settings.enabled {
+        checkers.push(Box::new(claude_settings::ClaudeSettingsChecker::new(
+            &config.checkers.claude_settings.scope,
+        )));
+    }
+    if config.checkers.subagent_definition.enabled {
+        checkers.push(Box::new(
+            subagent_definition::SubagentDefinitionChecker::new(
+                &config.checkers.subagent_definition.scope,
+            ),
+        ));
+    }
+    if config.checkers.slash_command.enabled {
+        checkers.push(Box::new(slash_command::SlashCommandChecker::new(
+            &config.checkers.slash_command.scope,
+        )));
+    }
+    if config.checkers.skill_package.enabled {
+        checkers.push(Box::new(skill_package::SkillPackageChecker::new(
+            &config.checkers.skill_package.scope,
+        )));
+    }
+    if config.checkers.malformed_frontmatter.enabled {
+        checkers.push(Box::new(
+            malformed_frontmatter::MalformedFrontmatterChecker::new(
+                &config.checkers.malformed_frontmatter.scope,
+            ),
+        ));
+    }
+    if config.checkers.copilot_instructions.enabled {
+        checkers.push(Box::new(
+            copilot_instructions::CopilotInstructionsChecker::new(
+                &config.checkers.copilot_instructions.scope,
+            ),
+        ));
+    }
     if !config.checkers.custom_patterns.is_empty() {
         checkers.push(Box::new(custom_pattern::CustomPatternChecker::new(
             &config.checkers.custom_patterns,
@@ -501,6 +612,19 @@ pub(crate) fn all_checkers(config: &Config) -> Vec<Box<dyn Checker>> {
     checkers
 }
 
+/// Checkers for the `[[plugins]]` of `config`. Like the checkers added
+/// through [`Linter::checker`](crate::Linter::checker), they see every file
+/// and their results are never cached.
+pub(crate) fn plugin_checkers(config: &Config, project_root: &Path) -> Vec<Box<dyn Checker>> {
+    config
+        .plugins
+        .iter()
+        .map(|plugin| {
+            Box::new(plugin::PluginChecker::new(plugin, project_root)) as Box<dyn Checker>
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
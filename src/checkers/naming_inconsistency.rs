@@ -8,8 +8,8 @@ use crate::emit;
 use crate::engine::cross_ref::CheckerContext;
 use crate::types::{Category, CheckResult, RuleMeta, Severity};
 
-use super::utils::{normalize, ScopeFilter};
-use super::Checker;
+use super::utils::{link_related, normalize, ScopeFilter};
+use super::{Checker, ProjectInputs};
 
 /// Patterns to skip: dates, timestamps, and YAML-like frontmatter lines.
 static DATE_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
@@ -72,6 +72,14 @@ impl Checker for NamingInconsistencyChecker {
         }
     }
 
+    fn is_project_wide(&self) -> bool {
+        true
+    }
+
+    fn project_inputs(&self) -> ProjectInputs {
+        ProjectInputs::FILES
+    }
+
     fn check(&self, ctx: &CheckerContext) -> CheckResult {
         let mut result = CheckResult::default();
 
@@ -150,6 +158,7 @@ impl Checker for NamingInconsistencyChecker {
                 iter.next().unwrap()
             );
 
+            let start = result.diagnostics.len();
             for occ in group {
                 emit!(
                     result,
@@ -161,6 +170,7 @@ impl Checker for NamingInconsistencyChecker {
                     "{msg}"
                 );
             }
+            link_related(&mut result.diagnostics[start..]);
         }
 
         let group_keys: Vec<_> = groups
@@ -245,6 +255,7 @@ impl Checker for NamingInconsistencyChecker {
                     group_a[0].original, group_b[0].original
                 );
 
+                let start = result.diagnostics.len();
                 for occ in group_a.iter().chain(group_b.iter()) {
                     emit!(
                         result,
@@ -256,6 +267,7 @@ impl Checker for NamingInconsistencyChecker {
                         "{msg}"
                     );
                 }
+                link_related(&mut result.diagnostics[start..]);
             }
         }
 
@@ -287,6 +299,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let file2 = ParsedFile {
@@ -303,6 +316,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -310,6 +324,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
 
             historical_indices: HashSet::new(),
         };
@@ -349,6 +365,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let file2 = ParsedFile {
@@ -365,6 +382,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -372,6 +390,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -416,6 +436,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -423,6 +444,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -461,6 +484,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let file2 = ParsedFile {
@@ -477,6 +501,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -484,6 +509,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -525,6 +552,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let file2 = ParsedFile {
@@ -541,6 +569,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -548,6 +577,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -585,6 +616,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let file2 = ParsedFile {
@@ -601,6 +633,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -608,6 +641,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -638,6 +673,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let file2 = ParsedFile {
@@ -654,6 +690,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -661,6 +698,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -696,6 +735,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let file2 = ParsedFile {
@@ -712,6 +752,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -719,6 +760,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -754,6 +797,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let file2 = ParsedFile {
@@ -770,6 +814,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -777,6 +822,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -812,6 +859,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let file2 = ParsedFile {
@@ -828,6 +876,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -835,6 +884,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -870,6 +921,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let file2 = ParsedFile {
@@ -886,6 +938,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -893,6 +946,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -928,6 +983,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let file2 = ParsedFile {
@@ -944,6 +1000,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -951,6 +1008,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -36,6 +36,13 @@ static OUTDATED_MODELS: LazyLock<Regex> = LazyLock::new(|| {
     .unwrap()
 });
 
+/// Returns `true` if `text` names a deprecated model (e.g. an agent's
+/// `model:` frontmatter value).
+#[must_use]
+pub(crate) fn is_outdated_model(text: &str) -> bool {
+    OUTDATED_MODELS.is_match(text)
+}
+
 /// Lines containing these words (case-insensitive) are self-documenting
 /// deprecation and should not be flagged.
 static EXCLUSION_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
@@ -3,9 +3,10 @@ use std::sync::LazyLock;
 
 use crate::emit;
 use crate::engine::cross_ref::CheckerContext;
+use crate::parser::inside_inline_code;
 use crate::types::{Category, CheckResult, RuleMeta, Severity};
 
-use super::utils::{inside_inline_code, ScopeFilter};
+use super::utils::{ScopeFilter, Span};
 use super::Checker;
 
 pub(crate) struct PlaceholderTextChecker {
@@ -133,15 +134,16 @@ impl Checker for PlaceholderTextChecker {
                     {
                         continue;
                     }
+                    let placeholder = m.as_str().trim();
                     emit!(
                         result,
                         file.path,
                         i + 1,
                         Severity::Warning,
                         Category::PlaceholderText,
+                        span: Span::in_line(line, m.start(), m.start() + placeholder.len()),
                         suggest: "Replace placeholder with actual content",
-                        "Placeholder text found: \"{}\"",
-                        m.as_str().trim()
+                        "Placeholder text found: \"{placeholder}\""
                     );
                     break; // One diagnostic per line
                 }
@@ -173,6 +175,13 @@ mod tests {
     fn test_todo_with_colon_detected() {
         let result = run_check(&["TODO: add error handling"]);
         assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(
+            (
+                result.diagnostics[0].column,
+                result.diagnostics[0].end_column
+            ),
+            (Some(1), Some(6))
+        );
     }
 
     #[test]
@@ -0,0 +1,333 @@
+//! External checkers run as subprocesses.
+//!
+//! A plugin is started once per file in its scope and sent
+//! `{"root": ..., "file": <ParsedFile>}` on stdin. It prints
+//! `{"diagnostics": [...]}` on stdout, each entry shaped like a
+//! [`Diagnostic`] without `file`: `line` and `message` are required;
+//! `column`, `end_line`, `end_column`, `severity`, `category`, `suggestion`
+//! and `fix` are optional. A plugin that cannot be started, times out, exits
+//! unsuccessfully or prints anything else is reported as an error on the
+//! file instead of aborting the run.
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::config::PluginConfig;
+use crate::engine::cross_ref::CheckerContext;
+use crate::parser::types::ParsedFile;
+use crate::types::{Category, CheckResult, Diagnostic, Fix, RuleMeta, Severity};
+
+use super::utils::ScopeFilter;
+use super::Checker;
+
+/// How often a running plugin is polled for exit.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+pub(crate) struct PluginChecker {
+    name: String,
+    command: Vec<String>,
+    timeout: Duration,
+    scope: ScopeFilter,
+    severity: Option<Severity>,
+}
+
+#[derive(Serialize)]
+struct Request<'a> {
+    root: &'a Path,
+    file: &'a ParsedFile,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    diagnostics: Vec<PluginDiagnostic>,
+}
+
+#[derive(Deserialize)]
+struct PluginDiagnostic {
+    line: usize,
+    column: Option<usize>,
+    end_line: Option<usize>,
+    end_column: Option<usize>,
+    severity: Option<Severity>,
+    category: Option<String>,
+    message: String,
+    suggestion: Option<String>,
+    fix: Option<Fix>,
+}
+
+impl PluginChecker {
+    pub(crate) fn new(config: &PluginConfig, project_root: &Path) -> Self {
+        let mut command = config.command.clone();
+        if let Some(program) = command.first_mut() {
+            let path = Path::new(program.as_str());
+            if path.is_relative() && path.components().count() > 1 {
+                *program = project_root.join(path).display().to_string();
+            }
+        }
+        Self {
+            name: config.name.clone(),
+            command,
+            timeout: Duration::from_secs(config.timeout),
+            scope: ScopeFilter::new(&config.scope),
+            severity: config.severity,
+        }
+    }
+
+    fn check_file(&self, file: &ParsedFile, project_root: &Path) -> Vec<Diagnostic> {
+        let request = Request {
+            root: project_root,
+            file,
+        };
+        let response = serde_json::to_vec(&request)
+            .map_err(|e| format!("could not encode {}: {e}", file.path.display()))
+            .and_then(|request| self.run(&request, project_root));
+        match response {
+            Ok(response) => response
+                .diagnostics
+                .into_iter()
+                .map(|d| self.diagnostic(&file.path, d))
+                .collect(),
+            Err(reason) => vec![Diagnostic {
+                file: Arc::clone(&file.path),
+                line: 1,
+                column: None,
+                end_line: None,
+                end_column: None,
+                severity: Severity::Error,
+                category: Category::CustomPattern(self.name.as_str().into()),
+                message: format!("Plugin `{}` {reason}", self.name),
+                suggestion: None,
+                fix: None,
+                related: Vec::new(),
+            }],
+        }
+    }
+
+    fn diagnostic(&self, path: &Arc<PathBuf>, d: PluginDiagnostic) -> Diagnostic {
+        let category = d.category.as_deref().unwrap_or(&self.name);
+        let category = category.strip_prefix("custom:").unwrap_or(category);
+        Diagnostic {
+            file: Arc::clone(path),
+            line: d.line,
+            column: d.column,
+            end_line: d.end_line,
+            end_column: d.end_column,
+            severity: self.severity.or(d.severity).unwrap_or(Severity::Warning),
+            category: Category::CustomPattern(category.into()),
+            message: d.message,
+            suggestion: d.suggestion,
+            fix: d.fix.map(Box::new),
+            related: Vec::new(),
+        }
+    }
+
+    /// Run the plugin on one request, killing it after the timeout.
+    fn run(&self, request: &[u8], project_root: &Path) -> Result<Response, String> {
+        let Some((program, args)) = self.command.split_first() else {
+            return Err("has an empty `command`".to_string());
+        };
+        let mut command = Command::new(program);
+        command
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        if project_root.is_dir() {
+            command.current_dir(project_root);
+        }
+        let mut child = command
+            .spawn()
+            .map_err(|e| format!("could not be started: {e}"))?;
+
+        // Feed stdin and drain the output pipes on their own threads so a
+        // plugin blocked on a full pipe cannot stall until the timeout.
+        let mut stdin = child.stdin.take();
+        let request = request.to_vec();
+        thread::spawn(move || {
+            if let Some(stdin) = stdin.as_mut() {
+                // A plugin may exit without reading its input.
+                let _ = stdin.write_all(&request);
+            }
+        });
+        let stdout = drain(child.stdout.take());
+        let stderr = drain(child.stderr.take());
+
+        let deadline = Instant::now() + self.timeout;
+        let status = loop {
+            match child.try_wait() {
+                Ok(Some(status)) => break status,
+                Ok(None) if Instant::now() >= deadline => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(format!("timed out after {}s", self.timeout.as_secs()));
+                }
+                Ok(None) => thread::sleep(POLL_INTERVAL),
+                Err(e) => return Err(format!("could not be waited on: {e}")),
+            }
+        };
+
+        let stdout = stdout.join().unwrap_or_default();
+        let stderr = stderr.join().unwrap_or_default();
+        if !status.success() {
+            let stderr = String::from_utf8_lossy(&stderr);
+            return Err(match stderr.lines().rev().find(|l| !l.trim().is_empty()) {
+                Some(last) => format!("failed ({status}): {}", last.trim()),
+                None => format!("failed ({status})"),
+            });
+        }
+        serde_json::from_slice(&stdout).map_err(|e| format!("returned invalid output: {e}"))
+    }
+}
+
+fn drain(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        buf
+    })
+}
+
+impl Checker for PluginChecker {
+    fn meta(&self) -> RuleMeta {
+        RuleMeta {
+            name: "plugin",
+            description: "External checker run as a subprocess",
+            default_severity: Severity::Warning,
+            strict_only: false,
+        }
+    }
+
+    fn check(&self, ctx: &CheckerContext) -> CheckResult {
+        let diagnostics = ctx
+            .files
+            .par_iter()
+            .filter(|file| self.scope.includes(&file.path, &ctx.project_root))
+            .flat_map_iter(|file| self.check_file(file, &ctx.project_root))
+            .collect();
+        CheckResult { diagnostics }
+    }
+
+    fn is_project_wide(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::checkers::utils::test_helpers::single_file_ctx;
+
+    fn plugin(script: &str, timeout: u64) -> PluginChecker {
+        PluginChecker::new(
+            &PluginConfig {
+                name: "security".to_string(),
+                command: vec!["sh".to_string(), "-c".to_string(), script.to_string()],
+                timeout,
+                scope: Vec::new(),
+                severity: None,
+            },
+            Path::new("/"),
+        )
+    }
+
+    fn run(checker: &PluginChecker) -> Vec<Diagnostic> {
+        let (_dir, ctx) = single_file_ctx(&["# Rules", "", "Use the staging key."]);
+        checker.check(&ctx).diagnostics
+    }
+
+    #[test]
+    fn test_plugin_findings_become_diagnostics() {
+        let checker = plugin(
+            r##"grep -q '"raw_lines":\["# Rules","","Use the staging key."\]' || exit 1
+            echo '{"diagnostics": [
+                {"line": 3, "column": 9, "message": "Secret name", "category": "secret-name",
+                 "fix": {"description": "Redact", "replacements":
+                   [{"line": 3, "start_col": 8, "end_col": 15, "new_text": "[redacted]"}]}},
+                {"line": 1, "severity": "info", "message": "Checked"}
+            ]}'"##,
+            10,
+        );
+        let diagnostics = run(&checker);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].line, 3);
+        assert_eq!(diagnostics[0].column, Some(9));
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(diagnostics[0].category.to_string(), "custom:secret-name");
+        assert_eq!(diagnostics[0].fix.as_ref().unwrap().replacements.len(), 1);
+        assert_eq!(diagnostics[1].severity, Severity::Info);
+        assert_eq!(diagnostics[1].category.to_string(), "custom:security");
+    }
+
+    #[test]
+    fn test_severity_override_applies_to_all_findings() {
+        let mut checker = plugin(
+            r#"echo '{"diagnostics": [{"line": 1, "severity": "info", "message": "x"}]}'"#,
+            10,
+        );
+        checker.severity = Some(Severity::Error);
+        assert_eq!(run(&checker)[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_crash_is_reported_with_stderr() {
+        let diagnostics = run(&plugin("echo 'rules.yml not found' >&2; exit 3", 10));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics[0].category.to_string(), "custom:security");
+        assert!(
+            diagnostics[0].message.contains("rules.yml not found"),
+            "{}",
+            diagnostics[0].message
+        );
+    }
+
+    #[test]
+    fn test_invalid_output_is_reported() {
+        let diagnostics = run(&plugin("echo 'no findings'", 10));
+        assert!(
+            diagnostics[0].message.contains("returned invalid output"),
+            "{}",
+            diagnostics[0].message
+        );
+    }
+
+    #[test]
+    fn test_timeout_kills_plugin() {
+        let start = Instant::now();
+        let diagnostics = run(&plugin("sleep 30", 1));
+        assert!(start.elapsed() < Duration::from_secs(10));
+        assert_eq!(
+            diagnostics[0].message,
+            "Plugin `security` timed out after 1s"
+        );
+    }
+
+    #[test]
+    fn test_missing_program_is_reported() {
+        let checker = PluginChecker::new(
+            &PluginConfig {
+                name: "security".to_string(),
+                command: vec!["tools/missing-plugin".to_string()],
+                timeout: 10,
+                scope: Vec::new(),
+                severity: None,
+            },
+            Path::new("/nonexistent"),
+        );
+        assert_eq!(checker.command[0], "/nonexistent/tools/missing-plugin");
+        let diagnostics = run(&checker);
+        assert!(
+            diagnostics[0].message.contains("could not be started"),
+            "{}",
+            diagnostics[0].message
+        );
+    }
+}
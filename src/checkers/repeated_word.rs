@@ -3,10 +3,10 @@ use std::sync::LazyLock;
 
 use crate::emit;
 use crate::engine::cross_ref::CheckerContext;
-use crate::parser::non_code_lines_masked;
+use crate::parser::{inside_inline_code, non_code_lines_masked};
 use crate::types::{Category, CheckResult, Fix, Replacement, RuleMeta, Severity};
 
-use super::utils::{inside_inline_code, ScopeFilter};
+use super::utils::{ScopeFilter, Span};
 use super::Checker;
 
 /// Pattern to extract word tokens from a line.
@@ -87,6 +87,7 @@ impl Checker for RepeatedWordChecker {
                                     line_num,
                                     Severity::Info,
                                     Category::RepeatedWord,
+                                    span: Span::in_line(line, first.start(), second.end()),
                                     fix: fix,
                                     suggest: &format!("Remove the duplicate \"{lower}\""),
                                     "repeated word: \"{lower} {lower}\""
@@ -118,6 +119,13 @@ mod tests {
         let result = check(&["The the dog ran"]);
         assert_eq!(result.diagnostics.len(), 1);
         assert_eq!(result.diagnostics[0].category, Category::RepeatedWord);
+        assert_eq!(
+            (
+                result.diagnostics[0].column,
+                result.diagnostics[0].end_column
+            ),
+            (Some(1), Some(8))
+        );
     }
 
     #[test]
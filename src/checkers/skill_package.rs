@@ -0,0 +1,491 @@
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
+
+use regex::Regex;
+
+use crate::emit;
+use crate::engine::cross_ref::CheckerContext;
+use crate::parser::types::{FileRef, FileRefKind, ParsedFile};
+use crate::parser::MAX_FILE_SIZE;
+use crate::types::{Category, CheckResult, RuleMeta, Severity};
+
+use super::dead_reference::resolve_mention;
+use super::utils::{is_skill_manifest, is_template_ref, ScopeFilter, SKIP_DIRS};
+use super::{Checker, ProjectInputs};
+
+/// Bundled files are rarely nested deeply; this bounds the folder walk.
+const MAX_SKILL_DEPTH: usize = 8;
+
+/// Backtick-quoted or linked relative path with an extension, e.g.
+/// `` `scripts/fill_form.py` `` or `[forms](forms.md)`. Parsed `FileRef`s only
+/// cover `.md` mentions; skills also bundle scripts and data files.
+static RESOURCE_REF: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"`((?:\./)?[\w-][\w.-]*(?:/[\w.-]+)*\.[A-Za-z0-9]+)`|\]\(((?:\./)?[\w-][\w.-]*(?:/[\w.-]+)*\.[A-Za-z0-9]+)\)")
+        .unwrap()
+});
+
+/// Extensions of files a skill bundles. A backticked token without a `/` is
+/// only taken for a file with one of these, since `json.loads` or `3.11` in
+/// prose are code and versions, not files.
+const RESOURCE_EXTENSIONS: &[&str] = &[
+    "py", "sh", "bash", "zsh", "ps1", "js", "mjs", "cjs", "ts", "rb", "pl", "lua", "md", "txt",
+    "rst", "json", "jsonl", "yaml", "yml", "toml", "csv", "tsv", "xml", "html", "sql", "ipynb",
+    "pdf", "docx", "xlsx", "pptx", "png", "jpg", "svg", "j2", "tmpl",
+];
+
+/// True if a backticked token names a bundled file rather than code.
+fn is_resource_token(token: &str) -> bool {
+    token.contains('/')
+        || Path::new(token)
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| RESOURCE_EXTENSIONS.contains(&e.to_ascii_lowercase().as_str()))
+}
+
+pub(crate) struct SkillPackageChecker {
+    scope: ScopeFilter,
+    /// Reference docs the last run read from skill folders.
+    external: Mutex<Vec<PathBuf>>,
+}
+
+impl SkillPackageChecker {
+    pub(crate) fn new(scope_patterns: &[String]) -> Self {
+        Self {
+            scope: ScopeFilter::new(scope_patterns),
+            external: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+/// Parsed `.md` mentions plus backtick/link mentions of any other file type.
+fn resource_refs(file: &ParsedFile) -> Vec<FileRef> {
+    let mut refs: Vec<FileRef> = file
+        .file_refs
+        .iter()
+        .filter(|r| r.kind == FileRefKind::Mention)
+        .cloned()
+        .collect();
+    for (idx, line) in file.non_code_lines() {
+        for cap in RESOURCE_REF.captures_iter(line) {
+            let path = match (cap.get(1), cap.get(2)) {
+                (Some(backticked), _) if is_resource_token(backticked.as_str()) => {
+                    backticked.as_str()
+                }
+                (_, Some(linked)) => linked.as_str(),
+                _ => continue,
+            };
+            if !refs.iter().any(|r| r.path == path && r.line == idx + 1) {
+                refs.push(FileRef {
+                    path: path.to_string(),
+                    line: idx + 1,
+                    source_file: (*file.path).clone(),
+                    kind: FileRefKind::Mention,
+                });
+            }
+        }
+    }
+    refs
+}
+
+/// Every regular file under `dir`, skipping dotfiles and build directories.
+fn collect_files(dir: &Path, depth: usize, out: &mut Vec<PathBuf>) {
+    if depth >= MAX_SKILL_DEPTH {
+        return;
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        let Ok(ft) = entry.file_type() else {
+            continue;
+        };
+        if name.starts_with('.') || ft.is_symlink() {
+            continue;
+        }
+        if ft.is_dir() {
+            if !SKIP_DIRS.contains(&name.as_ref()) {
+                collect_files(&entry.path(), depth + 1, out);
+            }
+        } else {
+            out.push(entry.path());
+        }
+    }
+}
+
+/// True if `text` names `needle` as a whole path: bounded by the start or end
+/// of the text, whitespace, a path separator, or quoting and link punctuation,
+/// so `a.py` is not found inside `data.py`.
+fn mentions(text: &str, needle: &str) -> bool {
+    let starts = |c: char| c.is_whitespace() || "/`([\"'".contains(c);
+    let ends = |rest: &str| {
+        let mut chars = rest.chars();
+        match chars.next() {
+            None => true,
+            Some('.') => chars.next().map_or(true, char::is_whitespace),
+            Some(c) => c.is_whitespace() || "`)]\"',;:".contains(c),
+        }
+    };
+    !needle.is_empty()
+        && text.match_indices(needle).any(|(i, _)| {
+            text[..i].chars().next_back().map_or(true, starts) && ends(&text[i + needle.len()..])
+        })
+}
+
+/// Text of every markdown file in the skill folder, used to decide whether a
+/// bundled file is referenced (directly from SKILL.md or from a reference doc).
+fn skill_texts(files: &[PathBuf], manifest: &ParsedFile) -> Vec<(PathBuf, String)> {
+    let mut texts = vec![((*manifest.path).clone(), manifest.raw_lines.join("\n"))];
+    for path in files {
+        if path.extension().is_some_and(|e| e == "md")
+            && path.as_path() != manifest.path.as_path()
+            && std::fs::metadata(path).is_ok_and(|m| m.len() <= MAX_FILE_SIZE)
+        {
+            if let Ok(content) = std::fs::read_to_string(path) {
+                texts.push((path.clone(), content));
+            }
+        }
+    }
+    texts
+}
+
+impl Checker for SkillPackageChecker {
+    fn meta(&self) -> RuleMeta {
+        RuleMeta {
+            name: "skill-package",
+            description: "Validates Agent Skill folders: SKILL.md frontmatter, bundled files, and unreferenced files",
+            default_severity: Severity::Warning,
+            strict_only: false,
+        }
+    }
+
+    fn is_project_wide(&self) -> bool {
+        true
+    }
+
+    fn project_inputs(&self) -> ProjectInputs {
+        ProjectInputs::FILES_AND_LISTING
+    }
+
+    fn external_inputs(&self) -> Vec<PathBuf> {
+        self.external
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn check(&self, ctx: &CheckerContext) -> CheckResult {
+        let mut result = CheckResult::default();
+        let mut external = Vec::new();
+
+        for file in &ctx.files {
+            if !is_skill_manifest(&file.path, &ctx.project_root)
+                || !self.scope.includes(&file.path, &ctx.project_root)
+            {
+                continue;
+            }
+            let Some(skill_dir) = file.path.parent() else {
+                continue;
+            };
+            let dir_name = skill_dir.file_name().unwrap_or_default().to_string_lossy();
+
+            for key in ["name", "description"] {
+                if file
+                    .frontmatter_get(key)
+                    .map_or(true, |e| e.value.is_empty())
+                {
+                    emit!(
+                        result,
+                        file.path,
+                        1,
+                        Severity::Error,
+                        Category::SkillPackage,
+                        suggest: format!("Add `{key}:` to the SKILL.md frontmatter"),
+                        "Skill is missing a `{}` in its frontmatter",
+                        key
+                    );
+                }
+            }
+            if let Some(entry) = file.frontmatter_get("name") {
+                if let Some(name) = entry.value.as_str().filter(|n| !n.is_empty()) {
+                    if name != dir_name {
+                        emit!(
+                            result,
+                            file.path,
+                            entry.line,
+                            Severity::Warning,
+                            Category::SkillPackage,
+                            suggest: "Rename the skill or its folder so they match",
+                            "Skill name \"{}\" does not match its folder \"{}\"",
+                            name,
+                            dir_name
+                        );
+                    }
+                }
+            }
+
+            let canonical_dir = skill_dir
+                .canonicalize()
+                .unwrap_or_else(|_| skill_dir.to_path_buf());
+            for file_ref in resource_refs(file) {
+                if is_template_ref(&file_ref.path) {
+                    continue;
+                }
+                match resolve_mention(&file_ref, ctx) {
+                    None => {
+                        emit!(
+                            result,
+                            Arc::clone(&file.path),
+                            file_ref.line,
+                            Severity::Error,
+                            Category::SkillPackage,
+                            suggest: "Add the file to the skill folder or fix the path",
+                            "\"{}\" does not exist in the skill folder",
+                            file_ref.path
+                        );
+                    }
+                    Some(resolved)
+                        if !resolved
+                            .canonicalize()
+                            .is_ok_and(|p| p.starts_with(&canonical_dir)) =>
+                    {
+                        emit!(
+                            result,
+                            Arc::clone(&file.path),
+                            file_ref.line,
+                            Severity::Warning,
+                            Category::SkillPackage,
+                            suggest: "Copy the file into the skill folder so the skill is self-contained",
+                            "\"{}\" resolves outside the skill folder",
+                            file_ref.path
+                        );
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            let mut files = Vec::new();
+            collect_files(skill_dir, 0, &mut files);
+            files.sort();
+            let texts = skill_texts(&files, file);
+            external.extend(texts.iter().skip(1).map(|(path, _)| path.clone()));
+            for path in &files {
+                if path.as_path() == file.path.as_path() {
+                    continue;
+                }
+                let Ok(rel) = path.strip_prefix(skill_dir) else {
+                    continue;
+                };
+                let rel_str = rel.to_string_lossy().replace('\\', "/");
+                let name = path.file_name().unwrap_or_default().to_string_lossy();
+                let referenced = texts.iter().any(|(source, text)| {
+                    source != path && (mentions(text, &rel_str) || mentions(text, &name))
+                });
+                if !referenced {
+                    emit!(
+                        result,
+                        Arc::clone(&file.path),
+                        1,
+                        Severity::Info,
+                        Category::SkillPackage,
+                        suggest: "Reference the file from SKILL.md so the agent can find it, or delete it",
+                        "Bundled file \"{}\" is never referenced (dead weight)",
+                        rel_str
+                    );
+                }
+            }
+        }
+
+        *self.external.lock().unwrap_or_else(PoisonError::into_inner) = external;
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::fs;
+
+    fn check_skill(root: &Path, skill: &str, content: &str, extra: &[&str]) -> Vec<String> {
+        let dir = root.join(".claude/skills").join(skill);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("SKILL.md"), content).unwrap();
+        for rel in extra {
+            let path = dir.join(rel);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+        let file = crate::parser::parse_file(&dir.join("SKILL.md")).unwrap();
+        let ctx = CheckerContext {
+            files: vec![file],
+            settings_files: vec![],
+            project_root: root.to_path_buf(),
+            canonical_root: root.canonicalize().ok(),
+            filename_index: HashSet::new(),
+            path_index: vec![],
+            historical_indices: HashSet::new(),
+        };
+        SkillPackageChecker::new(&[])
+            .check(&ctx)
+            .diagnostics
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_skill_passes() {
+        let dir = tempfile::tempdir().unwrap();
+        let msgs = check_skill(
+            dir.path(),
+            "pdf-forms",
+            "---\nname: pdf-forms\ndescription: Fill PDF forms\n---\n# PDF forms\n\nRun `scripts/fill.py`, see [the reference](reference.md).\n",
+            &["scripts/fill.py", "reference.md"],
+        );
+        assert!(msgs.is_empty(), "unexpected: {msgs:?}");
+    }
+
+    #[test]
+    fn test_missing_frontmatter_fields() {
+        let dir = tempfile::tempdir().unwrap();
+        let msgs = check_skill(dir.path(), "bare", "# Bare skill\n", &[]);
+        assert_eq!(msgs.len(), 2);
+        assert!(msgs[0].contains("missing a `name`"));
+        assert!(msgs[1].contains("missing a `description`"));
+    }
+
+    #[test]
+    fn test_name_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let msgs = check_skill(
+            dir.path(),
+            "pdf",
+            "---\nname: pdf-tools\ndescription: PDF helpers\n---\n",
+            &[],
+        );
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("does not match its folder \"pdf\""));
+    }
+
+    #[test]
+    fn test_missing_bundled_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let msgs = check_skill(
+            dir.path(),
+            "pdf",
+            "---\nname: pdf\ndescription: PDF helpers\n---\nRun `scripts/extract.py` first.\n",
+            &[],
+        );
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("\"scripts/extract.py\" does not exist"));
+    }
+
+    #[test]
+    fn test_reference_outside_skill_folder() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("shared.md"), "").unwrap();
+        let msgs = check_skill(
+            dir.path(),
+            "pdf",
+            "---\nname: pdf\ndescription: PDF helpers\n---\nSee `shared.md`.\n",
+            &[],
+        );
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("resolves outside the skill folder"));
+    }
+
+    #[test]
+    fn test_unreferenced_file_is_dead_weight() {
+        let dir = tempfile::tempdir().unwrap();
+        let msgs = check_skill(
+            dir.path(),
+            "pdf",
+            "---\nname: pdf\ndescription: PDF helpers\n---\nSee [forms](forms.md).\n",
+            &["forms.md", "scripts/old.py", "scripts/fill.py"],
+        );
+        assert_eq!(msgs.len(), 2, "{msgs:?}");
+        assert!(msgs[0].contains("\"scripts/fill.py\" is never referenced"));
+        assert!(msgs[1].contains("\"scripts/old.py\" is never referenced"));
+    }
+
+    #[test]
+    fn test_substring_of_another_name_is_not_a_reference() {
+        let dir = tempfile::tempdir().unwrap();
+        let msgs = check_skill(
+            dir.path(),
+            "etl",
+            "---\nname: etl\ndescription: Load data\n---\nRun `data.py`, then see scripts/b.sh.\n",
+            &["data.py", "a.py", "scripts/b.sh"],
+        );
+        assert_eq!(msgs.len(), 1, "{msgs:?}");
+        assert!(msgs[0].contains("\"a.py\" is never referenced"));
+    }
+
+    #[test]
+    fn test_mentions_respects_boundaries() {
+        assert!(mentions("Run `a.py` first", "a.py"));
+        assert!(mentions("a.py", "a.py"));
+        assert!(mentions("see [it](scripts/a.py).", "a.py"));
+        assert!(mentions("Then run a.py.", "a.py"));
+        assert!(!mentions("Run `data.py`", "a.py"));
+        assert!(!mentions("Run a.pyc", "a.py"));
+        assert!(!mentions("Run a.py.bak", "a.py"));
+    }
+
+    #[test]
+    fn test_code_identifiers_are_not_resources() {
+        let dir = tempfile::tempdir().unwrap();
+        let msgs = check_skill(
+            dir.path(),
+            "parse",
+            "---\nname: parse\ndescription: Parse JSON output\n---\n\
+             Load it with `json.loads`, log with `console.log` and call `os.path.join`.\n",
+            &[],
+        );
+        assert!(msgs.is_empty(), "unexpected: {msgs:?}");
+    }
+
+    #[test]
+    fn test_version_numbers_are_not_resources() {
+        let dir = tempfile::tempdir().unwrap();
+        let msgs = check_skill(
+            dir.path(),
+            "setup",
+            "---\nname: setup\ndescription: Set up Python\n---\n\
+             Requires Python `3.11` or `v2.0.1` of the CLI.\n",
+            &[],
+        );
+        assert!(msgs.is_empty(), "unexpected: {msgs:?}");
+    }
+
+    #[test]
+    fn test_missing_script_by_extension_is_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let msgs = check_skill(
+            dir.path(),
+            "pdf",
+            "---\nname: pdf\ndescription: PDF helpers\n---\nRun `fill.py` first.\n",
+            &[],
+        );
+        assert_eq!(msgs.len(), 1, "{msgs:?}");
+        assert!(msgs[0].contains("does not exist in the skill folder"));
+    }
+
+    #[test]
+    fn test_file_referenced_from_reference_doc_is_used() {
+        let dir = tempfile::tempdir().unwrap();
+        let skill = dir.path().join(".claude/skills/pdf");
+        fs::create_dir_all(skill.join("scripts")).unwrap();
+        fs::write(skill.join("forms.md"), "Run scripts/fill.py\n").unwrap();
+        let msgs = check_skill(
+            dir.path(),
+            "pdf",
+            "---\nname: pdf\ndescription: PDF helpers\n---\nSee [forms](forms.md).\n",
+            &["scripts/fill.py"],
+        );
+        assert!(msgs.is_empty(), "unexpected: {msgs:?}");
+    }
+}
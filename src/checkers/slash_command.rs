@@ -0,0 +1,356 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, LazyLock};
+
+use regex::Regex;
+
+use crate::emit;
+use crate::engine::cross_ref::CheckerContext;
+use crate::parser::types::FileRefKind;
+use crate::types::{Category, CheckResult, RuleMeta, Severity};
+
+use super::utils::{is_command_file, resolve_import, ScopeFilter};
+use super::{Checker, ProjectInputs};
+
+/// `$ARGUMENTS` or a positional `$1`, `$2`, ...
+static PLACEHOLDER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\$(ARGUMENTS\b|[1-9][0-9]*)").unwrap());
+
+/// `!`command`` — runs the command and inlines its output before the prompt.
+static BANG_COMMAND: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"!`([^`]+)`").unwrap());
+
+/// A `[name]` or `<name>` group in `argument-hint`.
+static HINT_ARG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\[[^\]]*\]|<[^>]*>").unwrap());
+
+pub(crate) struct SlashCommandChecker {
+    scope: ScopeFilter,
+}
+
+impl SlashCommandChecker {
+    pub(crate) fn new(scope_patterns: &[String]) -> Self {
+        Self {
+            scope: ScopeFilter::new(scope_patterns),
+        }
+    }
+}
+
+/// Arguments named by one `argument-hint` alternative: bracketed groups if
+/// there are any, otherwise the whitespace-separated words.
+fn hint_args(hint: &str) -> Vec<&str> {
+    let groups: Vec<&str> = HINT_ARG.find_iter(hint).map(|m| m.as_str()).collect();
+    if groups.is_empty() {
+        hint.split_whitespace().collect()
+    } else {
+        groups
+    }
+}
+
+/// True if an `allowed-tools` entry permits running `command` via Bash.
+/// `Bash(git:*)` and `Bash(git *)` match whole words, so they allow `git` and
+/// `git status` but not `gitk`; `Bash(git*)` is a raw prefix.
+fn bash_allows(entry: &str, command: &str) -> bool {
+    let entry = entry.trim();
+    if entry == "Bash" {
+        return true;
+    }
+    let Some(spec) = entry
+        .strip_prefix("Bash(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    else {
+        return false;
+    };
+    let word_prefix = |prefix: &str| {
+        command
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with(' '))
+    };
+    if let Some(prefix) = spec.strip_suffix(":*") {
+        return word_prefix(prefix.trim_end());
+    }
+    match spec.strip_suffix('*') {
+        Some(prefix) if prefix.ends_with(char::is_whitespace) => word_prefix(prefix.trim_end()),
+        Some(prefix) => command.starts_with(prefix),
+        None => command == spec,
+    }
+}
+
+impl Checker for SlashCommandChecker {
+    fn meta(&self) -> RuleMeta {
+        RuleMeta {
+            name: "slash-command",
+            description: "Validates argument placeholders, !-commands and @-file references in .claude/commands/",
+            default_severity: Severity::Warning,
+            strict_only: false,
+        }
+    }
+
+    fn is_project_wide(&self) -> bool {
+        true
+    }
+
+    fn project_inputs(&self) -> ProjectInputs {
+        ProjectInputs::FILES_AND_LISTING
+    }
+
+    fn check(&self, ctx: &CheckerContext) -> CheckResult {
+        let mut result = CheckResult::default();
+
+        for file in &ctx.files {
+            if !is_command_file(&file.path, &ctx.project_root)
+                || !self.scope.includes(&file.path, &ctx.project_root)
+            {
+                continue;
+            }
+
+            let body = file
+                .raw_lines
+                .iter()
+                .enumerate()
+                .skip(file.frontmatter.as_ref().map_or(0, |f| f.end_line));
+
+            // First line each positional argument appears on.
+            let mut positional: BTreeMap<usize, usize> = BTreeMap::new();
+            let mut uses_arguments = false;
+            let mut bang_commands: Vec<(usize, &str)> = Vec::new();
+            for (idx, line) in body {
+                for cap in PLACEHOLDER.captures_iter(line) {
+                    match cap[1].parse::<usize>() {
+                        Ok(n) => {
+                            positional.entry(n).or_insert(idx + 1);
+                        }
+                        Err(_) => uses_arguments = true,
+                    }
+                }
+                for cap in BANG_COMMAND.captures_iter(line) {
+                    bang_commands.push((idx + 1, cap.get(1).unwrap().as_str().trim()));
+                }
+            }
+
+            let hint = file.frontmatter_get("argument-hint");
+            // Read the hint from the source line: YAML would parse a hint like
+            // `[file] [mode]` as a flow list and lose the argument groups.
+            let hint_text = hint
+                .and_then(|h| file.raw_lines.get(h.line - 1))
+                .and_then(|line| line.split_once(':'))
+                .map_or("", |(_, rest)| rest.trim().trim_matches(['"', '\'']));
+            let alternatives: Vec<Vec<&str>> = hint_text.split('|').map(hint_args).collect();
+            let declared = alternatives.iter().map(Vec::len).max().unwrap_or(0);
+
+            for (&n, &line) in positional.range(declared + 1..) {
+                emit!(
+                    result,
+                    file.path,
+                    line,
+                    Severity::Warning,
+                    Category::SlashCommand,
+                    suggest: "List every positional argument in `argument-hint`, e.g. `argument-hint: [pr-number] [priority]`",
+                    "Positional argument ${} is used but not listed in argument-hint",
+                    n
+                );
+            }
+
+            if let (Some(hint), false) = (hint, uses_arguments) {
+                if declared > 0 && positional.is_empty() {
+                    emit!(
+                        result,
+                        file.path,
+                        hint.line,
+                        Severity::Warning,
+                        Category::SlashCommand,
+                        suggest: "Reference the arguments with `$ARGUMENTS` or `$1`, `$2`, ... or remove the hint",
+                        "argument-hint declares {} argument(s) but the command never uses them",
+                        declared
+                    );
+                } else if alternatives.len() == 1 {
+                    for (i, name) in alternatives[0].iter().enumerate() {
+                        if !positional.contains_key(&(i + 1)) {
+                            emit!(
+                                result,
+                                file.path,
+                                hint.line,
+                                Severity::Warning,
+                                Category::SlashCommand,
+                                suggest: "Use the argument in the command body or remove it from the hint",
+                                "argument-hint argument {} (${}) is never used",
+                                name,
+                                i + 1
+                            );
+                        }
+                    }
+                }
+            }
+
+            if !bang_commands.is_empty() {
+                let allowed = file.frontmatter_get("allowed-tools");
+                let entries = allowed.map(|a| a.value.items()).unwrap_or_default();
+                for (line, command) in bang_commands {
+                    if !entries.iter().any(|e| bash_allows(e, command)) {
+                        emit!(
+                            result,
+                            file.path,
+                            line,
+                            Severity::Warning,
+                            Category::SlashCommand,
+                            suggest: format!("Add `Bash({command}:*)` or a matching rule to `allowed-tools`"),
+                            "Command `!{}` is not permitted by allowed-tools",
+                            command
+                        );
+                    }
+                }
+            }
+
+            for file_ref in &file.file_refs {
+                if file_ref.kind != FileRefKind::Import {
+                    continue;
+                }
+                let Some(resolved) = resolve_import(file_ref, &ctx.project_root) else {
+                    continue;
+                };
+                if !resolved.exists() {
+                    emit!(
+                        result,
+                        Arc::clone(&file.path),
+                        file_ref.line,
+                        Severity::Error,
+                        Category::SlashCommand,
+                        suggest: "Command `@file` references resolve from the project root; fix the path",
+                        "File reference \"@{}\" does not exist",
+                        file_ref.path
+                    );
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::types::ParsedFile;
+    use std::collections::HashSet;
+    use std::path::Path;
+
+    fn check_in(root: &Path, name: &str, content: &str) -> Vec<String> {
+        let path = root.join(".claude/commands").join(name);
+        let raw_lines: Vec<String> = content.lines().map(String::from).collect();
+        let in_code_block = crate::parser::build_code_block_mask(&raw_lines);
+        let file = ParsedFile {
+            path: Arc::new(path.clone()),
+            sections: vec![],
+            tables: vec![],
+            file_refs: crate::parser::extract_import_refs(&raw_lines, &path),
+            directives: vec![],
+            suppress_comments: vec![],
+            frontmatter: crate::parser::frontmatter::parse_frontmatter(&raw_lines),
+            raw_lines,
+            in_code_block,
+        };
+        let ctx = CheckerContext {
+            files: vec![file],
+            settings_files: vec![],
+            project_root: root.to_path_buf(),
+            canonical_root: None,
+            filename_index: HashSet::new(),
+            path_index: vec![],
+            historical_indices: HashSet::new(),
+        };
+        SlashCommandChecker::new(&[])
+            .check(&ctx)
+            .diagnostics
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    fn check(content: &str) -> Vec<String> {
+        check_in(Path::new("/project"), "cmd.md", content)
+    }
+
+    #[test]
+    fn test_valid_command_passes() {
+        let msgs = check(
+            "---\nallowed-tools: Bash(git status:*), Bash(git diff:*)\nargument-hint: [pr-number] [priority]\n---\n## Context\n- Status: !`git status --short`\n\nReview PR #$1 with priority $2.\n",
+        );
+        assert!(msgs.is_empty(), "unexpected: {msgs:?}");
+    }
+
+    #[test]
+    fn test_positional_not_in_hint() {
+        let msgs = check("---\nargument-hint: [file]\n---\nCompare $1 with $2.\n");
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("$2 is used but not listed"));
+    }
+
+    #[test]
+    fn test_positional_without_hint() {
+        let msgs = check("Fix issue $1.\n");
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("$1 is used"));
+    }
+
+    #[test]
+    fn test_hint_never_used() {
+        let msgs = check("---\nargument-hint: <issue>\n---\nFix the bug.\n");
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("never uses them"));
+    }
+
+    #[test]
+    fn test_hint_argument_partly_unused() {
+        let msgs = check("---\nargument-hint: [file] [mode]\n---\nOpen $1.\n");
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("[mode] ($2) is never used"));
+    }
+
+    #[test]
+    fn test_arguments_placeholder_covers_hint() {
+        let msgs =
+            check("---\nargument-hint: add [tag] | remove [tag] | list\n---\nRun: $ARGUMENTS\n");
+        assert!(msgs.is_empty(), "unexpected: {msgs:?}");
+    }
+
+    #[test]
+    fn test_bang_command_not_allowed() {
+        let msgs = check(
+            "---\nallowed-tools: Bash(git status:*)\n---\n- Log: !`git log --oneline -5`\n- Status: !`git status`\n",
+        );
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("`!git log --oneline -5` is not permitted"));
+    }
+
+    #[test]
+    fn test_bang_command_without_allowed_tools() {
+        let msgs = check("Branch: !`git branch --show-current`\n");
+        assert_eq!(msgs.len(), 1);
+    }
+
+    #[test]
+    fn test_file_reference_resolves_from_project_root() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("src/lib.rs"), "").unwrap();
+        let msgs = check_in(
+            dir.path(),
+            "review.md",
+            "Review @src/lib.rs and @src/missing.rs\n",
+        );
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("\"@src/missing.rs\" does not exist"));
+    }
+
+    #[test]
+    fn test_bash_allows() {
+        assert!(bash_allows("Bash", "rm -rf build"));
+        assert!(bash_allows("Bash(git add:*)", "git add ."));
+        assert!(bash_allows("Bash(git:*)", "git"));
+        assert!(!bash_allows("Bash(git:*)", "gitk"));
+        assert!(!bash_allows("Bash(git:*)", "git-foo bar"));
+        assert!(bash_allows("Bash(git *)", "git status"));
+        assert!(!bash_allows("Bash(git *)", "gitk"));
+        assert!(bash_allows("Bash(npm run test*)", "npm run test:unit"));
+        assert!(bash_allows("Bash(npm test)", "npm test"));
+        assert!(!bash_allows("Bash(npm test)", "npm test --watch"));
+        assert!(!bash_allows("Read", "cat file"));
+    }
+}
@@ -3,9 +3,10 @@ use std::sync::LazyLock;
 
 use crate::emit;
 use crate::engine::cross_ref::CheckerContext;
+use crate::parser::inside_inline_code;
 use crate::types::{Category, CheckResult, RuleMeta, Severity};
 
-use super::utils::{inside_inline_code, ScopeFilter};
+use super::utils::ScopeFilter;
 use super::Checker;
 
 /// Pattern to extract month + year from stale reference matches.
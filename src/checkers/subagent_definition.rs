@@ -0,0 +1,310 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::emit;
+use crate::engine::cross_ref::CheckerContext;
+use crate::types::{Category, CheckResult, RuleMeta, Severity};
+
+use super::outdated_model_reference::is_outdated_model;
+use super::utils::{is_known_tool, ScopeFilter};
+use super::{Checker, ProjectInputs};
+
+/// Claude decides when to delegate from the description alone; anything
+/// shorter than this rarely says when the agent should be used.
+const MIN_DESCRIPTION_WORDS: usize = 5;
+
+pub(crate) struct SubagentDefinitionChecker {
+    scope: ScopeFilter,
+}
+
+impl SubagentDefinitionChecker {
+    pub(crate) fn new(scope_patterns: &[String]) -> Self {
+        Self {
+            scope: ScopeFilter::new(scope_patterns),
+        }
+    }
+}
+
+/// True for markdown files under `.claude/agents/`.
+fn is_agent_file(path: &Path, root: &Path) -> bool {
+    path.extension().is_some_and(|e| e == "md")
+        && path
+            .strip_prefix(root)
+            .is_ok_and(|rel| rel.starts_with(".claude/agents"))
+}
+
+impl Checker for SubagentDefinitionChecker {
+    fn meta(&self) -> RuleMeta {
+        RuleMeta {
+            name: "subagent-definition",
+            description: "Validates subagent frontmatter in .claude/agents/ (name, description, tools, model)",
+            default_severity: Severity::Warning,
+            strict_only: false,
+        }
+    }
+
+    fn is_project_wide(&self) -> bool {
+        true
+    }
+
+    fn project_inputs(&self) -> ProjectInputs {
+        ProjectInputs::FILES
+    }
+
+    fn check(&self, ctx: &CheckerContext) -> CheckResult {
+        let mut result = CheckResult::default();
+        let mut seen_names: HashMap<String, &Path> = HashMap::new();
+
+        for file in &ctx.files {
+            if !is_agent_file(&file.path, &ctx.project_root)
+                || !self.scope.includes(&file.path, &ctx.project_root)
+            {
+                continue;
+            }
+
+            let name = file
+                .frontmatter_get("name")
+                .and_then(|e| e.value.as_str().map(|v| (e.line, v.trim())))
+                .filter(|(_, v)| !v.is_empty());
+
+            match name {
+                None => {
+                    emit!(
+                        result,
+                        file.path,
+                        1,
+                        Severity::Error,
+                        Category::SubagentDefinition,
+                        suggest: "Add a `name:` field to the frontmatter",
+                        "Subagent is missing a `name` in its frontmatter"
+                    );
+                }
+                Some((line, name)) => {
+                    let stem = file.path.file_stem().and_then(|s| s.to_str());
+                    if stem.is_some_and(|stem| stem != name) {
+                        emit!(
+                            result,
+                            file.path,
+                            line,
+                            Severity::Warning,
+                            Category::SubagentDefinition,
+                            suggest: "Rename the file or the agent so they match",
+                            "Subagent name \"{}\" does not match filename \"{}.md\"",
+                            name,
+                            stem.unwrap_or_default()
+                        );
+                    }
+                    if let Some(first) = seen_names.get(name) {
+                        let first = first.strip_prefix(&ctx.project_root).unwrap_or(first);
+                        emit!(
+                            result,
+                            file.path,
+                            line,
+                            Severity::Error,
+                            Category::SubagentDefinition,
+                            suggest: "Give each subagent a unique name",
+                            "Duplicate subagent name \"{}\" (also defined in {})",
+                            name,
+                            first.display()
+                        );
+                    } else {
+                        seen_names.insert(name.to_string(), &file.path);
+                    }
+                }
+            }
+
+            match file.frontmatter_get("description") {
+                Some(entry) if !entry.value.is_empty() => {
+                    let words = entry
+                        .value
+                        .as_str()
+                        .map_or(0, |d| d.split_whitespace().count());
+                    if words < MIN_DESCRIPTION_WORDS {
+                        emit!(
+                            result,
+                            file.path,
+                            entry.line,
+                            Severity::Warning,
+                            Category::SubagentDefinition,
+                            suggest: "Describe what the agent does and when to use it so Claude can delegate to it",
+                            "Subagent description is too short to drive delegation ({} words)",
+                            words
+                        );
+                    }
+                }
+                _ => {
+                    emit!(
+                        result,
+                        file.path,
+                        1,
+                        Severity::Error,
+                        Category::SubagentDefinition,
+                        suggest: "Add a `description:` saying when this agent should be used",
+                        "Subagent is missing a `description` in its frontmatter"
+                    );
+                }
+            }
+
+            if let Some(tools) = file.frontmatter_get("tools") {
+                for tool in tools.value.items() {
+                    let name = tool.split_once('(').map_or(tool, |(name, _)| name).trim();
+                    if !is_known_tool(name) {
+                        emit!(
+                            result,
+                            Arc::clone(&file.path),
+                            tools.line,
+                            Severity::Warning,
+                            Category::SubagentDefinition,
+                            suggest: "Check the tool name spelling; built-in tools are PascalCase (e.g. `Read`, `Grep`)",
+                            "Unknown tool \"{}\" in subagent `tools`",
+                            name
+                        );
+                    }
+                }
+            }
+
+            if let Some(model) = file.frontmatter_get("model") {
+                if let Some(value) = model.value.as_str().filter(|v| is_outdated_model(v)) {
+                    emit!(
+                        result,
+                        file.path,
+                        model.line,
+                        Severity::Warning,
+                        Category::SubagentDefinition,
+                        suggest: "Use a current model alias such as `sonnet`, `opus`, `haiku`, or `inherit`",
+                        "Subagent uses deprecated model \"{}\"",
+                        value
+                    );
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::types::ParsedFile;
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    fn make_file(rel: &str, content: &str) -> ParsedFile {
+        let raw_lines: Vec<String> = content.lines().map(String::from).collect();
+        let in_code_block = crate::parser::build_code_block_mask(&raw_lines);
+        ParsedFile {
+            path: Arc::new(PathBuf::from("/project").join(rel)),
+            sections: vec![],
+            tables: vec![],
+            file_refs: vec![],
+            directives: vec![],
+            suppress_comments: vec![],
+            frontmatter: crate::parser::frontmatter::parse_frontmatter(&raw_lines),
+            raw_lines,
+            in_code_block,
+        }
+    }
+
+    fn check_files(files: Vec<ParsedFile>) -> Vec<String> {
+        let ctx = CheckerContext {
+            files,
+            settings_files: vec![],
+            project_root: PathBuf::from("/project"),
+            canonical_root: None,
+            filename_index: HashSet::new(),
+            path_index: vec![],
+            historical_indices: HashSet::new(),
+        };
+        SubagentDefinitionChecker::new(&[])
+            .check(&ctx)
+            .diagnostics
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    fn check(name: &str, content: &str) -> Vec<String> {
+        check_files(vec![make_file(&format!(".claude/agents/{name}"), content)])
+    }
+
+    const VALID: &str = "---\nname: reviewer\ndescription: Reviews Rust changes for safety issues before merge\ntools: Read, Grep, Glob\nmodel: sonnet\n---\nYou review code.\n";
+
+    #[test]
+    fn test_valid_agent_passes() {
+        let msgs = check("reviewer.md", VALID);
+        assert!(msgs.is_empty(), "unexpected: {msgs:?}");
+    }
+
+    #[test]
+    fn test_missing_name_and_description() {
+        let msgs = check("bare.md", "You review code.\n");
+        assert_eq!(msgs.len(), 2);
+        assert!(msgs[0].contains("missing a `name`"));
+        assert!(msgs[1].contains("missing a `description`"));
+    }
+
+    #[test]
+    fn test_name_mismatch_with_filename() {
+        let msgs = check("code-reviewer.md", VALID);
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("does not match filename \"code-reviewer.md\""));
+    }
+
+    #[test]
+    fn test_duplicate_names() {
+        let msgs = check_files(vec![
+            make_file(".claude/agents/reviewer.md", VALID),
+            make_file(".claude/agents/team/reviewer.md", VALID),
+        ]);
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("also defined in .claude/agents/reviewer.md"));
+    }
+
+    #[test]
+    fn test_unknown_tools() {
+        let msgs = check(
+            "reviewer.md",
+            "---\nname: reviewer\ndescription: Reviews Rust changes for safety issues\ntools:\n  - Read\n  - grep\n  - mcp__github__get_pr\n---\n",
+        );
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("Unknown tool \"grep\""));
+    }
+
+    #[test]
+    fn test_unknown_tools_in_unindented_list() {
+        let msgs = check(
+            "reviewer.md",
+            "---\nname: reviewer\ndescription: Reviews Rust changes for safety issues\ntools:\n- Read\n- grep\n---\n",
+        );
+        assert_eq!(msgs.len(), 1, "{msgs:?}");
+        assert!(msgs[0].contains("Unknown tool \"grep\""));
+    }
+
+    #[test]
+    fn test_short_description() {
+        let msgs = check(
+            "reviewer.md",
+            "---\nname: reviewer\ndescription: Reviews code\n---\n",
+        );
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("too short"));
+    }
+
+    #[test]
+    fn test_deprecated_model() {
+        let msgs = check(
+            "reviewer.md",
+            "---\nname: reviewer\ndescription: Reviews Rust changes for safety issues\nmodel: claude-3-opus-20240229\n---\n",
+        );
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].contains("deprecated model \"claude-3-opus-20240229\""));
+    }
+
+    #[test]
+    fn test_files_outside_agents_dir_ignored() {
+        let msgs = check_files(vec![make_file("CLAUDE.md", "# Project\n")]);
+        assert!(msgs.is_empty());
+    }
+}
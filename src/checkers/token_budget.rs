@@ -24,7 +24,7 @@ impl TokenBudgetChecker {
 
 /// Estimate the token count for the given lines by joining them with newlines
 /// and dividing total character count by 4 (~4 chars per token for English text).
-fn estimate_tokens(raw_lines: &[String]) -> usize {
+pub(crate) fn estimate_tokens(raw_lines: &[String]) -> usize {
     let total_chars: usize = raw_lines
         .iter()
         .map(|l| l.len() + 1)
@@ -119,12 +119,15 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: lines,
             in_code_block: vec![false],
+            frontmatter: None,
         };
         let ctx = CheckerContext {
             files: vec![file],
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
         let config = TokenBudgetConfig {
@@ -215,12 +218,15 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
         let ctx = CheckerContext {
             files: vec![file],
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
         let config = TokenBudgetConfig::default();
@@ -243,12 +249,15 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: lines,
             in_code_block: vec![false; 5],
+            frontmatter: None,
         };
         let ctx = CheckerContext {
             files: vec![file],
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
         // warn_tokens=100, max_tokens=200 => 126 tokens should trigger Info
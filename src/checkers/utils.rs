@@ -1,13 +1,14 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 
-use globset::GlobSet;
+use globset::{GlobMatcher, GlobSet};
 use regex::{Regex, RegexSet};
 
 use crate::engine::cross_ref::build_glob_set;
 use crate::engine::scanner::matches_glob;
-use crate::parser::types::ParsedFile;
+use crate::parser::types::{FileRef, ParsedFile};
 use crate::parser::{is_directive_line, non_code_lines_masked};
+use crate::types::{Diagnostic, RelatedLocation};
 
 pub(crate) const SKIP_DIRS: &[&str] = &[
     ".git",
@@ -186,10 +187,140 @@ pub(crate) fn is_template_ref(path: &str) -> bool {
         || path.split('/').all(|seg| seg.starts_with('.'))
 }
 
+/// Returns `true` for custom slash command files under `.claude/commands/`.
+#[must_use]
+pub(crate) fn is_command_file(path: &Path, project_root: &Path) -> bool {
+    path.extension().is_some_and(|e| e == "md")
+        && path
+            .strip_prefix(project_root)
+            .is_ok_and(|rel| rel.starts_with(".claude/commands"))
+}
+
+/// Returns `true` for an Agent Skill manifest, `.claude/skills/<name>/SKILL.md`.
+#[must_use]
+pub(crate) fn is_skill_manifest(path: &Path, project_root: &Path) -> bool {
+    path.file_name().is_some_and(|n| n == "SKILL.md")
+        && path
+            .strip_prefix(project_root)
+            .is_ok_and(|rel| rel.starts_with(".claude/skills"))
+}
+
+/// Returns `true` for a Copilot path-specific instructions file,
+/// `.github/instructions/**/<name>.instructions.md`.
+#[must_use]
+pub(crate) fn is_copilot_instructions(path: &Path, project_root: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.ends_with(".instructions.md"))
+        && path
+            .strip_prefix(project_root)
+            .is_ok_and(|rel| rel.starts_with(".github/instructions"))
+}
+
+/// The `applyTo` globs of a Copilot instructions file and their frontmatter
+/// line, or `None` if the file is not one or has no `applyTo`.
+pub(crate) fn copilot_apply_to(
+    file: &ParsedFile,
+    project_root: &Path,
+) -> Option<(usize, Vec<String>)> {
+    if !is_copilot_instructions(&file.path, project_root) {
+        return None;
+    }
+    let entry = file.frontmatter_get("applyTo")?;
+    let globs = entry.value.items().into_iter().map(String::from).collect();
+    Some((entry.line, globs))
+}
+
+/// True if `matcher` matches any of the given project-relative paths by
+/// relative path or basename.
+pub(crate) fn glob_matches_any<P: AsRef<Path>>(
+    matcher: &GlobMatcher,
+    paths: impl IntoIterator<Item = P>,
+) -> bool {
+    paths.into_iter().any(|rel| {
+        let rel = rel.as_ref();
+        matcher.is_match(rel) || rel.file_name().is_some_and(|name| matcher.is_match(name))
+    })
+}
+
+/// Resolve an `@path` import against the importing file's directory, or
+/// against the project root for slash commands, whose `@file` references are
+/// read from the working directory. Returns `None` for home-relative,
+/// absolute, and template imports, which cannot be checked. `@~/` imports are
+/// personal files that CI and other contributors do not have, so they are
+/// skipped rather than resolved against `$HOME`.
+#[must_use]
+pub(crate) fn resolve_import(file_ref: &FileRef, project_root: &Path) -> Option<PathBuf> {
+    if is_template_ref(&file_ref.path) {
+        return None;
+    }
+    if is_command_file(&file_ref.source_file, project_root) {
+        return Some(project_root.join(&file_ref.path));
+    }
+    let source_dir = file_ref.source_file.parent().unwrap_or(project_root);
+    Some(source_dir.join(&file_ref.path))
+}
+
+/// Built-in Claude Code tool names, as used in permission rules and agent
+/// `tools` lists. MCP tools (`mcp__server__tool`) are matched separately.
+pub(crate) const CLAUDE_TOOLS: &[&str] = &[
+    "Bash",
+    "BashOutput",
+    "Edit",
+    "ExitPlanMode",
+    "Glob",
+    "Grep",
+    "KillShell",
+    "LS",
+    "MultiEdit",
+    "NotebookEdit",
+    "NotebookRead",
+    "Read",
+    "SlashCommand",
+    "Skill",
+    "Task",
+    "TodoWrite",
+    "WebFetch",
+    "WebSearch",
+    "Write",
+];
+
+/// Returns `true` for a built-in Claude Code tool or an `mcp__` tool name.
+#[must_use]
+pub(crate) fn is_known_tool(name: &str) -> bool {
+    CLAUDE_TOOLS.contains(&name) || name.starts_with("mcp__")
+}
+
 /// Size limit (in bytes) for compiled regexes built from user-supplied patterns.
 /// Prevents ReDoS via pathologically large NFA construction.
 pub(crate) const REGEX_SIZE_LIMIT: usize = 1 << 20; // 1 MiB
 
+/// Columns of a match within one line, as reported on a [`Diagnostic`]:
+/// 1-based characters, with `end_column` exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Span {
+    pub(crate) column: usize,
+    pub(crate) end_column: usize,
+}
+
+impl Span {
+    /// The span of `line[start..end]`, given as byte offsets.
+    #[must_use]
+    pub(crate) fn in_line(line: &str, start: usize, end: usize) -> Self {
+        let column = line[..start].chars().count() + 1;
+        Self {
+            column,
+            end_column: column + line[start..end].chars().count(),
+        }
+    }
+
+    /// The span of a regex match in `line`.
+    #[must_use]
+    pub(crate) fn of_match(line: &str, m: &regex::Match<'_>) -> Self {
+        Self::in_line(line, m.start(), m.end())
+    }
+}
+
 /// Minimum number of non-empty directive lines for a file to be considered
 /// a substantive instruction file (used by multiple checkers).
 pub(crate) const MIN_DIRECTIVE_LINES: usize = 5;
@@ -221,15 +352,25 @@ pub(crate) fn is_heading(line: &str) -> bool {
     line.trim_start().starts_with('#')
 }
 
-/// Returns `true` if byte offset `pos` falls inside an inline backtick span.
-/// Used by checkers that scan prose lines and need to skip inline code.
-/// Operates on bytes to avoid panicking when `pos` falls inside a multi-byte
-/// UTF-8 character.
-#[must_use]
-pub(crate) fn inside_inline_code(line: &str, pos: usize) -> bool {
-    let bytes = line.as_bytes();
-    let end = pos.min(bytes.len());
-    bytes[..end].iter().filter(|&&b| b == b'`').count() % 2 == 1
+/// Point every diagnostic in `group` at the locations of the others, for
+/// findings reported once per location (e.g. each spelling of a name).
+pub(crate) fn link_related(group: &mut [Diagnostic]) {
+    let locations: Vec<RelatedLocation> = group
+        .iter()
+        .map(|d| RelatedLocation {
+            file: std::sync::Arc::clone(&d.file),
+            line: d.line,
+            end_line: d.end_line,
+        })
+        .collect();
+    for (i, diagnostic) in group.iter_mut().enumerate() {
+        diagnostic.related = locations
+            .iter()
+            .enumerate()
+            .filter(|&(j, _)| j != i)
+            .map(|(_, loc)| loc.clone())
+            .collect();
+    }
 }
 
 /// Returns true if text after a regex match contains elaboration (colon, em dash, etc.),
@@ -474,6 +615,7 @@ pub mod test_helpers {
             file_refs: vec![],
             directives: vec![],
             suppress_comments: vec![],
+            frontmatter: crate::parser::frontmatter::parse_frontmatter(&raw_lines),
             raw_lines,
             in_code_block,
         };
@@ -484,6 +626,8 @@ pub mod test_helpers {
             project_root: root.to_path_buf(),
             canonical_root,
             filename_index,
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
         (dir, ctx)
@@ -528,6 +672,19 @@ pub mod test_helpers {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_span_counts_characters_not_bytes() {
+        let line = "Ünïcode key: sk-abc";
+        let start = line.find("sk-").unwrap();
+        assert_eq!(
+            Span::in_line(line, start, line.len()),
+            Span {
+                column: 14,
+                end_column: 20
+            }
+        );
+    }
+
     #[test]
     fn test_normalize_snake_case() {
         assert_eq!(normalize("api_key"), "api_key");
@@ -620,6 +777,23 @@ mod tests {
         assert_eq!(normalize("api2Key"), "api2_key");
     }
 
+    #[test]
+    fn test_resolve_import_skips_home_and_absolute_paths() {
+        let root = Path::new("/project");
+        let import = |path: &str| FileRef {
+            path: path.to_string(),
+            line: 1,
+            source_file: root.join("docs/CLAUDE.md"),
+            kind: crate::parser::types::FileRefKind::Import,
+        };
+        assert_eq!(resolve_import(&import("~/.claude/personal.md"), root), None);
+        assert_eq!(resolve_import(&import("/etc/rules.md"), root), None);
+        assert_eq!(
+            resolve_import(&import("rules.md"), root),
+            Some(root.join("docs/rules.md"))
+        );
+    }
+
     #[test]
     fn test_is_template_ref() {
         // Glob patterns
@@ -722,15 +896,6 @@ mod tests {
         );
     }
 
-    #[test]
-    fn test_inside_inline_code() {
-        assert!(!inside_inline_code("hello world", 3));
-        assert!(inside_inline_code("use `foo` here", 5)); // inside backticks
-        assert!(!inside_inline_code("use `foo` here", 10)); // after backticks
-        assert!(inside_inline_code("`all code`", 3)); // inside
-        assert!(!inside_inline_code("`all code`", 0)); // before first backtick
-    }
-
     #[test]
     fn test_has_elaboration_after() {
         assert!(has_elaboration_after("follow the rules: do X, Y, Z", 16));
@@ -769,4 +934,21 @@ mod tests {
         let count = count_directive_lines(&lines, &mask);
         assert_eq!(count, 4);
     }
+
+    #[test]
+    fn test_is_copilot_instructions() {
+        let root = Path::new("/p");
+        assert!(is_copilot_instructions(
+            Path::new("/p/.github/instructions/ts.instructions.md"),
+            root
+        ));
+        assert!(!is_copilot_instructions(
+            Path::new("/p/.github/copilot-instructions.md"),
+            root
+        ));
+        assert!(!is_copilot_instructions(
+            Path::new("/p/docs/ts.instructions.md"),
+            root
+        ));
+    }
 }
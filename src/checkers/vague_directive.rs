@@ -6,7 +6,7 @@ use crate::engine::cross_ref::CheckerContext;
 use crate::parser::{is_directive_line, NON_DIRECTIVE_CONTEXT};
 use crate::types::{Category, CheckResult, RuleMeta, Severity};
 
-use super::utils::{is_reasoning_prompt, ScopeFilter, REGEX_SIZE_LIMIT};
+use super::utils::{is_reasoning_prompt, ScopeFilter, Span, REGEX_SIZE_LIMIT};
 use super::Checker;
 
 /// Hedging phrases enabled by `strict = true` (opt-in only).
@@ -90,6 +90,10 @@ impl Checker for VagueDirectiveChecker {
                     directive.line,
                     Severity::Info,
                     Category::VagueDirective,
+                    span: Span {
+                        column: directive.column,
+                        end_column: directive.column + directive.pattern_matched.chars().count(),
+                    },
                     suggest: "Replace with a specific, deterministic instruction",
                     "Non-deterministic directive found: \"{}\"",
                     directive.pattern_matched.trim()
@@ -124,6 +128,7 @@ impl Checker for VagueDirectiveChecker {
                             i + 1,
                             Severity::Info,
                             Category::VagueDirective,
+                            span: Span::of_match(line, &m),
                             suggest: "Replace with a specific, deterministic instruction",
                             "Non-deterministic directive found: \"{}\"",
                             m.as_str().trim()
@@ -140,7 +145,7 @@ impl Checker for VagueDirectiveChecker {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::parser::types::{Directive, FileRef, ParsedFile};
+    use crate::parser::types::{Directive, FileRef, FileRefKind, ParsedFile};
     use std::collections::HashSet;
     use std::path::PathBuf;
 
@@ -151,6 +156,7 @@ mod tests {
             path: "src/main.rs".to_string(),
             line: 1,
             source_file: PathBuf::from("instructions.md"),
+            kind: FileRefKind::Mention,
         }
     }
 
@@ -166,11 +172,13 @@ mod tests {
             file_refs: vec![code_file_ref()],
             directives: vec![Directive {
                 line: 5,
+                column: 1,
                 pattern_matched: "Try to".to_string(),
             }],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -178,6 +186,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -207,6 +217,7 @@ mod tests {
                 "This line is clean.".to_string(),
             ],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -214,6 +225,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -245,6 +258,13 @@ mod tests {
             assert_eq!(d.severity, Severity::Info);
             assert_eq!(d.category, Category::VagueDirective);
         }
+        assert_eq!(
+            (
+                result.diagnostics[0].column,
+                result.diagnostics[0].end_column
+            ),
+            (Some(12), Some(17))
+        );
     }
 
     #[test]
@@ -259,11 +279,13 @@ mod tests {
             file_refs: vec![code_file_ref()],
             directives: vec![Directive {
                 line: 3,
+                column: 1,
                 pattern_matched: "Try to".to_string(),
             }],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let out_of_scope = ParsedFile {
@@ -273,11 +295,13 @@ mod tests {
             file_refs: vec![code_file_ref()],
             directives: vec![Directive {
                 line: 5,
+                column: 1,
                 pattern_matched: "Try to".to_string(),
             }],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -285,6 +309,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -314,11 +340,13 @@ mod tests {
             file_refs: vec![code_file_ref()],
             directives: vec![Directive {
                 line: 3,
+                column: 1,
                 pattern_matched: "Try to".to_string(),
             }],
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -326,6 +354,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -359,6 +389,7 @@ mod tests {
                 "Consider using caching.".to_string(),
             ],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -366,6 +397,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -396,6 +429,7 @@ mod tests {
                 "Consider using caching.".to_string(),
             ],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -403,6 +437,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -432,6 +468,7 @@ mod tests {
                 "This is probably fine.".to_string(),
             ],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -439,6 +476,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -467,6 +506,7 @@ mod tests {
             file_refs: vec![],
             directives: vec![Directive {
                 line: 5,
+                column: 1,
                 pattern_matched: "Try to".to_string(),
             }],
             suppress_comments: vec![],
@@ -481,6 +521,7 @@ mod tests {
                 "Ideally, cross-reference at least two independent sources.".to_string(),
             ],
             in_code_block: vec![],
+            frontmatter: None,
         };
 
         let ctx = CheckerContext {
@@ -488,6 +529,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -522,6 +565,8 @@ mod tests {
             project_root: root.to_path_buf(),
             canonical_root: None,
             filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
             historical_indices: HashSet::new(),
         };
 
@@ -533,4 +578,36 @@ mod tests {
             "Negated 'try to' (e.g. 'Do not try to') should not be flagged as vague"
         );
     }
+
+    #[test]
+    fn test_directive_span_covers_matched_phrase() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+
+        let file_path = root.join("instructions.md");
+        std::fs::write(
+            &file_path,
+            "Édit carefully — try to keep functions short.\n\n```\ncargo test\n```\n",
+        )
+        .unwrap();
+
+        let parsed = crate::parser::parse_file(&file_path).unwrap();
+
+        let ctx = CheckerContext {
+            files: vec![parsed],
+            project_root: root.to_path_buf(),
+            canonical_root: None,
+            filename_index: HashSet::new(),
+            path_index: vec![],
+            settings_files: vec![],
+            historical_indices: HashSet::new(),
+        };
+
+        let checker = VagueDirectiveChecker::new(false, &[], &[]);
+        let result = checker.check(&ctx);
+
+        assert_eq!(result.diagnostics.len(), 1);
+        let d = &result.diagnostics[0];
+        assert_eq!((d.column, d.end_column), (Some(18), Some(24)));
+    }
 }
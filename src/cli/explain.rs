@@ -253,6 +253,38 @@ pub const AVAILABLE_RULES: &[(&str, &str)] = &[
         "token-budget",
         "Estimates token cost of instruction files and flags context window overuse",
     ),
+    (
+        "import-chain",
+        "Detects circular @-imports and import chains deeper than 5 hops",
+    ),
+    (
+        "cursor-rule",
+        "Validates Cursor .mdc rule frontmatter (description, globs, alwaysApply)",
+    ),
+    (
+        "claude-settings",
+        "Validates permission rules and hook commands in .claude/settings.json",
+    ),
+    (
+        "subagent-definition",
+        "Validates subagent frontmatter in .claude/agents/ (name, description, tools, model)",
+    ),
+    (
+        "slash-command",
+        "Validates argument placeholders, !-commands and @-file references in .claude/commands/",
+    ),
+    (
+        "skill-package",
+        "Validates Agent Skill folders: SKILL.md frontmatter, bundled files, and unreferenced files",
+    ),
+    (
+        "copilot-instructions",
+        "Validates `applyTo` globs in Copilot .github/instructions/*.instructions.md files",
+    ),
+    (
+        "malformed-frontmatter",
+        "Detects invalid YAML, duplicate keys and tab indentation in frontmatter",
+    ),
     (
         "invalid-suppression",
         "Warns on unrecognized rule names in suppress comments",
@@ -955,7 +987,8 @@ pub fn explain(rule: &str) -> Option<&'static str> {
              \n\
              Only ancestor-descendant pairs are compared (CLAUDE.md vs backend/CLAUDE.md).\n\
              Sibling directories (frontend/ vs backend/) are skipped — they represent\n\
-             intentionally different contexts.\n\
+             intentionally different contexts. Copilot .instructions.md files are also\n\
+             compared when their `applyTo` globs match a common project file.\n\
              \n\
              Severity: warning (strict-only)\n\
              Config: [checkers.cross_file_contradiction]",
@@ -1165,6 +1198,132 @@ pub fn explain(rule: &str) -> Option<&'static str> {
              Severity: warning (strict-only)\n\
              Config: [checkers.xml_document_wrapper]",
         ),
+        "import-chain" => Some(
+            "import-chain: Detects circular @-imports and import chains deeper than 5 hops.\n\
+             \n\
+             Claude Code inlines `@path/to/file` imports into the importing file, resolving\n\
+             each path relative to the file that contains it. Imports are followed\n\
+             recursively, including into files that spectralint does not scan, up to five\n\
+             hops deep. Anything deeper is silently dropped, and circular imports load the\n\
+             same content more than once.\n\
+             \n\
+             This checker reports import cycles (a.md → b.md → a.md) and chains where a file\n\
+             sits more than five hops from a top-level instruction file. Missing import\n\
+             targets are reported by dead-reference.\n\
+             \n\
+             Severity: warning\n\
+             Config: [checkers.import_chain]",
+        ),
+        "cursor-rule" => Some(
+            "cursor-rule: Validates Cursor .mdc rule frontmatter (description, globs, alwaysApply).\n\
+             \n\
+             Cursor rules in `.cursor/rules/*.mdc` are activated by their frontmatter:\n\
+             `alwaysApply: true` loads the rule everywhere, `globs` attaches it to matching\n\
+             files, and `description` lets the agent pull it in on demand. This checker flags:\n\
+             \n\
+             - globs that fail to parse (error)\n\
+             - globs that match no file in the project\n\
+             - rules with none of alwaysApply, globs or description (never applied)\n\
+             - rules with `alwaysApply: true` that also set globs (the globs are ignored)\n\
+             \n\
+             Severity: warning (error for invalid globs)\n\
+             Config: [checkers.cursor_rule]",
+        ),
+        "claude-settings" => Some(
+            "claude-settings: Validates permission rules and hook commands in .claude/settings.json.\n\
+             \n\
+             `.claude/settings.json` and `.claude/settings.local.json` hold the permissions\n\
+             and hooks your agent actually runs with. This checker flags:\n\
+             \n\
+             - invalid JSON (error)\n\
+             - malformed permission rules, e.g. `Bash(npm run test:*` or `Bash()` (error)\n\
+             - rules naming an unknown tool\n\
+             - allow rules contradicted by a deny rule (deny always wins)\n\
+             - overly broad grants such as `Bash(*)` or a bare `Write`\n\
+             - hook commands whose script does not exist (error)\n\
+             \n\
+             Diagnostics point at the line and column of the offending JSON value.\n\
+             \n\
+             Severity: warning (error for invalid JSON, malformed rules and missing hook scripts)\n\
+             Config: [checkers.claude_settings]",
+        ),
+        "subagent-definition" => Some(
+            "subagent-definition: Validates subagent frontmatter in .claude/agents/ (name, description, tools, model).\n\
+             \n\
+             Subagents are markdown files whose frontmatter declares a `name` and a\n\
+             `description`, plus optional `tools` and `model`. Claude picks which agent\n\
+             to delegate to from the description. This checker flags:\n\
+             \n\
+             - missing `name` or `description` (error)\n\
+             - the same name used by two agent files (error)\n\
+             - a name that differs from the filename\n\
+             - descriptions shorter than 5 words\n\
+             - unknown tool names in `tools`\n\
+             - deprecated `model` values (same list as outdated-model-reference)\n\
+             \n\
+             Severity: warning (error for missing fields and duplicate names)\n\
+             Config: [checkers.subagent_definition]",
+        ),
+        "slash-command" => Some(
+            "slash-command: Validates argument placeholders, !-commands and @-file references in .claude/commands/.\n\
+             \n\
+             Custom slash commands take arguments through `$ARGUMENTS` or `$1`, `$2`, ...,\n\
+             document them in the `argument-hint` frontmatter, and may run shell commands\n\
+             with !`cmd` when `allowed-tools` permits it. This checker flags:\n\
+             \n\
+             - positional arguments used in the body but missing from `argument-hint`\n\
+             - `argument-hint` arguments the body never uses\n\
+             - !`cmd` lines not covered by a `Bash(...)` entry in `allowed-tools`\n\
+             - `@file` references that do not exist (resolved from the project root; error)\n\
+             \n\
+             Severity: warning (error for missing @-files)\n\
+             Config: [checkers.slash_command]",
+        ),
+        "skill-package" => Some(
+            "skill-package: Validates Agent Skill folders: SKILL.md frontmatter, bundled files, and unreferenced files.\n\
+             \n\
+             A skill is a folder under .claude/skills/ with a SKILL.md manifest plus any\n\
+             scripts, templates and reference docs it points the agent at. This checker flags:\n\
+             \n\
+             - a missing `name` or `description` in the SKILL.md frontmatter (error)\n\
+             - a `name` that differs from the folder name\n\
+             - scripts or reference files mentioned in SKILL.md that do not exist (error)\n\
+             - mentioned files that resolve outside the skill folder\n\
+             - files in the folder that neither SKILL.md nor its reference docs mention (info)\n\
+             \n\
+             Severity: warning (error for missing fields and files, info for dead weight)\n\
+             Config: [checkers.skill_package]",
+        ),
+        "copilot-instructions" => Some(
+            "copilot-instructions: Validates `applyTo` globs in Copilot .github/instructions/*.instructions.md files.\n\
+             \n\
+             Path-specific Copilot instructions are only loaded for files matching the\n\
+             comma-separated `applyTo` globs in their frontmatter. This checker flags:\n\
+             \n\
+             - instructions files without `applyTo`, which are never applied automatically\n\
+             - invalid `applyTo` globs (error)\n\
+             - globs that match no file in the project\n\
+             \n\
+             Overlapping `applyTo` globs are used by cross-file-contradiction to compare\n\
+             instruction files that Copilot loads together.\n\
+             \n\
+             Severity: warning (error for invalid globs)\n\
+             Config: [checkers.copilot_instructions]",
+        ),
+        "malformed-frontmatter" => Some(
+            "malformed-frontmatter: Detects invalid YAML, duplicate keys and tab indentation in frontmatter.\n\
+             \n\
+             Cursor rules, subagents, slash commands, skills and Copilot instructions are\n\
+             configured through a leading `---` YAML block. When that block is malformed the\n\
+             tool may ignore the whole file or silently drop settings. This checker flags:\n\
+             \n\
+             - lines that are not `key: value`, empty keys, unclosed `[`/`{` and unterminated quotes\n\
+             - keys defined twice (warning; the first definition is the one spectralint reads)\n\
+             - tab characters in indentation, which YAML forbids\n\
+             \n\
+             Severity: error (warning for duplicate keys)\n\
+             Config: [checkers.malformed_frontmatter]",
+        ),
         "invalid-suppression" => Some(
             "invalid-suppression: Warns on unrecognized rule names in suppress comments.\n\
              \n\
@@ -1199,6 +1358,12 @@ pub fn explain(rule: &str) -> Option<&'static str> {
              Each pattern is scanned against non-code-block lines. Useful for project-specific\n\
              conventions, banned terms, or required markers.\n\
              \n\
+             Optional keys narrow and extend a pattern:\n\
+             - where = \"prose\" | \"code\" | \"heading\" | \"table\", with languages = [..] for code\n\
+             - files = [..] globs and unless = [..] regexes tested against the matched text\n\
+             - multiline = true to match across consecutive lines of the same kind\n\
+             - replace = \"..\" with $1 / ${name} captures, applied by --fix\n\
+             \n\
              Severity: configurable (default: warning)\n\
              Config: [[checkers.custom_patterns]]",
         ),
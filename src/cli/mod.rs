@@ -2,7 +2,7 @@ pub mod explain;
 pub mod output;
 
 use clap::{Parser, Subcommand, ValueEnum};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 use crate::types::Severity;
@@ -22,8 +22,22 @@ pub struct Cli {
 pub enum Commands {
     /// Lint markdown instruction files
     Check {
-        /// Project root directory to scan
-        path: PathBuf,
+        /// Project root directory to scan, or files and directories to check
+        /// within the enclosing project
+        #[arg(default_value = ".")]
+        paths: Vec<PathBuf>,
+
+        /// Read the file to check from stdin
+        #[arg(
+            long,
+            requires = "stdin_filename",
+            conflicts_with_all = ["paths", "watch", "fix", "staged"]
+        )]
+        stdin: bool,
+
+        /// Path the stdin content is checked as, relative to the current directory
+        #[arg(long, value_name = "PATH", requires = "stdin")]
+        stdin_filename: Option<PathBuf>,
 
         /// Output format
         #[arg(short, long)]
@@ -61,6 +75,10 @@ pub enum Commands {
         #[arg(long)]
         no_cache: bool,
 
+        /// Report wall time for scanning, parsing, each checker, suppression and output
+        #[arg(long)]
+        timings: bool,
+
         /// Re-run on file changes (poll every 2 seconds)
         #[arg(long)]
         watch: bool,
@@ -68,6 +86,22 @@ pub enum Commands {
         /// Automatically apply fixes for diagnostics that have structured fix data
         #[arg(long)]
         fix: bool,
+
+        /// Report only findings not recorded in this baseline file
+        #[arg(long, conflicts_with = "write_baseline")]
+        baseline: Option<PathBuf>,
+
+        /// Record all current findings in a baseline file and exit successfully
+        #[arg(long)]
+        write_baseline: Option<PathBuf>,
+
+        /// Report only findings on lines changed since this git ref
+        #[arg(long, value_name = "GIT_REF", conflicts_with = "staged")]
+        since: Option<String>,
+
+        /// Check the staged version of files and report only findings on staged lines
+        #[arg(long, conflicts_with = "fix")]
+        staged: bool,
     },
     /// Create a default .spectralintrc.toml
     Init {
@@ -75,6 +109,28 @@ pub enum Commands {
         #[arg(long)]
         preset: Option<Preset>,
     },
+    /// Show the instruction files an agent loads when working on a path
+    Context {
+        /// File or directory to compute the instruction set for
+        path: PathBuf,
+
+        /// Project root directory
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+
+        /// Path to config file
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+
+        /// Also print the concatenated instruction text
+        #[arg(long)]
+        text: bool,
+    },
+    /// Inspect and validate configuration
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
     /// Explain what a checker does and why it matters (omit rule to list all)
     Explain {
         /// Checker name (e.g., dead-reference, naming-inconsistency, agent-guidelines)
@@ -85,7 +141,23 @@ pub enum Commands {
     Lsp,
 }
 
-#[derive(Debug, Clone, Copy, ValueEnum, Deserialize, Default)]
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    /// Validate config files without linting: unknown keys, invalid globs and regexes
+    Check {
+        /// Project root directory
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Path to config file
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+    },
+    /// Print a JSON Schema for .spectralintrc.toml, for editor completion
+    Schema,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum OutputFormat {
     #[default]
@@ -1,12 +1,31 @@
 use std::path::Path;
 
-use crate::types::{CheckResult, Severity};
+use crate::types::{CheckResult, Diagnostic, Severity};
 
 /// Strip characters that could inject extra annotations or workflow commands.
 fn sanitize_annotation(s: &str) -> String {
     s.replace(['\r', '\n'], " ").replace("::", ": :")
 }
 
+/// The `line`, `endLine`, `col` and `endColumn` parameters of an annotation.
+/// GitHub columns are 1-based with an inclusive end, and are only used on a
+/// single-line annotation.
+fn position(d: &Diagnostic) -> String {
+    let mut position = format!("line={}", d.line);
+    match (d.end_line, d.column, d.end_column) {
+        (Some(end_line), _, _) if end_line != d.line => {
+            position.push_str(&format!(",endLine={end_line}"));
+        }
+        (_, Some(col), Some(end_col)) => {
+            let end_col = end_col.saturating_sub(1).max(col);
+            position.push_str(&format!(",col={col},endColumn={end_col}"));
+        }
+        (_, Some(col), None) => position.push_str(&format!(",col={col}")),
+        _ => {}
+    }
+    position
+}
+
 pub fn render(result: &CheckResult, project_root: &Path) {
     for d in &result.diagnostics {
         let rel = super::relative_path(&d.file, project_root);
@@ -25,8 +44,8 @@ pub fn render(result: &CheckResult, project_root: &Path) {
         let rel = sanitize_annotation(&rel);
         let category = sanitize_annotation(&d.category.to_string());
         println!(
-            "::{level} file={rel},line={line},title={category}::{msg}{suffix}",
-            line = d.line,
+            "::{level} file={rel},{position},title={category}::{msg}{suffix}",
+            position = position(d),
         );
     }
 }
@@ -41,6 +60,34 @@ mod tests {
         assert_eq!(sanitize_annotation("line1\rline2"), "line1 line2");
     }
 
+    fn diag(line: usize, column: Option<usize>, end_column: Option<usize>) -> Diagnostic {
+        Diagnostic {
+            file: std::sync::Arc::new(std::path::PathBuf::from("CLAUDE.md")),
+            line,
+            column,
+            end_line: None,
+            end_column,
+            severity: Severity::Warning,
+            category: crate::types::Category::BareUrl,
+            message: "bare URL".to_string(),
+            suggestion: None,
+            fix: None,
+            related: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_position_includes_columns() {
+        assert_eq!(position(&diag(3, None, None)), "line=3");
+        assert_eq!(
+            position(&diag(3, Some(5), Some(20))),
+            "line=3,col=5,endColumn=19"
+        );
+        let mut multi_line = diag(3, Some(5), Some(2));
+        multi_line.end_line = Some(6);
+        assert_eq!(position(&multi_line), "line=3,endLine=6");
+    }
+
     #[test]
     fn test_sanitize_breaks_colons() {
         assert_eq!(sanitize_annotation("::warning"), ": :warning");
@@ -1,12 +1,16 @@
 use serde::Serialize;
 use std::path::Path;
+use std::time::{Duration, Instant};
 
+use crate::engine::Timings;
 use crate::types::{Category, CheckResult, Severity};
 
 #[derive(Serialize)]
 struct JsonOutput<'a> {
     diagnostics: Vec<JsonDiagnostic<'a>>,
     summary: JsonSummary,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timings: Option<JsonTimings>,
 }
 
 #[derive(Serialize)]
@@ -24,6 +28,16 @@ struct JsonDiagnostic<'a> {
     message: &'a str,
     #[serde(skip_serializing_if = "Option::is_none")]
     suggestion: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related: Vec<JsonRelated>,
+}
+
+#[derive(Serialize)]
+struct JsonRelated {
+    file: String,
+    line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_line: Option<usize>,
 }
 
 #[derive(Serialize)]
@@ -33,6 +47,49 @@ struct JsonSummary {
     info: usize,
 }
 
+/// `--timings`, in milliseconds.
+#[derive(Serialize)]
+struct JsonTimings {
+    scan_ms: f64,
+    parse_ms: f64,
+    checks_ms: f64,
+    suppression_ms: f64,
+    output_ms: f64,
+    total_ms: f64,
+    checkers: Vec<JsonCheckerTiming>,
+}
+
+#[derive(Serialize)]
+struct JsonCheckerTiming {
+    name: String,
+    ms: f64,
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+impl From<&Timings> for JsonTimings {
+    fn from(t: &Timings) -> Self {
+        Self {
+            scan_ms: millis(t.scan),
+            parse_ms: millis(t.parse),
+            checks_ms: millis(t.checks),
+            suppression_ms: millis(t.suppression),
+            output_ms: millis(t.output),
+            total_ms: millis(t.total()),
+            checkers: t
+                .checkers
+                .iter()
+                .map(|(name, d)| JsonCheckerTiming {
+                    name: name.clone(),
+                    ms: millis(*d),
+                })
+                .collect(),
+        }
+    }
+}
+
 fn build_output<'a>(result: &'a CheckResult, project_root: &Path) -> JsonOutput<'a> {
     let diagnostics = result
         .diagnostics
@@ -47,6 +104,15 @@ fn build_output<'a>(result: &'a CheckResult, project_root: &Path) -> JsonOutput<
             category: &d.category,
             message: &d.message,
             suggestion: d.suggestion.as_deref(),
+            related: d
+                .related
+                .iter()
+                .map(|r| JsonRelated {
+                    file: super::relative_path(&r.file, project_root),
+                    line: r.line,
+                    end_line: r.end_line,
+                })
+                .collect(),
         })
         .collect();
 
@@ -58,11 +124,17 @@ fn build_output<'a>(result: &'a CheckResult, project_root: &Path) -> JsonOutput<
             warnings,
             info,
         },
+        timings: None,
     }
 }
 
-pub fn render(result: &CheckResult, project_root: &Path) {
-    let output = build_output(result, project_root);
+pub fn render(result: &CheckResult, project_root: &Path, timings: Option<Timings>) {
+    let start = Instant::now();
+    let mut output = build_output(result, project_root);
+    if let Some(mut timings) = timings {
+        timings.output = start.elapsed();
+        output.timings = Some(JsonTimings::from(&timings));
+    }
     println!(
         "{}",
         serde_json::to_string_pretty(&output)
@@ -95,6 +167,7 @@ mod tests {
             message: message.to_string(),
             suggestion: None,
             fix: None,
+            related: Vec::new(),
         }
     }
 
@@ -112,6 +185,7 @@ mod tests {
                 message: "file not found".to_string(),
                 suggestion: None,
                 fix: None,
+                related: Vec::new(),
             }],
         };
 
@@ -220,6 +294,7 @@ mod tests {
                 message: "msg".to_string(),
                 suggestion: Some("try this".to_string()),
                 fix: None,
+                related: Vec::new(),
             }],
         };
         let output = build_output(&result, Path::new("/project"));
@@ -377,4 +452,23 @@ mod tests {
         let _: serde_json::Value =
             serde_json::from_str(&json_str).expect("Output should be valid JSON");
     }
+
+    #[test]
+    fn timings_are_reported_in_milliseconds() {
+        let result = CheckResult::default();
+        let mut output = build_output(&result, Path::new("/project"));
+        let no_timings = serde_json::to_value(&output).unwrap();
+        assert!(no_timings.get("timings").is_none());
+
+        output.timings = Some(JsonTimings::from(&Timings {
+            scan: Duration::from_millis(2),
+            checkers: vec![("dead-reference".into(), Duration::from_micros(500))],
+            ..Timings::default()
+        }));
+        let parsed = serde_json::to_value(&output).unwrap();
+        assert_eq!(parsed["timings"]["scan_ms"], 2.0);
+        assert_eq!(parsed["timings"]["total_ms"], 2.0);
+        assert_eq!(parsed["timings"]["checkers"][0]["name"], "dead-reference");
+        assert_eq!(parsed["timings"]["checkers"][0]["ms"], 0.5);
+    }
 }
@@ -4,17 +4,32 @@ pub mod sarif;
 pub mod text;
 
 use std::path::Path;
+use std::time::Instant;
 
 use crate::cli::OutputFormat;
+use crate::engine::Timings;
 use crate::types::CheckResult;
 
-pub fn render(result: &CheckResult, project_root: &Path, format: OutputFormat) {
+/// Print `result` in `format`. With `timings`, the text and JSON formats
+/// include them in the report; the others print them to stderr so their
+/// output stays machine-readable.
+pub fn render(
+    result: &CheckResult,
+    project_root: &Path,
+    format: OutputFormat,
+    timings: Option<Timings>,
+) {
+    let start = Instant::now();
     match format {
-        OutputFormat::Text => text::render(result, project_root),
-        OutputFormat::Json => json::render(result, project_root),
+        OutputFormat::Text => return text::render(result, project_root, timings),
+        OutputFormat::Json => return json::render(result, project_root, timings),
         OutputFormat::Github => github::render(result, project_root),
         OutputFormat::Sarif => sarif::render(result, project_root),
     }
+    if let Some(mut timings) = timings {
+        timings.output = start.elapsed();
+        eprint!("{}", text::render_timings(&timings));
+    }
 }
 
 fn relative_path(file: &Path, project_root: &Path) -> String {
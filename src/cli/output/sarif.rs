@@ -14,6 +14,9 @@ struct SarifLog {
 #[derive(Serialize)]
 struct SarifRun {
     tool: SarifTool,
+    /// Columns count Unicode characters, not the default UTF-16 units.
+    #[serde(rename = "columnKind")]
+    column_kind: &'static str,
     results: Vec<SarifResult>,
 }
 
@@ -43,6 +46,8 @@ struct SarifResult {
     level: &'static str,
     message: SarifMessage,
     locations: Vec<SarifLocation>,
+    #[serde(rename = "relatedLocations", skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<SarifLocation>,
     #[serde(skip_serializing_if = "Option::is_none")]
     help: Option<SarifMessage>,
 }
@@ -131,6 +136,23 @@ fn build_output(result: &CheckResult, project_root: &Path) -> SarifLog {
                         },
                     },
                 }],
+                related_locations: d
+                    .related
+                    .iter()
+                    .map(|r| SarifLocation {
+                        physical_location: SarifPhysicalLocation {
+                            artifact_location: SarifArtifactLocation {
+                                uri: super::relative_path(&r.file, project_root),
+                            },
+                            region: SarifRegion {
+                                start_line: r.line,
+                                start_column: None,
+                                end_line: r.end_line,
+                                end_column: None,
+                            },
+                        },
+                    })
+                    .collect(),
                 help: d
                     .suggestion
                     .as_ref()
@@ -151,6 +173,7 @@ fn build_output(result: &CheckResult, project_root: &Path) -> SarifLog {
                 },
             },
             results,
+            column_kind: "unicodeCodePoints",
         }],
     }
 }
@@ -189,6 +212,7 @@ mod tests {
             message: message.to_string(),
             suggestion: None,
             fix: None,
+            related: Vec::new(),
         }
     }
 
@@ -206,6 +230,7 @@ mod tests {
                 message: "file not found".to_string(),
                 suggestion: None,
                 fix: None,
+                related: Vec::new(),
             }],
         };
 
@@ -215,6 +240,7 @@ mod tests {
 
         assert_eq!(parsed["version"], "2.1.0");
         assert_eq!(parsed["runs"][0]["tool"]["driver"]["name"], "spectralint");
+        assert_eq!(parsed["runs"][0]["columnKind"], "unicodeCodePoints");
         assert_eq!(parsed["runs"][0]["results"][0]["ruleId"], "dead-reference");
         assert_eq!(parsed["runs"][0]["results"][0]["level"], "error");
         assert_eq!(
@@ -240,6 +266,7 @@ mod tests {
                 message: "directive is too vague".to_string(),
                 suggestion: Some("Be more specific about the expected behavior".to_string()),
                 fix: None,
+                related: Vec::new(),
             }],
         };
 
@@ -253,6 +280,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sarif_output_includes_related_locations() {
+        let mut diag = make_diag(
+            "/project/a.md",
+            3,
+            Severity::Warning,
+            Category::EnumDrift,
+            "drift",
+        );
+        diag.related = vec![crate::types::RelatedLocation {
+            file: Arc::new(PathBuf::from("/project/b.md")),
+            line: 7,
+            end_line: Some(10),
+        }];
+        let result = CheckResult {
+            diagnostics: vec![diag],
+        };
+
+        let output = build_output(&result, Path::new("/project"));
+        let parsed = serde_json::to_value(&output).unwrap();
+        let related = &parsed["runs"][0]["results"][0]["relatedLocations"][0]["physicalLocation"];
+        assert_eq!(related["artifactLocation"]["uri"], "b.md");
+        assert_eq!(related["region"]["startLine"], 7);
+        assert_eq!(related["region"]["endLine"], 10);
+    }
+
     #[test]
     fn test_severity_mapping() {
         assert_eq!(severity_to_level(Severity::Error), "error");
@@ -457,6 +510,7 @@ mod tests {
                 message: "msg".to_string(),
                 suggestion: None,
                 fix: None,
+                related: Vec::new(),
             }],
         };
         let json =
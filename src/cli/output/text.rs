@@ -2,7 +2,9 @@ use owo_colors::{OwoColorize, Stream};
 use std::collections::{BTreeMap, HashSet};
 use std::fmt::Write as _;
 use std::path::Path;
+use std::time::{Duration, Instant};
 
+use crate::engine::Timings;
 use crate::types::{CheckResult, Severity};
 
 /// Conditionally apply styling, respecting `owo_colors::set_override()`.
@@ -21,8 +23,43 @@ pub(crate) fn render_to_string(result: &CheckResult, project_root: &Path) -> Str
     out
 }
 
-pub fn render(result: &CheckResult, project_root: &Path) {
-    print!("{}", render_to_string(result, project_root));
+pub fn render(result: &CheckResult, project_root: &Path, timings: Option<Timings>) {
+    let start = Instant::now();
+    let out = render_to_string(result, project_root);
+    print!("{out}");
+    if let Some(mut timings) = timings {
+        timings.output = start.elapsed();
+        print!("{}", render_timings(&timings));
+    }
+}
+
+/// Render `--timings`: each phase, then each checker, slowest first.
+#[must_use]
+pub fn render_timings(timings: &Timings) -> String {
+    let ms = |d: Duration| format!("{:>10.2}ms", d.as_secs_f64() * 1000.0);
+    let width = timings
+        .checkers
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0)
+        .max("suppression".len());
+
+    let mut out = String::new();
+    writeln!(out, "  {}", styled!("Timings", bold)).unwrap();
+    for (phase, elapsed) in timings.phases() {
+        writeln!(out, "    {phase:<width$}{}", ms(elapsed)).unwrap();
+    }
+    writeln!(out, "    {:<width$}{}", "total", ms(timings.total())).unwrap();
+    if !timings.checkers.is_empty() {
+        writeln!(out).unwrap();
+        writeln!(out, "  {}", styled!("Checkers", bold)).unwrap();
+        for (name, elapsed) in &timings.checkers {
+            writeln!(out, "    {name:<width$}{}", ms(*elapsed)).unwrap();
+        }
+    }
+    writeln!(out).unwrap();
+    out
 }
 
 fn render_into(out: &mut String, result: &CheckResult, project_root: &Path) {
@@ -186,6 +223,7 @@ mod tests {
             message: message.to_string(),
             suggestion: None,
             fix: None,
+            related: Vec::new(),
         }
     }
 
@@ -414,4 +452,24 @@ mod tests {
             "Should contain the diagnostic message"
         );
     }
+
+    #[test]
+    fn timings_list_phases_then_checkers() {
+        no_color();
+        let timings = Timings {
+            parse: Duration::from_micros(1500),
+            checkers: vec![
+                ("naming-inconsistency".into(), Duration::from_millis(12)),
+                ("file-size".into(), Duration::from_millis(1)),
+            ],
+            ..Timings::default()
+        };
+        let out = render_timings(&timings);
+        let lines: Vec<&str> = out.lines().map(str::trim).collect();
+        assert!(lines.contains(&"parse                     1.50ms"), "{out}");
+        assert!(lines.contains(&"total                     1.50ms"), "{out}");
+        let naming = out.find("naming-inconsistency").unwrap();
+        let file_size = out.find("file-size").unwrap();
+        assert!(out.find("total").unwrap() < naming && naming < file_size);
+    }
 }
@@ -0,0 +1,242 @@
+use anyhow::Result;
+use globset::GlobSet;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::Config;
+use crate::engine::cross_ref::build_glob_set;
+use crate::engine::scanner::matches_glob;
+
+/// The effective config of every file in a project.
+///
+/// Starts from the project config, layers each subdirectory's own
+/// `.spectralintrc.toml` over the config of its closest configured ancestor,
+/// then applies the `[[overrides]]` whose `files` globs match. Files that
+/// end up with identical layers share one config.
+pub(crate) struct FileConfigs<'a> {
+    root: &'a Config,
+    /// Configs other than the root; index `i` here is config `i + 1`.
+    configs: Vec<Config>,
+    /// Configured directories and their config index, parents before children.
+    dirs: Vec<(PathBuf, usize)>,
+    /// Files with matching overrides and the config index they resolve to.
+    files: HashMap<PathBuf, usize>,
+}
+
+impl<'a> FileConfigs<'a> {
+    /// Resolve configs for `paths`, given the `config_files` found in
+    /// subdirectories of `project_root`.
+    pub(crate) fn load(
+        root: &'a Config,
+        project_root: &Path,
+        config_files: &[PathBuf],
+        paths: &[&Path],
+    ) -> Result<Self> {
+        let mut config_files: Vec<&PathBuf> = config_files.iter().collect();
+        config_files.sort_by_key(|p| p.components().count());
+
+        let mut resolved = Self {
+            root,
+            configs: Vec::new(),
+            dirs: Vec::with_capacity(config_files.len()),
+            files: HashMap::new(),
+        };
+        for file in config_files {
+            let Some(dir) = file.parent() else {
+                continue;
+            };
+            let parent = resolved.get(resolved.dir_owner(dir));
+            let config = parent.with_directory_config(file)?;
+            resolved.configs.push(config);
+            resolved
+                .dirs
+                .push((dir.to_path_buf(), resolved.configs.len()));
+        }
+
+        let override_sets: Vec<Vec<GlobSet>> = (0..resolved.len())
+            .map(|i| {
+                resolved
+                    .get(i)
+                    .overrides
+                    .iter()
+                    .map(|o| build_glob_set(&o.files))
+                    .collect()
+            })
+            .collect();
+        let mut layered: HashMap<(usize, Vec<usize>), usize> = HashMap::new();
+        for &path in paths {
+            let base = resolved.dir_owner(path);
+            let matching: Vec<usize> = override_sets[base]
+                .iter()
+                .enumerate()
+                .filter(|(_, set)| matches_glob(path, project_root, set))
+                .map(|(i, _)| i)
+                .collect();
+            if matching.is_empty() {
+                continue;
+            }
+            let index = match layered.get(&(base, matching.clone())) {
+                Some(&index) => index,
+                None => {
+                    let config = resolved.get(base).with_overrides(&matching)?;
+                    resolved.configs.push(config);
+                    let index = resolved.configs.len();
+                    layered.insert((base, matching), index);
+                    index
+                }
+            };
+            resolved.files.insert(path.to_path_buf(), index);
+        }
+        Ok(resolved)
+    }
+
+    /// Number of distinct configs, including the root.
+    pub(crate) fn len(&self) -> usize {
+        self.configs.len() + 1
+    }
+
+    /// Config at `index`; `0` is the project config.
+    pub(crate) fn get(&self, index: usize) -> &Config {
+        match index {
+            0 => self.root,
+            i => &self.configs[i - 1],
+        }
+    }
+
+    /// Index of the config that applies to `path`.
+    pub(crate) fn owner(&self, path: &Path) -> usize {
+        self.files
+            .get(path)
+            .copied()
+            .unwrap_or_else(|| self.dir_owner(path))
+    }
+
+    /// Index of the config of the deepest configured directory containing
+    /// `path`, before overrides.
+    fn dir_owner(&self, path: &Path) -> usize {
+        self.dirs
+            .iter()
+            .rev()
+            .find(|(dir, _)| path.starts_with(dir))
+            .map_or(0, |&(_, i)| i)
+    }
+
+    /// Every config file read across all layers.
+    pub(crate) fn sources(&self) -> Vec<&Path> {
+        let mut sources: Vec<&Path> = self.root.sources.iter().map(PathBuf::as_path).collect();
+        for config in &self.configs {
+            for source in &config.sources {
+                if !sources.contains(&source.as_path()) {
+                    sources.push(source);
+                }
+            }
+        }
+        sources
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_nested_configs_layer_over_parents() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("pkg/inner")).unwrap();
+        fs::write(
+            root.join(".spectralintrc.toml"),
+            "[checkers.file_size]\nmax_lines = 100\nwarn_lines = 50\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("pkg/.spectralintrc.toml"),
+            "[checkers.file_size]\nwarn_lines = 80\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("pkg/inner/.spectralintrc.toml"),
+            "[checkers.dead_reference]\nenabled = false\n",
+        )
+        .unwrap();
+
+        let config = Config::load(None, root).unwrap();
+        let files = vec![
+            root.join("pkg/inner/.spectralintrc.toml"),
+            root.join("pkg/.spectralintrc.toml"),
+        ];
+        let configs = FileConfigs::load(&config, root, &files, &[]).unwrap();
+        assert_eq!(configs.len(), 3);
+
+        assert_eq!(configs.owner(&root.join("CLAUDE.md")), 0);
+        assert_eq!(configs.owner(&root.join("pkgs/CLAUDE.md")), 0);
+        let pkg = configs.get(configs.owner(&root.join("pkg/CLAUDE.md")));
+        assert_eq!(pkg.checkers.file_size.max_lines, 100);
+        assert_eq!(pkg.checkers.file_size.warn_lines, 80);
+        assert!(pkg.checkers.dead_reference.enabled);
+
+        let inner = configs.get(configs.owner(&root.join("pkg/inner/docs/AGENTS.md")));
+        assert_eq!(inner.checkers.file_size.warn_lines, 80);
+        assert!(!inner.checkers.dead_reference.enabled);
+        assert_eq!(configs.sources().len(), 3);
+    }
+
+    #[test]
+    fn test_nested_configs_layer_over_config_built_in_code() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("pkg")).unwrap();
+        fs::write(
+            root.join("pkg/.spectralintrc.toml"),
+            "[checkers.file_size]\nwarn_lines = 80\n",
+        )
+        .unwrap();
+
+        let mut config = Config {
+            strict: true,
+            ..Config::default()
+        };
+        config.checkers.file_size.max_lines = 100;
+        config.checkers.dead_reference.enabled = false;
+        config.checkers.dead_reference.scope = vec!["docs/**".to_string()];
+        let files = vec![root.join("pkg/.spectralintrc.toml")];
+        let configs = FileConfigs::load(&config, root, &files, &[]).unwrap();
+
+        let pkg = configs.get(configs.owner(&root.join("pkg/CLAUDE.md")));
+        assert!(pkg.strict);
+        assert_eq!(pkg.checkers.file_size.max_lines, 100);
+        assert_eq!(pkg.checkers.file_size.warn_lines, 80);
+        assert!(!pkg.checkers.dead_reference.enabled);
+        assert_eq!(pkg.checkers.dead_reference.scope, vec!["docs/**"]);
+    }
+
+    #[test]
+    fn test_overrides_resolve_per_file_and_share_configs() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::write(
+            root.join(".spectralintrc.toml"),
+            "[[overrides]]\nfiles = [\".claude/commands/**\"]\ncheckers.dead_reference.enabled = false\n",
+        )
+        .unwrap();
+
+        let config = Config::load(None, root).unwrap();
+        let review = root.join(".claude/commands/review.md");
+        let deploy = root.join(".claude/commands/deploy.md");
+        let claude = root.join("CLAUDE.md");
+        let paths = [review.as_path(), deploy.as_path(), claude.as_path()];
+        let configs = FileConfigs::load(&config, root, &[], &paths).unwrap();
+
+        assert_eq!(configs.len(), 2);
+        assert_eq!(configs.owner(&claude), 0);
+        assert_eq!(configs.owner(&review), configs.owner(&deploy));
+        assert!(
+            !configs
+                .get(configs.owner(&review))
+                .checkers
+                .dead_reference
+                .enabled
+        );
+    }
+}
@@ -0,0 +1,255 @@
+//! Config layering: `extends` resolution and deep merging of config tables.
+//!
+//! Layers are merged as raw TOML before deserializing, so a key counts as
+//! "set" only if some layer wrote it. Tables merge key by key, scalars and
+//! most arrays are replaced by the later layer, and the file-list arrays in
+//! [`APPENDED_KEYS`] are concatenated.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+/// Dotted key paths whose arrays accumulate across layers instead of being
+/// replaced, so a shared base can list files and patterns a repo adds to.
+const APPENDED_KEYS: &[&str] = &[
+    "profiles",
+    "include",
+    "ignore",
+    "ignore_files",
+    "plain_text",
+    "historical_files",
+    "overrides",
+    "plugins",
+    "checkers.custom_patterns",
+];
+
+/// Read a config file and everything it `extends`, merged in order: each
+/// base first, then the file itself. Returns the merged table and the paths
+/// of every file read.
+pub(super) fn load_table(path: &Path) -> Result<(toml::Table, Vec<PathBuf>)> {
+    let mut sources = Vec::new();
+    let table = load_recursive(path, &mut Vec::new(), &mut sources)?;
+    Ok((table, sources))
+}
+
+/// Resolve the `extends` key of an already-parsed table, relative to `base_dir`.
+pub(super) fn resolve_extends(
+    table: toml::Table,
+    base_dir: &Path,
+    sources: &mut Vec<PathBuf>,
+) -> Result<toml::Table> {
+    resolve(table, base_dir, &mut Vec::new(), sources)
+}
+
+fn load_recursive(
+    path: &Path,
+    chain: &mut Vec<PathBuf>,
+    sources: &mut Vec<PathBuf>,
+) -> Result<toml::Table> {
+    let key = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if chain.contains(&key) {
+        let cycle: Vec<String> = chain
+            .iter()
+            .chain(std::iter::once(&key))
+            .map(|p| p.display().to_string())
+            .collect();
+        anyhow::bail!("Circular config extends: {}", cycle.join(" → "));
+    }
+
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config {}", path.display()))?;
+    let table: toml::Table = toml::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("Config parse error in {}: {e}", path.display()))?;
+    sources.push(path.to_path_buf());
+
+    chain.push(key);
+    let base_dir = path.parent().unwrap_or(Path::new("."));
+    let table = resolve(table, base_dir, chain, sources)?;
+    chain.pop();
+    Ok(table)
+}
+
+fn resolve(
+    mut table: toml::Table,
+    base_dir: &Path,
+    chain: &mut Vec<PathBuf>,
+    sources: &mut Vec<PathBuf>,
+) -> Result<toml::Table> {
+    let Some(extends) = table.remove("extends") else {
+        return Ok(table);
+    };
+    let bases: Vec<String> = match extends {
+        toml::Value::String(s) => vec![s],
+        toml::Value::Array(items) => items
+            .into_iter()
+            .map(|v| match v {
+                toml::Value::String(s) => Ok(s),
+                other => anyhow::bail!("`extends` entries must be paths, found {other}"),
+            })
+            .collect::<Result<_>>()?,
+        other => anyhow::bail!("`extends` must be a path or a list of paths, found {other}"),
+    };
+
+    let mut merged = toml::Table::new();
+    for base in bases {
+        let base_table = load_recursive(&base_dir.join(base), chain, sources)?;
+        merge_tables(&mut merged, base_table);
+    }
+    merge_tables(&mut merged, table);
+    Ok(merged)
+}
+
+/// Deep-merge `overlay` into `base`; `overlay` wins on conflicts.
+pub(super) fn merge_tables(base: &mut toml::Table, overlay: toml::Table) {
+    merge_at(base, overlay, "");
+}
+
+fn merge_at(base: &mut toml::Table, overlay: toml::Table, prefix: &str) {
+    for (key, value) in overlay {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(existing)), toml::Value::Table(table)) => {
+                merge_at(existing, table, &path);
+            }
+            (Some(toml::Value::Array(existing)), toml::Value::Array(items))
+                if APPENDED_KEYS.contains(&path.as_str()) =>
+            {
+                for item in items {
+                    if !existing.contains(&item) {
+                        existing.push(item);
+                    }
+                }
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn table(s: &str) -> toml::Table {
+        toml::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_merge_tables_deep_merges_checkers() {
+        let mut base = table(
+            r#"
+            strict = true
+            [checkers.file_size]
+            max_lines = 100
+            warn_lines = 50
+            "#,
+        );
+        merge_tables(
+            &mut base,
+            table(
+                r#"
+                [checkers.file_size]
+                warn_lines = 80
+                [checkers.dead_reference]
+                enabled = false
+                "#,
+            ),
+        );
+        assert_eq!(
+            base,
+            table(
+                r#"
+                strict = true
+                [checkers.file_size]
+                max_lines = 100
+                warn_lines = 80
+                [checkers.dead_reference]
+                enabled = false
+                "#,
+            )
+        );
+    }
+
+    #[test]
+    fn test_merge_tables_appends_file_lists_and_replaces_other_arrays() {
+        let mut base = table(
+            r#"
+            include = ["CLAUDE.md"]
+            [checkers.dead_reference]
+            scope = ["CLAUDE.md"]
+            "#,
+        );
+        merge_tables(
+            &mut base,
+            table(
+                r#"
+                include = ["CLAUDE.md", "docs/**"]
+                [checkers.dead_reference]
+                scope = ["docs/**"]
+                "#,
+            ),
+        );
+        assert_eq!(
+            base,
+            table(
+                r#"
+                include = ["CLAUDE.md", "docs/**"]
+                [checkers.dead_reference]
+                scope = ["docs/**"]
+                "#,
+            )
+        );
+    }
+
+    #[test]
+    fn test_load_table_resolves_relative_extends_chain() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("shared")).unwrap();
+        fs::create_dir_all(dir.path().join("repo")).unwrap();
+        fs::write(
+            dir.path().join("shared/base.toml"),
+            "ignore = [\"vendor\"]\n[checkers.file_size]\nmax_lines = 100\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("shared/org.toml"),
+            "extends = \"base.toml\"\nstrict = true\n",
+        )
+        .unwrap();
+        let repo = dir.path().join("repo/.spectralintrc.toml");
+        fs::write(
+            &repo,
+            "extends = [\"../shared/org.toml\"]\nignore = [\"dist\"]\n",
+        )
+        .unwrap();
+
+        let (merged, sources) = load_table(&repo).unwrap();
+        assert_eq!(
+            merged,
+            table(
+                r#"
+                ignore = ["vendor", "dist"]
+                strict = true
+                [checkers.file_size]
+                max_lines = 100
+                "#,
+            )
+        );
+        assert_eq!(sources.len(), 3);
+    }
+
+    #[test]
+    fn test_load_table_rejects_circular_extends() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.toml"), "extends = \"b.toml\"\n").unwrap();
+        fs::write(dir.path().join("b.toml"), "extends = \"a.toml\"\n").unwrap();
+
+        let err = load_table(&dir.path().join("a.toml")).unwrap_err();
+        assert!(err.to_string().contains("Circular config extends"), "{err}");
+    }
+}
@@ -1,23 +1,53 @@
 use anyhow::Result;
-use serde::Deserialize;
-use std::path::Path;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
 use crate::cli::OutputFormat;
 use crate::types::Severity;
 
-#[derive(Debug, Clone, Deserialize)]
+mod file_configs;
+mod merge;
+mod profile;
+pub(crate) mod reflect;
+mod schema;
+mod validate;
+
+pub(crate) use file_configs::FileConfigs;
+pub use schema::json_schema;
+pub use validate::{validate_file, ConfigIssue};
+
+pub use profile::{detect_profiles, expand_profiles, Profile};
+
+/// File name of the project config, also honoured in subdirectories.
+pub const CONFIG_FILE: &str = ".spectralintrc.toml";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct Config {
     pub format: OutputFormat,
     pub strict: bool,
+    /// Agent ecosystems to lint. Each expands to include patterns; see [`Profile`].
+    pub profiles: Vec<Profile>,
     pub include: Vec<String>,
     pub ignore: Vec<String>,
     pub ignore_files: Vec<String>,
+    /// Skip files matched by `.gitignore`, `.git/info/exclude` and `.ignore`.
+    pub respect_gitignore: bool,
+    /// Files parsed as plain text rather than markdown (glob patterns), in
+    /// addition to `.cursorrules`, `.windsurfrules` and `.clinerules`.
+    pub plain_text: Vec<String>,
     pub historical_files: Vec<String>,
     pub checkers: CheckersConfig,
+    /// Checker settings for subsets of files, applied in order.
+    pub overrides: Vec<ConfigOverride>,
+    /// External checkers run as subprocesses.
+    pub plugins: Vec<PluginConfig>,
+    /// Every config file read to build this config, including `extends` bases.
+    #[serde(skip)]
+    pub(crate) sources: Vec<PathBuf>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct CheckersConfig {
     pub dead_reference: ScopedCheckerConfig,
@@ -114,6 +144,14 @@ pub struct CheckersConfig {
     pub missing_verification_step: ScopedCheckerConfig,
     #[serde(default = "ScopedCheckerConfig::disabled")]
     pub xml_document_wrapper: ScopedCheckerConfig,
+    pub import_chain: ScopedCheckerConfig,
+    pub cursor_rule: ScopedCheckerConfig,
+    pub claude_settings: ScopedCheckerConfig,
+    pub subagent_definition: ScopedCheckerConfig,
+    pub slash_command: ScopedCheckerConfig,
+    pub skill_package: ScopedCheckerConfig,
+    pub copilot_instructions: ScopedCheckerConfig,
+    pub malformed_frontmatter: ScopedCheckerConfig,
     pub custom_patterns: Vec<CustomPattern>,
 }
 
@@ -189,12 +227,20 @@ impl Default for CheckersConfig {
             command_without_codeblock: ScopedCheckerConfig::disabled(),
             missing_verification_step: ScopedCheckerConfig::disabled(),
             xml_document_wrapper: ScopedCheckerConfig::disabled(),
+            import_chain: ScopedCheckerConfig::default(),
+            cursor_rule: ScopedCheckerConfig::default(),
+            claude_settings: ScopedCheckerConfig::default(),
+            subagent_definition: ScopedCheckerConfig::default(),
+            slash_command: ScopedCheckerConfig::default(),
+            skill_package: ScopedCheckerConfig::default(),
+            copilot_instructions: ScopedCheckerConfig::default(),
+            malformed_frontmatter: ScopedCheckerConfig::default(),
             custom_patterns: Vec::new(),
         }
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct EmojiDensityConfig {
     pub enabled: bool,
@@ -212,7 +258,7 @@ impl Default for EmojiDensityConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct MissingEssentialSectionsConfig {
     pub enabled: bool,
@@ -232,7 +278,7 @@ impl Default for MissingEssentialSectionsConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct MissingVerificationConfig {
     pub enabled: bool,
@@ -252,7 +298,7 @@ impl Default for MissingVerificationConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct NegativeOnlyFramingConfig {
     pub enabled: bool,
@@ -274,7 +320,7 @@ impl Default for NegativeOnlyFramingConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct RedundantDirectiveConfig {
     pub enabled: bool,
@@ -296,7 +342,7 @@ impl Default for RedundantDirectiveConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct InstructionDensityConfig {
     pub enabled: bool,
@@ -316,7 +362,7 @@ impl Default for InstructionDensityConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct LargeCodeBlockConfig {
     pub enabled: bool,
@@ -336,7 +382,7 @@ impl Default for LargeCodeBlockConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct SectionLengthImbalanceConfig {
     pub enabled: bool,
@@ -358,7 +404,7 @@ impl Default for SectionLengthImbalanceConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct EmphasisOveruseConfig {
     pub enabled: bool,
@@ -378,7 +424,7 @@ impl Default for EmphasisOveruseConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct LongParagraphConfig {
     pub enabled: bool,
@@ -398,7 +444,7 @@ impl Default for LongParagraphConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct ExcessiveNestingConfig {
     pub enabled: bool,
@@ -418,7 +464,7 @@ impl Default for ExcessiveNestingConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct FileSizeConfig {
     pub enabled: bool,
@@ -438,7 +484,7 @@ impl Default for FileSizeConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(default)]
 pub struct VagueDirectiveConfig {
     pub enabled: bool,
@@ -448,7 +494,7 @@ pub struct VagueDirectiveConfig {
     pub severity: Option<Severity>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct ScopedCheckerConfig {
     pub enabled: bool,
@@ -456,7 +502,7 @@ pub struct ScopedCheckerConfig {
     pub severity: Option<Severity>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct TokenBudgetConfig {
     pub enabled: bool,
@@ -478,17 +524,108 @@ impl Default for TokenBudgetConfig {
     }
 }
 
+/// An `[[overrides]]` entry: a partial `[checkers]` table applied to the
+/// files matching `files` (globs relative to the project root, like `scope`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigOverride {
+    pub files: Vec<String>,
+    #[serde(default)]
+    pub checkers: toml::Table,
+}
+
 fn default_severity() -> Severity {
     Severity::Warning
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CustomPattern {
     pub name: String,
     pub pattern: String,
     #[serde(default = "default_severity")]
     pub severity: Severity,
     pub message: String,
+    /// Match against this frontmatter key's value (each item of a list)
+    /// instead of the file's lines.
+    #[serde(default)]
+    pub frontmatter_key: Option<String>,
+    /// Kind of line to match; every line outside code blocks when unset.
+    #[serde(default, rename = "where")]
+    pub context: Option<PatternContext>,
+    /// With `where = "code"`, only code blocks tagged with one of these
+    /// languages.
+    #[serde(default)]
+    pub languages: Vec<String>,
+    /// Only check files matching these glob patterns (all files when empty).
+    #[serde(default)]
+    pub files: Vec<String>,
+    /// Ignore matches whose text matches any of these regexes.
+    #[serde(default)]
+    pub unless: Vec<String>,
+    /// Match across consecutive lines of the same kind instead of line by
+    /// line; `^` and `$` then match at line boundaries.
+    #[serde(default)]
+    pub multiline: bool,
+    /// Replacement offered to `--fix`, with `$1` or `${name}` for captures.
+    #[serde(default)]
+    pub replace: Option<String>,
+}
+
+impl Default for CustomPattern {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            pattern: String::new(),
+            severity: default_severity(),
+            message: String::new(),
+            frontmatter_key: None,
+            context: None,
+            languages: Vec::new(),
+            files: Vec::new(),
+            unless: Vec::new(),
+            multiline: false,
+            replace: None,
+        }
+    }
+}
+
+/// The `where` of a custom pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PatternContext {
+    /// Lines outside code blocks, headings and tables.
+    Prose,
+    /// Lines inside fenced code blocks.
+    Code,
+    Heading,
+    /// Header and rows of markdown tables.
+    Table,
+}
+
+/// A `[[plugins]]` entry: an external checker, run once per file with the
+/// parsed file as JSON on stdin.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginConfig {
+    /// Reported as `custom:<name>` for findings without their own category
+    /// and for plugin failures.
+    pub name: String,
+    /// Program and arguments. A relative program path containing a
+    /// separator is resolved against the project root.
+    pub command: Vec<String>,
+    /// Seconds to wait for the plugin on each file.
+    #[serde(default = "default_plugin_timeout")]
+    pub timeout: u64,
+    #[serde(default)]
+    pub scope: Vec<String>,
+    #[serde(default)]
+    pub severity: Option<Severity>,
+}
+
+fn default_plugin_timeout() -> u64 {
+    10
+}
+
+fn parse_table(content: &str) -> Result<toml::Table> {
+    toml::from_str(content).map_err(|e| anyhow::anyhow!("Config parse error: {e}"))
 }
 
 impl Default for Config {
@@ -496,14 +633,18 @@ impl Default for Config {
         Self {
             format: OutputFormat::Text,
             strict: false,
+            profiles: Vec::new(),
             include: vec![
                 "CLAUDE.md".into(),
                 "AGENTS.md".into(),
                 ".claude/**".into(),
                 ".github/copilot-instructions.md".into(),
+                ".cursor/rules/**".into(),
             ],
             ignore: vec!["node_modules".into(), ".git".into(), "target".into()],
             ignore_files: Vec::new(),
+            respect_gitignore: true,
+            plain_text: Vec::new(),
             historical_files: vec![
                 "changelog*".into(),
                 "retro*".into(),
@@ -512,6 +653,9 @@ impl Default for Config {
                 "restart*".into(),
             ],
             checkers: CheckersConfig::default(),
+            overrides: Vec::new(),
+            plugins: Vec::new(),
+            sources: Vec::new(),
         }
     }
 }
@@ -614,6 +758,14 @@ impl Config {
         config.checkers.command_without_codeblock.enabled = true;
         config.checkers.missing_verification_step.enabled = true;
         config.checkers.xml_document_wrapper.enabled = true;
+        config.checkers.import_chain.enabled = true;
+        config.checkers.cursor_rule.enabled = true;
+        config.checkers.claude_settings.enabled = true;
+        config.checkers.subagent_definition.enabled = true;
+        config.checkers.slash_command.enabled = true;
+        config.checkers.skill_package.enabled = true;
+        config.checkers.copilot_instructions.enabled = true;
+        config.checkers.malformed_frontmatter.enabled = true;
         config
     }
 
@@ -621,32 +773,179 @@ impl Config {
         // When an explicit --config path is given, any read error is fatal.
         if let Some(path) = config_path {
             let content = std::fs::read_to_string(path)?;
-            return toml::from_str(&content)
-                .map_err(|e| anyhow::anyhow!("Config parse error: {e}"));
+            return Self::parse_file(&content, path);
         }
 
         // Auto-discover: try to read the default path directly (avoids TOCTOU
         // race between exists() and read_to_string()).
-        let default = project_root.join(".spectralintrc.toml");
+        let default = project_root.join(CONFIG_FILE);
         match std::fs::read_to_string(&default) {
-            Ok(content) => {
-                toml::from_str(&content).map_err(|e| anyhow::anyhow!("Config parse error: {e}"))
-            }
+            Ok(content) => Self::parse_file(&content, &default),
             Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Config::default()),
             Err(e) => Err(e.into()),
         }
     }
 
+    /// Parse a `.spectralintrc.toml` document and expand `profiles`.
+    /// `extends` paths are resolved relative to the current directory.
+    ///
+    /// With `profiles` set, an omitted `include` means "only the profiles'
+    /// files"; an explicit `include` is kept and the profile patterns are added.
+    pub fn parse(content: &str) -> Result<Self> {
+        let table = parse_table(content)?;
+        let mut sources = Vec::new();
+        let table = merge::resolve_extends(table, Path::new("."), &mut sources)?;
+        let mut config = Self::from_table(table)?;
+        config.sources = sources;
+        Ok(config)
+    }
+
+    /// Parse the content of the config file at `path`, resolving `extends`
+    /// relative to that file.
+    fn parse_file(content: &str, path: &Path) -> Result<Self> {
+        let table = parse_table(content)?;
+        let mut sources = vec![path.to_path_buf()];
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+        let table = merge::resolve_extends(table, base_dir, &mut sources)?;
+        let mut config = Self::from_table(table)?;
+        config.sources = sources;
+        Ok(config)
+    }
+
+    /// The config for files under a directory with its own config file at
+    /// `path`: that file (and what it extends) deep-merged over `self`.
+    pub(crate) fn with_directory_config(&self, path: &Path) -> Result<Self> {
+        let (overlay, sources) = merge::load_table(path)?;
+        let mut table = self.to_table()?;
+        merge::merge_tables(&mut table, overlay);
+        let mut config = Self::from_table(table)?;
+        config.sources = self.sources.iter().cloned().chain(sources).collect();
+        Ok(config)
+    }
+
+    /// Resolved settings of the checker with rule name `name`, with strict
+    /// mode, as a comparable string. Two configs with equal settings run that
+    /// checker identically; `severity` is left out since it is applied to the
+    /// findings afterwards.
+    pub(crate) fn checker_settings(&self, name: &str) -> String {
+        let mut settings = toml::Value::try_from(&self.checkers)
+            .ok()
+            .and_then(|mut t| t.as_table_mut()?.remove(&name.replace('-', "_")));
+        if let Some(toml::Value::Table(table)) = &mut settings {
+            table.remove("severity");
+        }
+        let settings = settings.map(|s| s.to_string()).unwrap_or_default();
+        format!("strict={} {settings}", self.strict)
+    }
+
+    /// `self` as a TOML table, so later layers merge over every setting,
+    /// including ones made in code rather than read from a file.
+    fn to_table(&self) -> Result<toml::Table> {
+        match toml::Value::try_from(self) {
+            Ok(toml::Value::Table(table)) => Ok(table),
+            Ok(other) => anyhow::bail!("Config serialized to {}", other.type_str()),
+            Err(e) => Err(anyhow::anyhow!("Config serialize error: {e}")),
+        }
+    }
+
+    /// Report unknown keys, invalid globs and invalid regexes in every file
+    /// this config was read from. Files that can no longer be read are skipped.
+    #[must_use]
+    pub fn validate(&self) -> Vec<ConfigIssue> {
+        self.sources
+            .iter()
+            .filter_map(|path| validate_file(path).ok())
+            .flatten()
+            .collect()
+    }
+
+    /// The config for a file matched by the overrides at `indices`: their
+    /// `checkers` tables deep-merged over `self`, in order.
+    pub(crate) fn with_overrides(&self, indices: &[usize]) -> Result<Self> {
+        let mut table = self.to_table()?;
+        for &i in indices {
+            let mut overlay = toml::Table::new();
+            overlay.insert(
+                "checkers".to_string(),
+                toml::Value::Table(self.overrides[i].checkers.clone()),
+            );
+            merge::merge_tables(&mut table, overlay);
+        }
+        let mut config = Self::from_table(table)?;
+        config.sources.clone_from(&self.sources);
+        Ok(config)
+    }
+
+    fn from_table(table: toml::Table) -> Result<Self> {
+        let include_set = table.contains_key("include");
+        let mut config: Self = table
+            .try_into()
+            .map_err(|e| anyhow::anyhow!("Config parse error: {e}"))?;
+        // Catch invalid override settings at load time rather than when a
+        // matching file is checked.
+        for (i, o) in config.overrides.iter().enumerate() {
+            CheckersConfig::deserialize(toml::Value::Table(o.checkers.clone()))
+                .map_err(|e| anyhow::anyhow!("Config parse error in overrides[{i}]: {e}"))?;
+        }
+        if !config.profiles.is_empty() {
+            if !include_set {
+                config.include.clear();
+            }
+            for pattern in expand_profiles(&config.profiles) {
+                if !config.include.contains(&pattern) {
+                    config.include.push(pattern);
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    /// Replace the `include = [...]` line of a config template with a
+    /// `profiles = [...]` line for the given profiles. Used by `init` after
+    /// detecting which agent ecosystems a project uses.
+    #[must_use]
+    pub fn with_profiles(template: &str, profiles: &[Profile]) -> String {
+        if profiles.is_empty() {
+            return template.to_string();
+        }
+        let names: Vec<String> = profiles
+            .iter()
+            .map(|p| format!("\"{}\"", p.name()))
+            .collect();
+        let line = format!("profiles = [{}]", names.join(", "));
+        template
+            .lines()
+            .map(|l| {
+                if l.starts_with("include = ") {
+                    line.as_str()
+                } else {
+                    l
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+            + "\n"
+    }
+
     pub const fn default_toml() -> &'static str {
         r#"# spectralint configuration
 
+# Inherit settings from shared configs (paths relative to this file).
+# [checkers] tables are merged key by key; include/ignore lists are combined.
+# extends = ["../shared/spectralint.toml"]
+
 # Output format: text, json, github, sarif
 # format = "text"
 
+# Agent ecosystems to lint; each expands to that tool's instruction files
+# (AGENTS.md is always included). Replaces the default `include` list.
+# Available: claude, cursor, copilot, gemini, windsurf, cline, aider, junie
+# profiles = ["claude", "cursor", "copilot"]
+
 # Which files to scan (glob patterns, case-insensitive).
 # Default: known AI instruction file patterns.
 # Set to ["**/*.md"] to scan all markdown files.
-include = ["CLAUDE.md", "AGENTS.md", ".claude/**", ".github/copilot-instructions.md"]
+include = ["CLAUDE.md", "AGENTS.md", ".claude/**", ".github/copilot-instructions.md", ".cursor/rules/**"]
 
 # Directories to ignore when scanning
 ignore = ["node_modules", ".git", "target"]
@@ -654,6 +953,14 @@ ignore = ["node_modules", ".git", "target"]
 # Individual files to skip entirely (supports glob patterns)
 # ignore_files = ["changelog.md", "docs/history.md"]
 
+# Skip files ignored by .gitignore, .git/info/exclude and .ignore (default: true)
+# respect_gitignore = false
+
+# Files parsed as plain text instead of markdown (supports glob patterns).
+# Structural checks (headings, sections, fences, tables) are skipped for them.
+# .cursorrules, .windsurfrules and .clinerules are always plain text.
+# plain_text = ["docs/agent-rules"]
+
 # Files treated as historical (dead refs and enum drift are skipped)
 # Patterns are matched case-insensitively.
 # historical_files = ["changelog*", "retro*", "history*", "archive*", "restart*"]
@@ -666,6 +973,12 @@ ignore = ["node_modules", ".git", "target"]
 # [checkers.vague_directive]
 # severity = "error"
 
+# Checker settings for a subset of files (globs, like `scope`):
+# [[overrides]]
+# files = [".claude/commands/**"]
+# checkers.missing_role_definition.enabled = false
+# checkers.dangerous_command.severity = "error"
+
 [checkers.dead_reference]
 enabled = true
 
@@ -879,12 +1192,50 @@ max_tokens = 8000
 # [checkers.xml_document_wrapper]
 # enabled = true
 
+# [checkers.import_chain]
+# enabled = true
+
+# [checkers.cursor_rule]
+# enabled = true
+
+# [checkers.claude_settings]
+# enabled = true
+
+# [checkers.subagent_definition]
+# enabled = true
+
+# [checkers.slash_command]
+# enabled = true
+
+# [checkers.skill_package]
+# enabled = true
+
+# [checkers.copilot_instructions]
+# enabled = true
+
+# [checkers.malformed_frontmatter]
+# enabled = true
+
 # Custom regex patterns:
 # [[checkers.custom_patterns]]
 # name = "todo-comment"
 # pattern = "(?i)\\bTODO\\b"
 # severity = "warning"
 # message = "TODO comment found"
+# frontmatter_key = "model"  # match this frontmatter value instead of lines
+# where = "prose"            # or "code", "heading", "table"
+# languages = ["bash"]       # with where = "code": only these fences
+# files = ["docs/**"]        # only these files
+# unless = ["^todo$"]        # skip matches whose text matches
+# multiline = true           # match across consecutive lines
+# replace = "FIXME"          # fix template; $1 / ${name} insert captures
+
+# External checkers: each is run per file with the parsed file as JSON on
+# stdin and prints {"diagnostics": [...]} on stdout.
+# [[plugins]]
+# name = "security"
+# command = ["python3", "tools/lint_security.py"]
+# timeout = 10  # seconds per file
 "#
     }
 
@@ -892,7 +1243,7 @@ max_tokens = 8000
         r#"# spectralint configuration — minimal preset
 # Only the most critical checkers are enabled.
 
-include = ["CLAUDE.md", "AGENTS.md", ".claude/**", ".github/copilot-instructions.md"]
+include = ["CLAUDE.md", "AGENTS.md", ".claude/**", ".github/copilot-instructions.md", ".cursor/rules/**"]
 ignore = ["node_modules", ".git", "target"]
 
 [checkers.dead_reference]
@@ -907,7 +1258,7 @@ enabled = true
         r#"# spectralint configuration — strict preset
 # All checkers enabled, including opinionated ones.
 
-include = ["CLAUDE.md", "AGENTS.md", ".claude/**", ".github/copilot-instructions.md"]
+include = ["CLAUDE.md", "AGENTS.md", ".claude/**", ".github/copilot-instructions.md", ".cursor/rules/**"]
 ignore = ["node_modules", ".git", "target"]
 strict = true
 "#
@@ -992,6 +1343,14 @@ strict = true
             Category::BrokenAnchorLink => self.checkers.broken_anchor_link.severity,
             Category::LongParagraph => self.checkers.long_paragraph.severity,
             Category::XmlDocumentWrapper => self.checkers.xml_document_wrapper.severity,
+            Category::ImportChain => self.checkers.import_chain.severity,
+            Category::CursorRule => self.checkers.cursor_rule.severity,
+            Category::ClaudeSettings => self.checkers.claude_settings.severity,
+            Category::SubagentDefinition => self.checkers.subagent_definition.severity,
+            Category::SlashCommand => self.checkers.slash_command.severity,
+            Category::SkillPackage => self.checkers.skill_package.severity,
+            Category::CopilotInstructions => self.checkers.copilot_instructions.severity,
+            Category::MalformedFrontmatter => self.checkers.malformed_frontmatter.severity,
             Category::InvalidSuppression
             | Category::UnusedSuppression
             | Category::CustomPattern(_) => None,
@@ -1021,7 +1380,7 @@ mod tests {
         );
         assert!(config.checkers.enum_drift.scope.is_empty());
         assert_eq!(config.ignore.len(), 3);
-        assert_eq!(config.include.len(), 4);
+        assert_eq!(config.include.len(), 5);
         assert!(config.include.contains(&"CLAUDE.md".to_string()));
         assert!(config.include.contains(&"AGENTS.md".to_string()));
     }
@@ -1155,7 +1514,117 @@ enabled = true
 "#;
         let config: Config = toml::from_str(toml_str).unwrap();
         assert_eq!(config.include, Config::default().include);
-        assert_eq!(config.include.len(), 4);
+        assert_eq!(config.include.len(), 5);
+    }
+
+    #[test]
+    fn test_profiles_replace_default_include() {
+        let config = Config::parse("profiles = [\"gemini\", \"aider\"]\n").unwrap();
+        assert_eq!(config.profiles, vec![Profile::Gemini, Profile::Aider]);
+        assert_eq!(
+            config.include,
+            vec!["AGENTS.md", "GEMINI.md", "CONVENTIONS.md"]
+        );
+    }
+
+    #[test]
+    fn test_profiles_extend_explicit_include() {
+        let config =
+            Config::parse("profiles = [\"junie\"]\ninclude = [\"docs/agents/**\"]\n").unwrap();
+        assert_eq!(
+            config.include,
+            vec!["docs/agents/**", "AGENTS.md", ".junie/guidelines.md"]
+        );
+    }
+
+    #[test]
+    fn test_unknown_profile_rejected() {
+        let err = Config::parse("profiles = [\"emacs\"]\n").unwrap_err();
+        assert!(err.to_string().contains("unknown variant"));
+    }
+
+    #[test]
+    fn test_with_profiles_rewrites_include_line() {
+        let out = Config::with_profiles(
+            Config::minimal_toml(),
+            &[Profile::Claude, Profile::Windsurf],
+        );
+        assert!(out.contains("\nprofiles = [\"claude\", \"windsurf\"]\n"));
+        assert!(!out.lines().any(|l| l.starts_with("include = ")));
+        let config = Config::parse(&out).unwrap();
+        assert!(config.include.contains(&".windsurfrules".to_string()));
+        assert_eq!(
+            Config::with_profiles(Config::minimal_toml(), &[]),
+            Config::minimal_toml()
+        );
+    }
+
+    #[test]
+    fn test_with_overrides_merges_partial_checkers() {
+        let config = Config::parse(
+            r#"
+            [checkers.dangerous_command]
+            severity = "warning"
+
+            [[overrides]]
+            files = [".claude/commands/**"]
+            checkers.missing_role_definition.enabled = false
+            checkers.dangerous_command.severity = "error"
+
+            [[overrides]]
+            files = ["docs/**"]
+            checkers.file_size.warn_lines = 100
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.overrides.len(), 2);
+
+        let commands = config.with_overrides(&[0]).unwrap();
+        assert!(!commands.checkers.missing_role_definition.enabled);
+        assert_eq!(
+            commands.severity_override(&crate::types::Category::DangerousCommand),
+            Some(Severity::Error)
+        );
+        assert_eq!(commands.checkers.file_size.warn_lines, 500);
+
+        let docs = config.with_overrides(&[1]).unwrap();
+        assert_eq!(docs.checkers.file_size.warn_lines, 100);
+        assert_eq!(docs.checkers.file_size.max_lines, 750);
+    }
+
+    #[test]
+    fn test_with_overrides_keeps_settings_made_in_code() {
+        let mut config = Config {
+            strict: true,
+            overrides: vec![ConfigOverride {
+                files: vec!["docs/**".to_string()],
+                checkers: toml::from_str("file_size.warn_lines = 100").unwrap(),
+            }],
+            ..Config::default()
+        };
+        config.checkers.file_size.max_lines = 300;
+        config.checkers.dead_reference.enabled = false;
+
+        let docs = config.with_overrides(&[0]).unwrap();
+        assert!(docs.strict);
+        assert_eq!(docs.checkers.file_size.warn_lines, 100);
+        assert_eq!(docs.checkers.file_size.max_lines, 300);
+        assert!(!docs.checkers.dead_reference.enabled);
+    }
+
+    #[test]
+    fn test_invalid_override_rejected_at_load() {
+        let result = Config::parse(
+            r#"
+            [[overrides]]
+            files = ["docs/**"]
+            checkers.file_size.max_lines = "many"
+            "#,
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Config parse error in overrides[0]"));
     }
 
     #[test]
@@ -1221,4 +1690,46 @@ message = "TODO found"
             "Default config should have no custom patterns"
         );
     }
+
+    #[test]
+    fn test_parse_custom_pattern_options() {
+        let config: Config = toml::from_str(
+            r#"
+[[checkers.custom_patterns]]
+name = "npm-run"
+pattern = "npm run (\\w+)"
+message = "Use pnpm"
+where = "code"
+languages = ["bash"]
+unless = ["^npm run lint$"]
+multiline = true
+replace = "pnpm $1"
+"#,
+        )
+        .unwrap();
+        let pattern = &config.checkers.custom_patterns[0];
+        assert_eq!(pattern.context, Some(PatternContext::Code));
+        assert_eq!(pattern.languages, vec!["bash"]);
+        assert_eq!(pattern.unless, vec!["^npm run lint$"]);
+        assert!(pattern.multiline);
+        assert_eq!(pattern.replace.as_deref(), Some("pnpm $1"));
+        assert!(pattern.files.is_empty());
+    }
+
+    #[test]
+    fn test_parse_plugins() {
+        let config: Config = toml::from_str(
+            r#"
+[[plugins]]
+name = "security"
+command = ["python3", "tools/lint_security.py"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.plugins.len(), 1);
+        assert_eq!(config.plugins[0].command[1], "tools/lint_security.py");
+        assert_eq!(config.plugins[0].timeout, 10);
+        assert!(config.plugins[0].scope.is_empty());
+        assert_eq!(config.plugins[0].severity, None);
+    }
 }
@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// An agent ecosystem whose instruction files spectralint knows how to find.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Profile {
+    Claude,
+    Cursor,
+    Copilot,
+    Gemini,
+    Windsurf,
+    Cline,
+    Aider,
+    Junie,
+}
+
+impl Profile {
+    pub const ALL: &'static [Profile] = &[
+        Profile::Claude,
+        Profile::Cursor,
+        Profile::Copilot,
+        Profile::Gemini,
+        Profile::Windsurf,
+        Profile::Cline,
+        Profile::Aider,
+        Profile::Junie,
+    ];
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Profile::Claude => "claude",
+            Profile::Cursor => "cursor",
+            Profile::Copilot => "copilot",
+            Profile::Gemini => "gemini",
+            Profile::Windsurf => "windsurf",
+            Profile::Cline => "cline",
+            Profile::Aider => "aider",
+            Profile::Junie => "junie",
+        }
+    }
+
+    /// Include globs for this ecosystem's instruction files.
+    #[must_use]
+    pub const fn include_patterns(self) -> &'static [&'static str] {
+        match self {
+            Profile::Claude => &["CLAUDE.md", ".claude/**"],
+            Profile::Cursor => &[".cursor/rules/**", ".cursorrules"],
+            Profile::Copilot => &[".github/copilot-instructions.md", ".github/instructions/**"],
+            Profile::Gemini => &["GEMINI.md"],
+            Profile::Windsurf => &[".windsurfrules", ".windsurf/rules/**"],
+            Profile::Cline => &[".clinerules", ".clinerules/**"],
+            Profile::Aider => &["CONVENTIONS.md"],
+            Profile::Junie => &[".junie/guidelines.md"],
+        }
+    }
+
+    /// Paths (relative to the project root) whose presence means the project
+    /// uses this ecosystem.
+    const fn markers(self) -> &'static [&'static str] {
+        match self {
+            Profile::Claude => &["CLAUDE.md", ".claude"],
+            Profile::Cursor => &[".cursor/rules", ".cursorrules"],
+            Profile::Copilot => &[".github/copilot-instructions.md", ".github/instructions"],
+            Profile::Gemini => &["GEMINI.md", ".gemini"],
+            Profile::Windsurf => &[".windsurfrules", ".windsurf"],
+            Profile::Cline => &[".clinerules"],
+            Profile::Aider => &["CONVENTIONS.md", ".aider.conf.yml"],
+            Profile::Junie => &[".junie"],
+        }
+    }
+}
+
+/// `AGENTS.md` is read by most agents, so every profile set includes it.
+const SHARED_PATTERNS: &[&str] = &["AGENTS.md"];
+
+/// Include globs for a set of profiles, without duplicates.
+#[must_use]
+pub fn expand_profiles(profiles: &[Profile]) -> Vec<String> {
+    let mut patterns: Vec<String> = Vec::new();
+    let all = SHARED_PATTERNS
+        .iter()
+        .chain(profiles.iter().flat_map(|p| p.include_patterns()));
+    for pattern in all {
+        if !patterns.iter().any(|p| p == pattern) {
+            patterns.push((*pattern).to_string());
+        }
+    }
+    patterns
+}
+
+/// Profiles whose marker files exist at the project root, in `Profile::ALL` order.
+#[must_use]
+pub fn detect_profiles(root: &Path) -> Vec<Profile> {
+    Profile::ALL
+        .iter()
+        .copied()
+        .filter(|p| p.markers().iter().any(|m| root.join(m).exists()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_profiles_dedups_and_includes_agents_md() {
+        let patterns = expand_profiles(&[Profile::Claude, Profile::Claude, Profile::Gemini]);
+        assert_eq!(
+            patterns,
+            vec!["AGENTS.md", "CLAUDE.md", ".claude/**", "GEMINI.md"]
+        );
+    }
+
+    #[test]
+    fn test_default_profiles_match_default_include() {
+        let mut expanded = expand_profiles(&[Profile::Claude, Profile::Copilot, Profile::Cursor]);
+        expanded.retain(|p| !matches!(p.as_str(), ".cursorrules" | ".github/instructions/**"));
+        let mut default = crate::config::Config::default().include;
+        expanded.sort();
+        default.sort();
+        assert_eq!(expanded, default);
+    }
+
+    #[test]
+    fn test_detect_profiles() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("GEMINI.md"), "# Gemini").unwrap();
+        std::fs::create_dir_all(dir.path().join(".claude")).unwrap();
+        std::fs::write(dir.path().join(".windsurfrules"), "rules").unwrap();
+        assert_eq!(
+            detect_profiles(dir.path()),
+            vec![Profile::Claude, Profile::Gemini, Profile::Windsurf]
+        );
+    }
+
+    #[test]
+    fn test_detect_profiles_empty_project() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(detect_profiles(dir.path()).is_empty());
+    }
+}
@@ -0,0 +1,363 @@
+//! Config shape introspection.
+//!
+//! [`shape_of`] drives a type's `Deserialize` impl with a deserializer that
+//! records what is asked of it: the field names of each struct, the variants
+//! of each enum, element types of lists. Because the shape comes from the
+//! same derives that parse `.spectralintrc.toml`, key validation and the JSON
+//! Schema cannot drift from the config structs.
+
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use std::fmt;
+
+/// The shape of a config value, as seen by serde.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Shape {
+    Bool,
+    Integer,
+    Float,
+    String,
+    /// A unit-variant enum deserialized from one of these strings.
+    Enum(&'static [&'static str]),
+    Optional(Box<Shape>),
+    Array(Box<Shape>),
+    /// A free-form table (e.g. a raw `toml::Table`).
+    Map,
+    Struct(Vec<(&'static str, Shape)>),
+}
+
+impl Shape {
+    /// The shape of the field `key`, if this is a struct that has it.
+    pub(crate) fn field(&self, key: &str) -> Option<&Shape> {
+        match self {
+            Shape::Struct(fields) => fields.iter().find(|(name, _)| *name == key).map(|(_, s)| s),
+            Shape::Optional(inner) => inner.field(key),
+            _ => None,
+        }
+    }
+
+    /// Field names, if this is a struct.
+    pub(crate) fn field_names(&self) -> Vec<&'static str> {
+        match self {
+            Shape::Struct(fields) => fields.iter().map(|(name, _)| *name).collect(),
+            Shape::Optional(inner) => inner.field_names(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Record the shape of `T`.
+pub(crate) fn shape_of<T: DeserializeOwned>() -> Shape {
+    let mut shape = None;
+    // Tracing feeds placeholder values, so the result itself is discarded.
+    let _ = T::deserialize(Tracer { shape: &mut shape });
+    shape.unwrap_or(Shape::Map)
+}
+
+#[derive(Debug)]
+struct TraceError(String);
+
+impl fmt::Display for TraceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for TraceError {}
+
+impl de::Error for TraceError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        TraceError(msg.to_string())
+    }
+}
+
+/// Deserializer that records the requested shape into `shape` and feeds the
+/// visitor a placeholder value of that shape.
+struct Tracer<'a> {
+    shape: &'a mut Option<Shape>,
+}
+
+macro_rules! trace_scalar {
+    ($($method:ident => $shape:expr, $visit:ident($value:expr);)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+                *self.shape = Some($shape);
+                visitor.$visit($value)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Tracer<'_> {
+    type Error = TraceError;
+
+    trace_scalar! {
+        deserialize_bool => Shape::Bool, visit_bool(false);
+        deserialize_i8 => Shape::Integer, visit_i8(0);
+        deserialize_i16 => Shape::Integer, visit_i16(0);
+        deserialize_i32 => Shape::Integer, visit_i32(0);
+        deserialize_i64 => Shape::Integer, visit_i64(0);
+        deserialize_u8 => Shape::Integer, visit_u8(0);
+        deserialize_u16 => Shape::Integer, visit_u16(0);
+        deserialize_u32 => Shape::Integer, visit_u32(0);
+        deserialize_u64 => Shape::Integer, visit_u64(0);
+        deserialize_f32 => Shape::Float, visit_f32(0.0);
+        deserialize_f64 => Shape::Float, visit_f64(0.0);
+        deserialize_char => Shape::String, visit_char(' ');
+        deserialize_str => Shape::String, visit_str("");
+        deserialize_string => Shape::String, visit_string(String::new());
+        deserialize_identifier => Shape::String, visit_str("");
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        *self.shape = Some(Shape::String);
+        visitor.visit_bytes(&[])
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        let mut inner = None;
+        let value = visitor.visit_some(Tracer { shape: &mut inner });
+        *self.shape = Some(Shape::Optional(Box::new(inner.unwrap_or(Shape::Map))));
+        value
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        *self.shape = Some(Shape::Map);
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        let mut element = None;
+        let value = visitor.visit_seq(OneElement {
+            shape: Some(&mut element),
+        });
+        *self.shape = Some(Shape::Array(Box::new(element.unwrap_or(Shape::Map))));
+        value
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        *self.shape = Some(Shape::Map);
+        visitor.visit_map(Fields {
+            names: &[],
+            shapes: Vec::new(),
+        })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        let mut access = Fields {
+            names: fields,
+            shapes: Vec::with_capacity(fields.len()),
+        };
+        let value = visitor.visit_map(&mut access);
+        *self.shape = Some(Shape::Struct(
+            fields.iter().copied().zip(access.shapes).collect(),
+        ));
+        value
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        *self.shape = Some(Shape::Enum(variants));
+        visitor.visit_enum(FirstVariant(variants.first().copied().unwrap_or_default()))
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        visitor.visit_unit()
+    }
+}
+
+/// Sequence access yielding a single traced element.
+struct OneElement<'a> {
+    shape: Option<&'a mut Option<Shape>>,
+}
+
+impl<'de> SeqAccess<'de> for OneElement<'_> {
+    type Error = TraceError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, TraceError> {
+        match self.shape.take() {
+            Some(shape) => seed.deserialize(Tracer { shape }).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Map access yielding every struct field once, tracing each value.
+struct Fields {
+    names: &'static [&'static str],
+    shapes: Vec<Shape>,
+}
+
+impl<'de> MapAccess<'de> for Fields {
+    type Error = TraceError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, TraceError> {
+        match self.names.get(self.shapes.len()) {
+            Some(name) => seed.deserialize(name.into_deserializer()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, TraceError> {
+        let mut shape = None;
+        let value = seed.deserialize(Tracer { shape: &mut shape });
+        self.shapes.push(shape.unwrap_or(Shape::Map));
+        value
+    }
+}
+
+/// Enum access choosing `variant` as a unit variant.
+struct FirstVariant(&'static str);
+
+impl<'de> EnumAccess<'de> for FirstVariant {
+    type Error = TraceError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self), TraceError> {
+        let value = seed.deserialize(self.0.into_deserializer())?;
+        Ok((value, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for FirstVariant {
+    type Error = TraceError;
+
+    fn unit_variant(self) -> Result<(), TraceError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, TraceError> {
+        let mut shape = None;
+        seed.deserialize(Tracer { shape: &mut shape })
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        visitor.visit_seq(OneElement { shape: None })
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        visitor.visit_map(Fields {
+            names: fields,
+            shapes: Vec::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Config, FileSizeConfig};
+
+    #[test]
+    fn test_shape_of_checker_config() {
+        assert_eq!(
+            shape_of::<FileSizeConfig>(),
+            Shape::Struct(vec![
+                ("enabled", Shape::Bool),
+                ("max_lines", Shape::Integer),
+                ("warn_lines", Shape::Integer),
+                (
+                    "severity",
+                    Shape::Optional(Box::new(Shape::Enum(&["info", "warning", "error"])))
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_shape_of_config_skips_internal_fields() {
+        let shape = shape_of::<Config>();
+        let names = shape.field_names();
+        assert!(names.contains(&"checkers"));
+        assert!(names.contains(&"overrides"));
+        assert!(!names.contains(&"table"));
+        assert!(!names.contains(&"sources"));
+
+        let patterns = shape
+            .field("checkers")
+            .and_then(|c| c.field("custom_patterns"));
+        let Some(Shape::Array(element)) = patterns else {
+            panic!("custom_patterns should be an array, got {patterns:?}");
+        };
+        assert!(element.field("frontmatter_key").is_some());
+        assert_eq!(
+            shape.field("include"),
+            Some(&Shape::Array(Box::new(Shape::String)))
+        );
+    }
+}
@@ -0,0 +1,250 @@
+//! JSON Schema for `.spectralintrc.toml`.
+//!
+//! The structure comes from [`shape_of`] on the config structs, so the schema
+//! lists exactly the keys serde accepts. Checker tables are described with
+//! their rule's [`RuleMeta`](crate::types::RuleMeta); other keys use the
+//! descriptions below.
+
+use serde_json::{json, Map, Value};
+
+use super::reflect::{shape_of, Shape};
+use super::Config;
+use crate::checkers::all_checker_meta;
+
+/// Descriptions of the top-level keys.
+const ROOT_DESCRIPTIONS: &[(&str, &str)] = &[
+    ("format", "Default output format."),
+    (
+        "strict",
+        "Enable strict mode, which turns on the opinionated strict-only checkers.",
+    ),
+    (
+        "profiles",
+        "Agent ecosystems to lint. Each adds that ecosystem's instruction files to `include`.",
+    ),
+    ("include", "Glob patterns of instruction files to lint."),
+    ("ignore", "Directory names or glob patterns skipped while scanning."),
+    (
+        "ignore_files",
+        "Glob patterns of files skipped even when matched by `include`.",
+    ),
+    (
+        "respect_gitignore",
+        "Skip files ignored by `.gitignore`, `.git/info/exclude` and `.ignore` files.",
+    ),
+    (
+        "plain_text",
+        "Glob patterns of files parsed as plain text rather than markdown.",
+    ),
+    (
+        "historical_files",
+        "Glob patterns of changelogs and other files whose references may point at things that no longer exist.",
+    ),
+    ("checkers", "Per-checker settings."),
+    (
+        "overrides",
+        "Checker settings for subsets of files, applied in order.",
+    ),
+    ("plugins", "External checkers run as subprocesses."),
+];
+
+/// Descriptions of options shared by checker tables.
+const OPTION_DESCRIPTIONS: &[(&str, &str)] = &[
+    ("enabled", "Run this checker."),
+    (
+        "scope",
+        "Only check files matching these glob patterns (all files when empty).",
+    ),
+    (
+        "severity",
+        "Override the severity of this checker's findings.",
+    ),
+];
+
+/// Descriptions of the keys of a `[[plugins]]` entry.
+const PLUGIN_DESCRIPTIONS: &[(&str, &str)] = &[
+    (
+        "name",
+        "Rule name, reported as `custom:<name>` for findings without a category and for plugin failures.",
+    ),
+    (
+        "command",
+        "Program and arguments. The parsed file is sent as JSON on stdin.",
+    ),
+    ("timeout", "Seconds to wait for the plugin on each file."),
+    (
+        "scope",
+        "Only send files matching these glob patterns (all files when empty).",
+    ),
+    (
+        "severity",
+        "Override the severity of this plugin's findings.",
+    ),
+];
+
+/// Build the JSON Schema (draft-07) for the config file.
+#[must_use]
+pub fn json_schema() -> Value {
+    let root = shape_of::<Config>();
+    let checkers = root.field("checkers").cloned().unwrap_or(Shape::Map);
+    let checkers_ref = json!({ "$ref": "#/definitions/checkers" });
+
+    let mut schema = to_schema(&root);
+    let properties = &mut schema["properties"];
+    properties["checkers"] = checkers_ref.clone();
+    properties["overrides"]["items"]["properties"]["checkers"] = checkers_ref;
+    properties["overrides"]["items"]["properties"]["files"]["description"] =
+        json!("Glob patterns of the files these settings apply to.");
+    properties["extends"] = json!({
+        "description": "Config files to inherit settings from, relative to this file.",
+        "anyOf": [
+            { "type": "string" },
+            { "type": "array", "items": { "type": "string" } },
+        ],
+    });
+    for (key, description) in ROOT_DESCRIPTIONS {
+        properties[*key]["description"] = json!(description);
+    }
+    for (key, description) in PLUGIN_DESCRIPTIONS {
+        properties["plugins"]["items"]["properties"][*key]["description"] = json!(description);
+    }
+
+    schema["$schema"] = json!("http://json-schema.org/draft-07/schema#");
+    schema["title"] = json!("spectralint configuration");
+    schema["definitions"] = json!({ "checkers": checkers_schema(&checkers) });
+    schema
+}
+
+/// The `[checkers]` table, with each checker described by its rule metadata.
+fn checkers_schema(shape: &Shape) -> Value {
+    let metas = all_checker_meta();
+    let mut schema = to_schema(shape);
+    let Some(Value::Object(properties)) = schema.get_mut("properties") else {
+        return schema;
+    };
+    for (key, table) in properties.iter_mut() {
+        if key == "custom_patterns" {
+            table["description"] = json!("Project-specific regex rules.");
+            continue;
+        }
+        let rule = key.replace('_', "-");
+        if let Some(meta) = metas.iter().find(|m| m.name == rule) {
+            let mut description = format!(
+                "{} (`{rule}`, default severity: {}).",
+                meta.description, meta.default_severity
+            );
+            if meta.strict_only {
+                description.push_str(" Strict-only: enabled by `strict = true`.");
+            }
+            table["description"] = json!(description);
+        }
+        if let Some(Value::Object(options)) = table.get_mut("properties") {
+            for (option, description) in OPTION_DESCRIPTIONS {
+                if let Some(option) = options.get_mut(*option) {
+                    option["description"] = json!(description);
+                }
+            }
+        }
+    }
+    schema
+}
+
+fn to_schema(shape: &Shape) -> Value {
+    match shape {
+        Shape::Bool => json!({ "type": "boolean" }),
+        Shape::Integer => json!({ "type": "integer", "minimum": 0 }),
+        Shape::Float => json!({ "type": "number" }),
+        Shape::String => json!({ "type": "string" }),
+        Shape::Enum(variants) => json!({ "type": "string", "enum": variants }),
+        // TOML has no null: an optional key is simply left out.
+        Shape::Optional(inner) => to_schema(inner),
+        Shape::Array(element) => json!({ "type": "array", "items": to_schema(element) }),
+        Shape::Map => json!({ "type": "object" }),
+        Shape::Struct(fields) => {
+            let properties: Map<String, Value> = fields
+                .iter()
+                .map(|(name, field)| ((*name).to_string(), to_schema(field)))
+                .collect();
+            json!({
+                "type": "object",
+                "properties": properties,
+                "additionalProperties": false,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The schema shipped in the repository, for editors that load it by URL.
+    const COMMITTED: &str = include_str!("../../spectralint.schema.json");
+
+    fn rendered() -> String {
+        serde_json::to_string_pretty(&json_schema()).unwrap() + "\n"
+    }
+
+    #[test]
+    fn test_committed_schema_is_up_to_date() {
+        assert!(
+            COMMITTED == rendered(),
+            "spectralint.schema.json is out of date; regenerate it with \
+             `cargo run -- config schema > spectralint.schema.json`"
+        );
+    }
+
+    #[test]
+    fn test_every_checker_table_has_rule_description() {
+        let schema = json_schema();
+        let checkers = schema["definitions"]["checkers"]["properties"]
+            .as_object()
+            .unwrap();
+        assert_eq!(
+            checkers.len(),
+            all_checker_meta().len() + 1,
+            "every checker table except custom_patterns should map to a rule"
+        );
+        for (key, table) in checkers {
+            assert!(
+                table["description"].as_str().is_some_and(|d| !d.is_empty()),
+                "checkers.{key} has no description"
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_root_key_is_described() {
+        let schema = json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+        for (key, property) in properties {
+            assert!(
+                property["description"]
+                    .as_str()
+                    .is_some_and(|d| !d.is_empty()),
+                "`{key}` has no description"
+            );
+        }
+        for (key, _) in ROOT_DESCRIPTIONS {
+            assert!(
+                properties.contains_key(*key),
+                "described key `{key}` no longer exists"
+            );
+        }
+    }
+
+    #[test]
+    fn test_schema_is_closed_and_shares_checkers_definition() {
+        let schema = json_schema();
+        let file_size = &schema["definitions"]["checkers"]["properties"]["file_size"];
+        assert_eq!(file_size["additionalProperties"], json!(false));
+        assert_eq!(
+            file_size["properties"]["severity"]["enum"],
+            json!(["info", "warning", "error"])
+        );
+        assert_eq!(
+            schema["properties"]["overrides"]["items"]["properties"]["checkers"]["$ref"],
+            json!("#/definitions/checkers")
+        );
+    }
+}
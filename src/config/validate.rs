@@ -0,0 +1,288 @@
+//! Config validation: unknown tables and keys, invalid globs and invalid
+//! regexes, each located by file and line.
+//!
+//! serde ignores unknown keys and the scanner drops unparsable globs, so a
+//! typo in `.spectralintrc.toml` otherwise just makes a setting do nothing.
+
+use anyhow::{Context, Result};
+use globset::GlobBuilder;
+use std::fmt;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use strsim::jaro_winkler;
+use toml_edit::{Item, TableLike};
+
+use super::reflect::{shape_of, Shape};
+use super::Config;
+
+/// Keys whose string values (or list items) are glob patterns.
+const GLOB_KEYS: &[&str] = &[
+    "include",
+    "ignore",
+    "ignore_files",
+    "plain_text",
+    "historical_files",
+    "scope",
+    "files",
+];
+
+/// Keys whose string values (or list items) are regular expressions.
+const REGEX_KEYS: &[&str] = &["pattern", "extra_patterns", "unless"];
+
+/// Minimum Jaro-Winkler similarity for a "did you mean" suggestion.
+const SUGGESTION_THRESHOLD: f64 = 0.8;
+
+/// A problem found in a config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    pub file: PathBuf,
+    pub line: usize,
+    pub message: String,
+    /// The known key closest to an unknown one.
+    pub suggestion: Option<String>,
+}
+
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.file.display(), self.line, self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " (did you mean `{suggestion}`?)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Validate the config file at `path`.
+pub fn validate_file(path: &Path) -> Result<Vec<ConfigIssue>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config {}", path.display()))?;
+    validate_str(&content, path)
+}
+
+/// Validate config `content`, reporting issues against `file`.
+pub(crate) fn validate_str(content: &str, file: &Path) -> Result<Vec<ConfigIssue>> {
+    let document = toml_edit::ImDocument::parse(content)
+        .map_err(|e| anyhow::anyhow!("Config parse error in {}: {e}", file.display()))?;
+    let root = shape_of::<Config>();
+    let mut validator = Validator {
+        content,
+        file,
+        checkers: root.field("checkers").cloned().unwrap_or(Shape::Map),
+        issues: Vec::new(),
+    };
+    validator.table(document.as_table(), &root, "");
+    validator.issues.sort_by_key(|issue| issue.line);
+    Ok(validator.issues)
+}
+
+struct Validator<'a> {
+    content: &'a str,
+    file: &'a Path,
+    /// Shape of `[checkers]`, also used for the raw `checkers` table of
+    /// each `[[overrides]]` entry.
+    checkers: Shape,
+    issues: Vec<ConfigIssue>,
+}
+
+impl Validator<'_> {
+    fn table(&mut self, table: &dyn TableLike, shape: &Shape, path: &str) {
+        for (key, item) in table.iter() {
+            let span = table
+                .key(key)
+                .and_then(toml_edit::Key::span)
+                .or_else(|| item.span());
+            let full = if path.is_empty() {
+                key.to_string()
+            } else {
+                format!("{path}.{key}")
+            };
+            if path.is_empty() && key == "extends" {
+                continue;
+            }
+            let Some(field) = shape.field(key) else {
+                let kind = if item.is_table_like() { "table" } else { "key" };
+                let suggestion = suggest(key, &shape.field_names());
+                self.report(span, format!("Unknown {kind} `{full}`"), suggestion);
+                continue;
+            };
+            let field = if key == "checkers" && path.starts_with("overrides") {
+                self.checkers.clone()
+            } else {
+                field.clone()
+            };
+            self.item(item, &field, &full, key);
+        }
+    }
+
+    fn item(&mut self, item: &Item, shape: &Shape, path: &str, key: &str) {
+        match shape {
+            Shape::Optional(inner) => self.item(item, inner, path, key),
+            Shape::Struct(_) => {
+                if let Some(table) = item.as_table_like() {
+                    self.table(table, shape, path);
+                }
+            }
+            Shape::Array(element) => {
+                if let Some(tables) = item.as_array_of_tables() {
+                    for (i, table) in tables.iter().enumerate() {
+                        self.table(table, element, &format!("{path}[{i}]"));
+                    }
+                } else if let Some(array) = item.as_array() {
+                    for (i, value) in array.iter().enumerate() {
+                        if let Some(table) = value.as_inline_table() {
+                            self.table(table, element, &format!("{path}[{i}]"));
+                        } else if let Some(s) = value.as_str() {
+                            self.string(s, value.span(), path, key);
+                        }
+                    }
+                }
+            }
+            Shape::String => {
+                if let Some(s) = item.as_str() {
+                    self.string(s, item.span(), path, key);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn string(&mut self, value: &str, span: Option<Range<usize>>, path: &str, key: &str) {
+        if GLOB_KEYS.contains(&key) {
+            if let Err(e) = GlobBuilder::new(value).build() {
+                self.report(
+                    span,
+                    format!("Invalid glob `{value}` in `{path}`: {}", e.kind()),
+                    None,
+                );
+            }
+        } else if REGEX_KEYS.contains(&key) {
+            if let Err(e) = regex::Regex::new(value) {
+                let reason = e.to_string();
+                let reason = reason.lines().last().unwrap_or_default().trim();
+                self.report(
+                    span,
+                    format!("Invalid regex `{value}` in `{path}`: {reason}"),
+                    None,
+                );
+            }
+        }
+    }
+
+    fn report(&mut self, span: Option<Range<usize>>, message: String, suggestion: Option<&str>) {
+        let line = span.map_or(1, |s| {
+            self.content[..s.start.min(self.content.len())]
+                .matches('\n')
+                .count()
+                + 1
+        });
+        self.issues.push(ConfigIssue {
+            file: self.file.to_path_buf(),
+            line,
+            message,
+            suggestion: suggestion.map(String::from),
+        });
+    }
+}
+
+/// The candidate most similar to `key`, if any is close enough.
+fn suggest<'a>(key: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let key = key.replace('-', "_");
+    candidates
+        .iter()
+        .map(|c| (*c, jaro_winkler(&key, c)))
+        .filter(|&(_, score)| score >= SUGGESTION_THRESHOLD)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(c, _)| c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issues(content: &str) -> Vec<String> {
+        validate_str(content, Path::new("cfg.toml"))
+            .unwrap()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_config_has_no_issues() {
+        assert!(issues(Config::default_toml()).is_empty());
+        assert!(issues(Config::strict_toml()).is_empty());
+        assert!(issues(Config::minimal_toml()).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_table_and_key_with_suggestions() {
+        let found = issues(
+            "strict = true\n\
+             \n\
+             [checkers.vague_directives]\n\
+             enabled = true\n\
+             \n\
+             [checkers.file_size]\n\
+             max_line = 500\n",
+        );
+        assert_eq!(
+            found,
+            vec![
+                "cfg.toml:3: Unknown table `checkers.vague_directives` (did you mean `vague_directive`?)",
+                "cfg.toml:7: Unknown key `checkers.file_size.max_line` (did you mean `max_lines`?)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_key_without_close_match() {
+        assert_eq!(
+            issues("colour_scheme = \"dark\"\n"),
+            vec!["cfg.toml:1: Unknown key `colour_scheme`"]
+        );
+    }
+
+    #[test]
+    fn test_extends_is_known() {
+        assert!(issues("extends = [\"../base.toml\"]\n").is_empty());
+    }
+
+    #[test]
+    fn test_invalid_globs_and_regexes() {
+        let found = issues(
+            "include = [\"CLAUDE.md\", \"docs/[abc\"]\n\
+             \n\
+             [checkers.vague_directive]\n\
+             extra_patterns = [\"(?i)maybe\", \"(unclosed\"]\n\
+             \n\
+             [[checkers.custom_patterns]]\n\
+             name = \"todo\"\n\
+             pattern = \"TODO(\"\n\
+             message = \"todo\"\n",
+        );
+        assert_eq!(found.len(), 3, "{found:?}");
+        assert!(found[0].starts_with("cfg.toml:1: Invalid glob `docs/[abc` in `include`"));
+        assert!(found[1].starts_with(
+            "cfg.toml:4: Invalid regex `(unclosed` in `checkers.vague_directive.extra_patterns`"
+        ));
+        assert!(found[2].starts_with(
+            "cfg.toml:8: Invalid regex `TODO(` in `checkers.custom_patterns[0].pattern`"
+        ));
+    }
+
+    #[test]
+    fn test_override_checkers_validated_against_checkers_config() {
+        let found = issues(
+            "[[overrides]]\n\
+             files = [\".claude/commands/**\"]\n\
+             checkers.dangerous_commands.severity = \"error\"\n\
+             checkers.file_size.warn_lines = 10\n",
+        );
+        assert_eq!(
+            found,
+            vec![
+                "cfg.toml:3: Unknown table `overrides[0].checkers.dangerous_commands` (did you mean `dangerous_command`?)"
+            ]
+        );
+    }
+}
@@ -0,0 +1,337 @@
+//! Baseline files: a record of accepted findings, so a repo can adopt
+//! spectralint without fixing every pre-existing diagnostic first.
+//!
+//! Each entry is identified by a fingerprint over the rule, the file, the
+//! message with numbers normalized away, and the content of the flagged line.
+//! Line numbers are deliberately left out so unrelated edits that shift a
+//! finding up or down do not make it "new".
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::types::Diagnostic;
+
+use super::cache::fnv1a_hash;
+
+const BASELINE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    version: u32,
+    pub entries: Vec<BaselineEntry>,
+}
+
+/// One accepted finding. `rule`, `file` and `message` are informational;
+/// matching only uses `fingerprint`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub fingerprint: String,
+    pub rule: String,
+    pub file: String,
+    pub message: String,
+}
+
+impl Baseline {
+    /// Record every diagnostic in `diagnostics` as accepted. `sources` are
+    /// the in-memory contents the run checked (see
+    /// [`Inputs::sources`](super::Inputs::sources)); other files are read
+    /// from disk.
+    #[must_use]
+    pub fn from_diagnostics(
+        diagnostics: &[Diagnostic],
+        project_root: &Path,
+        sources: &HashMap<PathBuf, String>,
+    ) -> Self {
+        let mut lines = LineCache::new(sources);
+        let mut entries: Vec<BaselineEntry> = diagnostics
+            .iter()
+            .map(|d| BaselineEntry {
+                fingerprint: fingerprint(d, project_root, &mut lines),
+                rule: d.category.to_string(),
+                file: relative(&d.file, project_root),
+                message: d.message.clone(),
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            (&a.file, &a.rule, &a.fingerprint).cmp(&(&b.file, &b.rule, &b.fingerprint))
+        });
+        Self {
+            version: BASELINE_VERSION,
+            entries,
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read baseline {}", path.display()))?;
+        let baseline: Self = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse baseline {}", path.display()))?;
+        if baseline.version != BASELINE_VERSION {
+            anyhow::bail!(
+                "Unsupported baseline version {} in {} (expected {BASELINE_VERSION})",
+                baseline.version,
+                path.display()
+            );
+        }
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut json = serde_json::to_string_pretty(self)?;
+        json.push('\n');
+        std::fs::write(path, json)
+            .with_context(|| format!("Failed to write baseline {}", path.display()))
+    }
+
+    /// Drop diagnostics recorded in the baseline and return the entries that
+    /// no longer match any diagnostic. Each entry absorbs at most one
+    /// diagnostic, so a finding that appears one more time than recorded is
+    /// still reported. `sources` are as for [`from_diagnostics`](Self::from_diagnostics).
+    pub fn filter(
+        &self,
+        diagnostics: &mut Vec<Diagnostic>,
+        project_root: &Path,
+        sources: &HashMap<PathBuf, String>,
+    ) -> Vec<&BaselineEntry> {
+        let mut remaining: HashMap<&str, Vec<&BaselineEntry>> = HashMap::new();
+        for entry in &self.entries {
+            remaining.entry(&entry.fingerprint).or_default().push(entry);
+        }
+
+        let mut lines = LineCache::new(sources);
+        diagnostics.retain(|d| {
+            let key = fingerprint(d, project_root, &mut lines);
+            let matched = remaining.get_mut(key.as_str()).and_then(Vec::pop);
+            matched.is_none()
+        });
+
+        let mut stale: Vec<&BaselineEntry> = remaining.into_values().flatten().collect();
+        stale.sort_by(|a, b| (&a.file, &a.rule, &a.message).cmp(&(&b.file, &b.rule, &b.message)));
+        stale
+    }
+}
+
+/// Lines of files read while fingerprinting, so each file is read once.
+/// Files with an in-memory source are split from it rather than read.
+struct LineCache<'a> {
+    sources: &'a HashMap<PathBuf, String>,
+    lines: HashMap<PathBuf, Vec<String>>,
+}
+
+impl<'a> LineCache<'a> {
+    fn new(sources: &'a HashMap<PathBuf, String>) -> Self {
+        Self {
+            sources,
+            lines: HashMap::new(),
+        }
+    }
+
+    fn line(&mut self, file: &Path, line: usize) -> &str {
+        let sources = self.sources;
+        let lines = self.lines.entry(file.to_path_buf()).or_insert_with(|| {
+            let content = match sources.get(file) {
+                Some(content) => Some(content.clone()),
+                None => std::fs::read_to_string(file).ok(),
+            };
+            content
+                .map(|c| c.lines().map(String::from).collect())
+                .unwrap_or_default()
+        });
+        line.checked_sub(1)
+            .and_then(|i| lines.get(i))
+            .map_or("", String::as_str)
+    }
+}
+
+fn relative(file: &Path, project_root: &Path) -> String {
+    file.strip_prefix(project_root)
+        .unwrap_or(file)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Collapse whitespace, and in messages replace digit runs with `#` so
+/// embedded line numbers and counts do not change the fingerprint.
+fn normalize(text: &str, digits: bool) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last_digit = false;
+    for word in text.split_whitespace() {
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        for c in word.chars() {
+            if digits && c.is_ascii_digit() {
+                if !last_digit {
+                    out.push('#');
+                }
+                last_digit = true;
+            } else {
+                out.push(c);
+                last_digit = false;
+            }
+        }
+        last_digit = false;
+    }
+    out
+}
+
+fn fingerprint(d: &Diagnostic, project_root: &Path, lines: &mut LineCache<'_>) -> String {
+    let key = format!(
+        "{}\0{}\0{}\0{}",
+        d.category,
+        relative(&d.file, project_root),
+        normalize(&d.message, true),
+        normalize(lines.line(&d.file, d.line), false)
+    );
+    format!("{:016x}", fnv1a_hash(key.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Category, Severity};
+    use std::sync::Arc;
+
+    fn diag(file: &Path, line: usize, category: Category, message: &str) -> Diagnostic {
+        Diagnostic {
+            file: Arc::new(file.to_path_buf()),
+            line,
+            column: None,
+            end_line: None,
+            end_column: None,
+            severity: Severity::Warning,
+            category,
+            message: message.to_string(),
+            suggestion: None,
+            fix: None,
+            related: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            normalize("Duplicate key (first  defined on line 12)", true),
+            "Duplicate key (first defined on line #)"
+        );
+        assert_eq!(normalize("  keep 42\tdigits ", false), "keep 42 digits");
+    }
+
+    #[test]
+    fn test_baselined_findings_survive_line_shifts() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let file = root.join("CLAUDE.md");
+        std::fs::write(&file, "# Rules\nSee `missing.md`.\n").unwrap();
+        let before = vec![diag(
+            &file,
+            2,
+            Category::DeadReference,
+            "missing.md not found",
+        )];
+        let baseline = Baseline::from_diagnostics(&before, root, &HashMap::new());
+        assert_eq!(baseline.entries[0].file, "CLAUDE.md");
+        assert_eq!(baseline.entries[0].rule, "dead-reference");
+
+        std::fs::write(
+            &file,
+            "# Rules\n\nIntro.\n\nSee `missing.md`.\nSee `gone.md`.\n",
+        )
+        .unwrap();
+        let mut after = vec![
+            diag(&file, 5, Category::DeadReference, "missing.md not found"),
+            diag(&file, 6, Category::DeadReference, "gone.md not found"),
+        ];
+        let stale = baseline.filter(&mut after, root, &HashMap::new());
+        assert!(stale.is_empty());
+        assert_eq!(after.len(), 1);
+        assert_eq!(after[0].message, "gone.md not found");
+    }
+
+    #[test]
+    fn test_stale_entries_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let file = root.join("CLAUDE.md");
+        std::fs::write(&file, "Try to be brief.\n").unwrap();
+        let baseline = Baseline::from_diagnostics(
+            &[diag(&file, 1, Category::VagueDirective, "vague")],
+            root,
+            &HashMap::new(),
+        );
+
+        std::fs::write(&file, "Be brief.\n").unwrap();
+        let mut diagnostics = vec![diag(&file, 1, Category::VagueDirective, "vague")];
+        let stale = baseline.filter(&mut diagnostics, root, &HashMap::new());
+        assert_eq!(
+            diagnostics.len(),
+            1,
+            "changed line content is a new finding"
+        );
+        assert_eq!(stale.len(), 1);
+        assert_eq!(stale[0].message, "vague");
+    }
+
+    #[test]
+    fn test_fingerprints_use_in_memory_sources() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let file = root.join("CLAUDE.md");
+        std::fs::write(&file, "# Rules\nTry to be brief.\n").unwrap();
+        let staged = HashMap::from([(file.clone(), "Try to be brief.\n".to_string())]);
+        let d = diag(&file, 1, Category::VagueDirective, "vague");
+
+        let baseline = Baseline::from_diagnostics(std::slice::from_ref(&d), root, &staged);
+        let mut diagnostics = vec![d.clone()];
+        baseline.filter(&mut diagnostics, root, &staged);
+        assert!(diagnostics.is_empty(), "same source matches");
+
+        let mut diagnostics = vec![d];
+        baseline.filter(&mut diagnostics, root, &HashMap::new());
+        assert_eq!(diagnostics.len(), 1, "line 1 on disk is the heading");
+    }
+
+    #[test]
+    fn test_each_entry_matches_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let file = root.join("CLAUDE.md");
+        std::fs::write(&file, "x\n").unwrap();
+        let d = diag(&file, 1, Category::RepeatedWord, "repeated");
+        let baseline = Baseline::from_diagnostics(std::slice::from_ref(&d), root, &HashMap::new());
+
+        let mut diagnostics = vec![d.clone(), d];
+        baseline.filter(&mut diagnostics, root, &HashMap::new());
+        assert_eq!(diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let file = root.join("AGENTS.md");
+        std::fs::write(&file, "x\n").unwrap();
+        let baseline = Baseline::from_diagnostics(
+            &[diag(
+                &file,
+                1,
+                Category::CustomPattern("todo".into()),
+                "TODO",
+            )],
+            root,
+            &HashMap::new(),
+        );
+        let path = root.join("baseline.json");
+        baseline.save(&path).unwrap();
+
+        let loaded = Baseline::load(&path).unwrap();
+        assert_eq!(loaded.entries, baseline.entries);
+        assert_eq!(loaded.entries[0].rule, "custom:todo");
+
+        std::fs::write(&path, "{\"version\": 99, \"entries\": []}").unwrap();
+        let err = Baseline::load(&path).unwrap_err();
+        assert!(err.to_string().contains("Unsupported baseline version"));
+    }
+}
@@ -1,25 +1,55 @@
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::SystemTime;
 
-use crate::types::{Category, Diagnostic, Fix, Severity};
+use crate::checkers::ProjectInputs;
+use crate::parser::types::ParsedFile;
+use crate::types::{Category, Diagnostic, Fix, RelatedLocation, Severity};
 
-const CACHE_VERSION: &str = "1";
+const CACHE_VERSION: &str = "5";
 const CACHE_FILE: &str = ".spectralint-cache.json";
 /// Maximum cache file size (50 MiB) to prevent memory exhaustion from crafted caches.
 const MAX_CACHE_SIZE: u64 = 50 * 1024 * 1024;
 
-/// On-disk cache format.
+/// On-disk cache format. Parsed files and single-file checker diagnostics are
+/// stored per file and stay valid while the file's content hash matches;
+/// project-wide checker diagnostics are stored per checker, keyed by a hash
+/// of the inputs that checker reads.
 #[derive(Debug, Serialize, Deserialize)]
-struct CacheFile {
+struct CacheFile<'a> {
     version: String,
     spectralint_version: String,
     config_hash: u64,
-    files_hash: u64,
+    files: BTreeMap<String, CachedFile<'a>>,
+    project: BTreeMap<String, CachedProject>,
+}
+
+/// The leading fields of [`CacheFile`], read first to reject stale caches.
+#[derive(Debug, Deserialize)]
+struct CacheHeader {
+    version: String,
+    spectralint_version: String,
+    config_hash: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedFile<'a> {
+    content_hash: u64,
+    parsed: Cow<'a, ParsedFile>,
     diagnostics: Vec<CachedDiagnostic>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedProject {
+    inputs_hash: u64,
+    diagnostics: Vec<CachedDiagnostic>,
+    /// Content hashes of files outside the scan that checkers read.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    external_inputs: BTreeMap<String, u64>,
+}
+
 /// A diagnostic stored in the cache (uses owned `String` path instead of `Arc<PathBuf>`).
 #[derive(Debug, Serialize, Deserialize)]
 struct CachedDiagnostic {
@@ -38,6 +68,8 @@ struct CachedDiagnostic {
     suggestion: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     fix: Option<Box<Fix>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    related: Vec<RelatedLocation>,
 }
 
 impl CachedDiagnostic {
@@ -53,14 +85,13 @@ impl CachedDiagnostic {
             message: d.message.clone(),
             suggestion: d.suggestion.clone(),
             fix: d.fix.clone(),
+            related: d.related.clone(),
         }
     }
 
-    /// Used in tests for roundtrip verification; the main load path interns Arc paths instead.
-    #[cfg(test)]
-    fn into_diagnostic(self) -> Diagnostic {
+    fn into_diagnostic(self, file: Arc<PathBuf>) -> Diagnostic {
         Diagnostic {
-            file: Arc::new(PathBuf::from(self.file)),
+            file,
             line: self.line,
             column: self.column,
             end_line: self.end_line,
@@ -70,12 +101,67 @@ impl CachedDiagnostic {
             message: self.message,
             suggestion: self.suggestion,
             fix: self.fix,
+            related: self.related,
         }
     }
 }
 
+/// Cached results from a previous run. Empty when the cache is missing,
+/// corrupt, or was written by another version or config.
+#[derive(Debug, Default)]
+pub(crate) struct Cache {
+    files: HashMap<PathBuf, (u64, ParsedFile, Vec<Diagnostic>)>,
+    project: HashMap<String, ProjectEntry>,
+}
+
+/// One project-wide checker's findings and what they were computed from.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ProjectEntry {
+    /// [`ProjectHashes::of`] the checker's inputs.
+    pub inputs_hash: u64,
+    pub diagnostics: Vec<Diagnostic>,
+    pub external: ExternalInputs,
+}
+
+/// Files outside the scan that project-wide checkers read (see
+/// [`Checker::external_inputs`](crate::checkers::Checker::external_inputs)),
+/// with the hash of their content when they were read.
+pub(crate) type ExternalInputs = BTreeMap<PathBuf, u64>;
+
+impl Cache {
+    /// The cached parse and single-file diagnostics for `path`, if its
+    /// content is unchanged.
+    pub(crate) fn file(
+        &self,
+        path: &Path,
+        content_hash: u64,
+    ) -> Option<(&ParsedFile, &[Diagnostic])> {
+        let (hash, parsed, diagnostics) = self.files.get(path)?;
+        (*hash == content_hash).then_some((parsed, diagnostics.as_slice()))
+    }
+
+    /// The cached results of the project-wide checker `name`, if none of
+    /// its inputs changed.
+    pub(crate) fn project(&self, name: &str, inputs_hash: u64) -> Option<&ProjectEntry> {
+        let entry = self.project.get(name)?;
+        let unchanged = entry.inputs_hash == inputs_hash
+            && entry
+                .external
+                .iter()
+                .all(|(path, hash)| external_hash(path) == *hash);
+        unchanged.then_some(entry)
+    }
+}
+
+/// One file's results, as written by [`save`].
+pub(crate) struct FileEntry<'a> {
+    pub parsed: &'a ParsedFile,
+    pub content_hash: u64,
+    pub diagnostics: &'a [Diagnostic],
+}
+
 /// Stable FNV-1a hash (deterministic across Rust versions, unlike DefaultHasher).
-fn fnv1a_hash(data: &[u8]) -> u64 {
+pub(crate) fn fnv1a_hash(data: &[u8]) -> u64 {
     let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
     for &byte in data {
         hash ^= u64::from(byte);
@@ -84,64 +170,115 @@ fn fnv1a_hash(data: &[u8]) -> u64 {
     hash
 }
 
+/// Fold `value` into a running FNV-style hash.
+fn mix(hash: u64, value: u64) -> u64 {
+    (hash ^ value).wrapping_mul(0x0100_0000_01b3)
+}
+
 /// Hash a string using FNV-1a.
 fn hash_str(s: &str) -> u64 {
     fnv1a_hash(s.as_bytes())
 }
 
-/// Compute a combined hash of all scanned file paths and their metadata (mtime + size).
-/// Uses metadata instead of reading file contents to avoid a redundant double-read
-/// (files are read again later during parsing). Files are sorted by path for determinism.
-pub(crate) fn compute_files_hash(files: &[PathBuf]) -> u64 {
-    debug_assert!(
-        files.windows(2).all(|w| w[0] <= w[1]),
-        "files must be sorted"
-    );
-
-    let mut combined: u64 = 0xcbf2_9ce4_8422_2325;
-    for path in files {
-        // Hash the path bytes directly (no allocation via display().to_string())
-        let path_hash = fnv1a_hash(path.as_os_str().as_encoded_bytes());
-        combined ^= path_hash;
-        combined = combined.wrapping_mul(0x0100_0000_01b3);
-
-        match std::fs::metadata(path) {
-            Ok(meta) => {
-                // Hash file size
-                let size = meta.len();
-                combined ^= size;
-                combined = combined.wrapping_mul(0x0100_0000_01b3);
-
-                // Hash mtime as seconds since UNIX_EPOCH
-                if let Ok(mtime) = meta.modified() {
-                    let secs = mtime
-                        .duration_since(SystemTime::UNIX_EPOCH)
-                        .unwrap_or_default()
-                        .as_secs();
-                    combined ^= secs;
-                    combined = combined.wrapping_mul(0x0100_0000_01b3);
-                }
-            }
-            Err(_) => {
-                // File unreadable: incorporate a sentinel so the hash differs
-                // from the case where the file simply doesn't exist in the list.
-                combined ^= 0xdead_beef_dead_beef;
-                combined = combined.wrapping_mul(0x0100_0000_01b3);
-                tracing::warn!(
-                    "Cannot stat {}, cache hash may be inaccurate",
-                    path.display()
-                );
+/// Hash of a file's content, the key for its cached parse and diagnostics.
+pub(crate) fn content_hash(content: &str) -> u64 {
+    hash_str(content)
+}
+
+/// Hash of a file read by a checker, with a sentinel for a missing or
+/// unreadable file so that creating it invalidates the cache.
+fn external_hash(path: &Path) -> u64 {
+    match std::fs::read(path) {
+        Ok(content) => fnv1a_hash(&content),
+        Err(_) => 0xdead_beef_dead_beef,
+    }
+}
+
+/// Hash the current content of `paths`, the external inputs of a run.
+pub(crate) fn hash_external_inputs(paths: impl IntoIterator<Item = PathBuf>) -> ExternalInputs {
+    paths
+        .into_iter()
+        .map(|path| {
+            let hash = external_hash(&path);
+            (path, hash)
+        })
+        .collect()
+}
+
+/// Hashes of the parts of the project that project-wide checkers read (see
+/// [`ProjectInputs`]). Files outside the scan are tracked separately as
+/// [`ExternalInputs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ProjectHashes {
+    files: u64,
+    settings: u64,
+    listing: u64,
+}
+
+impl ProjectHashes {
+    /// Hash the path and content hash of every scanned file, the settings
+    /// files, and the listing of project files (which decides whether
+    /// references and globs resolve). `files` must be sorted by path.
+    pub(crate) fn new(
+        files: &[(&Path, u64)],
+        settings_files: &[PathBuf],
+        path_index: &[PathBuf],
+    ) -> Self {
+        debug_assert!(
+            files.windows(2).all(|w| w[0].0 <= w[1].0),
+            "files must be sorted"
+        );
+        const SEED: u64 = 0xcbf2_9ce4_8422_2325;
+
+        let mut files_hash = SEED;
+        for (path, hash) in files {
+            files_hash = mix(files_hash, fnv1a_hash(path.as_os_str().as_encoded_bytes()));
+            files_hash = mix(files_hash, *hash);
+        }
+        let mut settings = SEED;
+        for path in settings_files {
+            settings = mix(settings, fnv1a_hash(path.as_os_str().as_encoded_bytes()));
+            settings = mix(settings, external_hash(path));
+        }
+        // The cache file itself appears in the listing after the first run.
+        let tmp_file = Path::new(CACHE_FILE).with_extension("tmp");
+        let mut listing = SEED;
+        for rel in path_index {
+            if rel == Path::new(CACHE_FILE) || *rel == tmp_file {
+                continue;
             }
+            listing = mix(listing, fnv1a_hash(rel.as_os_str().as_encoded_bytes()));
+        }
+        Self {
+            files: files_hash,
+            settings,
+            listing,
         }
     }
 
-    combined
+    /// Combined hash of the selected inputs.
+    pub(crate) fn of(&self, inputs: ProjectInputs) -> u64 {
+        [
+            (inputs.files, self.files),
+            (inputs.settings, self.settings),
+            (inputs.listing, self.listing),
+        ]
+        .into_iter()
+        .fold(0xcbf2_9ce4_8422_2325, |combined, (used, hash)| {
+            mix(combined, if used { hash } else { 0 })
+        })
+    }
 }
 
 /// Compute a hash of the config by serializing it to a canonical string.
 /// We hash the TOML config file content directly if available, otherwise
-/// hash the serialized default.
-pub(crate) fn compute_config_hash(config_path: Option<&Path>, project_root: &Path) -> u64 {
+/// hash the serialized default. `layers` are the other config files that
+/// feed into the result (`extends` bases and nested directory configs).
+pub(crate) fn compute_config_hash(
+    config_path: Option<&Path>,
+    project_root: &Path,
+    layers: &[&Path],
+) -> u64 {
     // Try explicit config path first, then auto-discovered path
     let default_path;
     let path = match config_path {
@@ -152,20 +289,28 @@ pub(crate) fn compute_config_hash(config_path: Option<&Path>, project_root: &Pat
         }
     };
 
-    match std::fs::read_to_string(path) {
+    let base = match std::fs::read_to_string(path) {
         Ok(content) => hash_str(&content),
         Err(_) => hash_str("__default_config__"),
-    }
+    };
+    layers.iter().fold(base, |combined, layer| {
+        let content = std::fs::read_to_string(layer).unwrap_or_default();
+        mix(
+            mix(combined, fnv1a_hash(layer.as_os_str().as_encoded_bytes())),
+            hash_str(&content),
+        )
+    })
 }
 
-/// Try to load cached diagnostics. Returns `Some(diagnostics)` if the cache
-/// is valid (same version, config, and file contents), or `None` if the cache
-/// is missing, corrupt, or stale.
-pub(crate) fn load(
-    project_root: &Path,
-    files_hash: u64,
-    config_hash: u64,
-) -> Option<Vec<Diagnostic>> {
+/// Load the cache written by a previous run with the same version and
+/// config. Returns an empty cache if it is missing, corrupt, or stale.
+pub(crate) fn load(project_root: &Path, config_hash: u64) -> Cache {
+    read_cache_file(project_root, config_hash)
+        .map(into_cache)
+        .unwrap_or_default()
+}
+
+fn read_cache_file(project_root: &Path, config_hash: u64) -> Option<CacheFile<'static>> {
     let cache_path = project_root.join(CACHE_FILE);
     let meta = std::fs::metadata(&cache_path).ok()?;
     if meta.len() > MAX_CACHE_SIZE {
@@ -176,58 +321,126 @@ pub(crate) fn load(
         return None;
     }
     let content = std::fs::read_to_string(&cache_path).ok()?;
-    let cache: CacheFile = serde_json::from_str(&content).ok()?;
 
-    if cache.version != CACHE_VERSION
-        || cache.spectralint_version != env!("CARGO_PKG_VERSION")
-        || cache.config_hash != config_hash
-        || cache.files_hash != files_hash
+    // Check the version before decoding the rest, whose shape may have
+    // changed since the cache was written.
+    let header: CacheHeader = serde_json::from_str(&content).ok()?;
+    if header.version != CACHE_VERSION
+        || header.spectralint_version != env!("CARGO_PKG_VERSION")
+        || header.config_hash != config_hash
     {
+        tracing::debug!("Cache is stale (version {}), ignoring", header.version);
         return None;
     }
+    match serde_json::from_str(&content) {
+        Ok(cache) => Some(cache),
+        Err(e) => {
+            tracing::warn!("Cache file is corrupt ({e}), ignoring");
+            None
+        }
+    }
+}
+
+fn into_cache(cache: CacheFile<'static>) -> Cache {
+    // Share one `Arc` per path between parsed files and their diagnostics.
+    let mut paths = HashMap::<String, Arc<PathBuf>>::new();
+    let mut intern = |file: &str| {
+        paths
+            .entry(file.to_string())
+            .or_insert_with(|| Arc::new(PathBuf::from(file)))
+            .clone()
+    };
 
-    let mut path_cache = std::collections::HashMap::<String, Arc<PathBuf>>::new();
-    Some(
-        cache
+    let mut files = HashMap::with_capacity(cache.files.len());
+    for (key, cached) in cache.files {
+        let mut parsed = cached.parsed.into_owned();
+        parsed.path = intern(&key);
+        let diagnostics = cached
             .diagnostics
             .into_iter()
             .map(|cd| {
-                let file = path_cache
-                    .entry(cd.file.clone())
-                    .or_insert_with(|| Arc::new(PathBuf::from(&cd.file)))
-                    .clone();
-                Diagnostic {
-                    file,
-                    line: cd.line,
-                    column: cd.column,
-                    end_line: cd.end_line,
-                    end_column: cd.end_column,
-                    severity: cd.severity,
-                    category: cd.category,
-                    message: cd.message,
-                    suggestion: cd.suggestion,
-                    fix: cd.fix,
-                }
+                let file = intern(&cd.file);
+                cd.into_diagnostic(file)
             })
-            .collect(),
-    )
+            .collect();
+        files.insert(
+            PathBuf::from(key),
+            (cached.content_hash, parsed, diagnostics),
+        );
+    }
+    let project = cache
+        .project
+        .into_iter()
+        .map(|(name, p)| {
+            let diagnostics = p
+                .diagnostics
+                .into_iter()
+                .map(|cd| {
+                    let file = intern(&cd.file);
+                    cd.into_diagnostic(file)
+                })
+                .collect();
+            let external = p
+                .external_inputs
+                .into_iter()
+                .map(|(path, hash)| (PathBuf::from(path), hash))
+                .collect();
+            let entry = ProjectEntry {
+                inputs_hash: p.inputs_hash,
+                diagnostics,
+                external,
+            };
+            (name, entry)
+        })
+        .collect();
+    Cache { files, project }
 }
 
-/// Save diagnostics to the cache file.
+/// Save per-file results and each project-wide checker's results to the
+/// cache file.
 pub(crate) fn save(
     project_root: &Path,
-    files_hash: u64,
     config_hash: u64,
-    diagnostics: &[Diagnostic],
+    files: &[FileEntry<'_>],
+    project: &BTreeMap<String, ProjectEntry>,
 ) {
     let cache = CacheFile {
         version: CACHE_VERSION.to_string(),
         spectralint_version: env!("CARGO_PKG_VERSION").to_string(),
         config_hash,
-        files_hash,
-        diagnostics: diagnostics
+        files: files
             .iter()
-            .map(CachedDiagnostic::from_diagnostic)
+            .map(|entry| {
+                let cached = CachedFile {
+                    content_hash: entry.content_hash,
+                    parsed: Cow::Borrowed(entry.parsed),
+                    diagnostics: entry
+                        .diagnostics
+                        .iter()
+                        .map(CachedDiagnostic::from_diagnostic)
+                        .collect(),
+                };
+                (entry.parsed.path.display().to_string(), cached)
+            })
+            .collect(),
+        project: project
+            .iter()
+            .map(|(name, entry)| {
+                let cached = CachedProject {
+                    inputs_hash: entry.inputs_hash,
+                    diagnostics: entry
+                        .diagnostics
+                        .iter()
+                        .map(CachedDiagnostic::from_diagnostic)
+                        .collect(),
+                    external_inputs: entry
+                        .external
+                        .iter()
+                        .map(|(path, hash)| (path.display().to_string(), *hash))
+                        .collect(),
+                };
+                (name.clone(), cached)
+            })
             .collect(),
     };
 
@@ -245,6 +458,60 @@ pub(crate) fn save(
 mod tests {
     use super::*;
 
+    fn parsed(path: &str, lines: &[&str]) -> ParsedFile {
+        let raw_lines: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+        let in_code_block = crate::parser::build_code_block_mask(&raw_lines);
+        ParsedFile {
+            path: Arc::new(PathBuf::from(path)),
+            sections: vec![],
+            tables: vec![],
+            file_refs: vec![],
+            directives: vec![],
+            suppress_comments: vec![],
+            frontmatter: crate::parser::frontmatter::parse_frontmatter(&raw_lines),
+            raw_lines,
+            in_code_block,
+        }
+    }
+
+    fn diag(file: &str, line: usize, category: Category, message: &str) -> Diagnostic {
+        Diagnostic {
+            file: Arc::new(PathBuf::from(file)),
+            line,
+            column: None,
+            end_line: None,
+            end_column: None,
+            severity: Severity::Warning,
+            category,
+            message: message.to_string(),
+            suggestion: None,
+            fix: None,
+            related: Vec::new(),
+        }
+    }
+
+    fn save_one(root: &Path, config_hash: u64, content_hash: u64, diags: &[Diagnostic]) {
+        let file = parsed("CLAUDE.md", &["# Title"]);
+        let entry = FileEntry {
+            parsed: &file,
+            content_hash,
+            diagnostics: diags,
+        };
+        save(root, config_hash, &[entry], &project_entry(7, Vec::new()));
+    }
+
+    fn project_entry(
+        inputs_hash: u64,
+        diagnostics: Vec<Diagnostic>,
+    ) -> BTreeMap<String, ProjectEntry> {
+        let entry = ProjectEntry {
+            inputs_hash,
+            diagnostics,
+            external: ExternalInputs::new(),
+        };
+        BTreeMap::from([("dead-reference".to_string(), entry)])
+    }
+
     #[test]
     fn test_hash_str_deterministic() {
         let h1 = hash_str("hello world");
@@ -259,6 +526,12 @@ mod tests {
         assert_ne!(h1, h2);
     }
 
+    #[test]
+    fn test_fnv1a_hash_known_value() {
+        // FNV-1a 64-bit hash of empty string should be the offset basis
+        assert_eq!(fnv1a_hash(b""), 0xcbf2_9ce4_8422_2325);
+    }
+
     #[test]
     fn test_cached_diagnostic_roundtrip() {
         let diag = Diagnostic {
@@ -272,10 +545,12 @@ mod tests {
             message: "broken ref".to_string(),
             suggestion: Some("fix it".to_string()),
             fix: None,
+            related: Vec::new(),
         };
 
         let cached = CachedDiagnostic::from_diagnostic(&diag);
-        let restored = cached.into_diagnostic();
+        let file = Arc::new(PathBuf::from(&cached.file));
+        let restored = cached.into_diagnostic(file);
 
         assert_eq!(*restored.file, PathBuf::from("test.md"));
         assert_eq!(restored.line, 42);
@@ -288,89 +563,169 @@ mod tests {
         assert_eq!(restored.suggestion, Some("fix it".to_string()));
     }
 
+    #[test]
+    fn test_cached_diagnostic_with_fix_roundtrip() {
+        use crate::types::Replacement;
+        let diag = Diagnostic {
+            fix: Some(Box::new(Fix {
+                description: "Remove duplicate".to_string(),
+                replacements: vec![Replacement {
+                    line: 5,
+                    start_col: 10,
+                    end_col: 14,
+                    new_text: String::new(),
+                }],
+            })),
+            ..diag("CLAUDE.md", 5, Category::RepeatedWord, "repeated 'the'")
+        };
+
+        let cached = CachedDiagnostic::from_diagnostic(&diag);
+        let json = serde_json::to_string(&cached).unwrap();
+        let deserialized: CachedDiagnostic = serde_json::from_str(&json).unwrap();
+        let restored = deserialized.into_diagnostic(Arc::clone(&diag.file));
+
+        let fix = restored.fix.unwrap();
+        assert_eq!(fix.replacements.len(), 1);
+        assert_eq!(fix.replacements[0].start_col, 10);
+        assert_eq!(fix.replacements[0].end_col, 14);
+        assert_eq!(fix.replacements[0].new_text, "");
+    }
+
     #[test]
     fn test_cache_load_nonexistent() {
         let dir = tempfile::tempdir().unwrap();
-        let result = load(dir.path(), 0, 0);
-        assert!(result.is_none());
+        let cache = load(dir.path(), 0);
+        assert!(cache.files.is_empty());
+        assert!(cache.project.is_empty());
     }
 
     #[test]
-    fn test_cache_save_and_load() {
+    fn test_file_entry_reused_while_content_unchanged() {
         let dir = tempfile::tempdir().unwrap();
-        let diags = vec![Diagnostic {
-            file: Arc::new(PathBuf::from("CLAUDE.md")),
-            line: 10,
-            column: None,
-            end_line: None,
-            end_column: None,
-            severity: Severity::Error,
-            category: Category::VagueDirective,
-            message: "vague".to_string(),
-            suggestion: None,
-            fix: None,
-        }];
-
-        save(dir.path(), 123, 456, &diags);
+        let diags = vec![diag("CLAUDE.md", 1, Category::VagueDirective, "vague")];
+        save_one(dir.path(), 456, 123, &diags);
 
-        let loaded = load(dir.path(), 123, 456);
-        assert!(loaded.is_some());
-        let loaded = loaded.unwrap();
+        let cache = load(dir.path(), 456);
+        let (parsed, loaded) = cache.file(Path::new("CLAUDE.md"), 123).unwrap();
+        assert_eq!(parsed.raw_lines, ["# Title"]);
         assert_eq!(loaded.len(), 1);
-        assert_eq!(loaded[0].line, 10);
         assert_eq!(loaded[0].category, Category::VagueDirective);
+        assert!(
+            Arc::ptr_eq(&parsed.path, &loaded[0].file),
+            "parsed file and its diagnostics should share the path"
+        );
+
+        assert!(cache.file(Path::new("CLAUDE.md"), 999).is_none());
+        assert!(cache.file(Path::new("AGENTS.md"), 123).is_none());
     }
 
     #[test]
-    fn test_cache_invalidated_by_files_hash() {
+    fn test_project_entry_keyed_by_checker_and_inputs_hash() {
         let dir = tempfile::tempdir().unwrap();
-        save(dir.path(), 123, 456, &[]);
+        let diags = vec![diag("a.md", 3, Category::DeadReference, "dead ref")];
+        save(dir.path(), 1, &[], &project_entry(42, diags));
 
-        // Different files_hash should invalidate
-        let result = load(dir.path(), 999, 456);
-        assert!(result.is_none());
+        let cache = load(dir.path(), 1);
+        assert_eq!(
+            cache.project("dead-reference", 42).unwrap().diagnostics[0].message,
+            "dead ref"
+        );
+        assert!(cache.project("dead-reference", 43).is_none());
+        assert!(cache.project("enum-drift", 42).is_none());
     }
 
     #[test]
-    fn test_cache_invalidated_by_config_hash() {
+    fn test_project_cache_invalidated_by_external_input() {
         let dir = tempfile::tempdir().unwrap();
-        save(dir.path(), 123, 456, &[]);
+        let outside = tempfile::tempdir().unwrap();
+        let rules = outside.path().join("rules.md");
+        std::fs::write(&rules, "@../CLAUDE.md\n").unwrap();
+        let missing = outside.path().join("later.md");
+        let save_external = |external: ExternalInputs| {
+            let mut project = project_entry(42, Vec::new());
+            project
+                .values_mut()
+                .for_each(|e| e.external = external.clone());
+            save(dir.path(), 1, &[], &project);
+        };
+        let external = hash_external_inputs([rules.clone(), missing.clone()]);
+        save_external(external.clone());
+
+        let cache = load(dir.path(), 1);
+        assert_eq!(
+            cache.project("dead-reference", 42).unwrap().external,
+            external
+        );
+
+        std::fs::write(&rules, "# Rules\n").unwrap();
+        assert!(load(dir.path(), 1).project("dead-reference", 42).is_none());
 
-        // Different config_hash should invalidate
-        let result = load(dir.path(), 123, 999);
-        assert!(result.is_none());
+        save_external(hash_external_inputs([rules, missing.clone()]));
+        std::fs::write(&missing, "").unwrap();
+        assert!(load(dir.path(), 1).project("dead-reference", 42).is_none());
     }
 
     #[test]
-    fn test_cache_invalidated_by_corrupt_json() {
+    fn test_cache_invalidated_by_config_hash() {
         let dir = tempfile::tempdir().unwrap();
-        let cache_path = dir.path().join(CACHE_FILE);
-        std::fs::write(&cache_path, "not valid json{{{").unwrap();
+        save_one(dir.path(), 456, 123, &[]);
 
-        let result = load(dir.path(), 0, 0);
-        assert!(result.is_none());
+        let cache = load(dir.path(), 999);
+        assert!(cache.file(Path::new("CLAUDE.md"), 123).is_none());
+        assert!(cache.project("dead-reference", 7).is_none());
     }
 
     #[test]
     fn test_cache_invalidated_by_version_change() {
-        // Simulate a cache with a different CACHE_VERSION
         let dir = tempfile::tempdir().unwrap();
         let cache_path = dir.path().join(CACHE_FILE);
         let cache = CacheFile {
-            version: "0".to_string(), // old version
+            version: "1".to_string(), // old version
             spectralint_version: env!("CARGO_PKG_VERSION").to_string(),
             config_hash: 100,
-            files_hash: 200,
-            diagnostics: vec![],
+            files: BTreeMap::new(),
+            project: BTreeMap::from([(
+                "dead-reference".to_string(),
+                CachedProject {
+                    inputs_hash: 200,
+                    diagnostics: vec![],
+                    external_inputs: BTreeMap::new(),
+                },
+            )]),
         };
         std::fs::write(&cache_path, serde_json::to_string(&cache).unwrap()).unwrap();
-        let result = load(dir.path(), 200, 100);
         assert!(
-            result.is_none(),
+            load(dir.path(), 100)
+                .project("dead-reference", 200)
+                .is_none(),
             "Different CACHE_VERSION should invalidate cache"
         );
     }
 
+    #[test]
+    fn test_cache_from_older_format_is_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(CACHE_FILE),
+            format!(
+                r#"{{"version":"2","spectralint_version":"{}","config_hash":100,
+                    "files":{{"CLAUDE.md":{{"content_hash":1,"parsed":"old shape"}}}}}}"#,
+                env!("CARGO_PKG_VERSION")
+            ),
+        )
+        .unwrap();
+        assert!(load(dir.path(), 100)
+            .file(Path::new("CLAUDE.md"), 1)
+            .is_none());
+    }
+
+    #[test]
+    fn test_directive_without_column_deserializes() {
+        let directive: crate::parser::types::Directive =
+            serde_json::from_str(r#"{"line": 3, "pattern_matched": "try to"}"#).unwrap();
+        assert_eq!((directive.line, directive.column), (3, 0));
+    }
+
     #[test]
     fn test_cache_invalidated_by_spectralint_version() {
         let dir = tempfile::tempdir().unwrap();
@@ -379,119 +734,87 @@ mod tests {
             version: CACHE_VERSION.to_string(),
             spectralint_version: "0.0.0-fake".to_string(), // different binary version
             config_hash: 100,
-            files_hash: 200,
-            diagnostics: vec![],
+            files: BTreeMap::new(),
+            project: BTreeMap::from([(
+                "dead-reference".to_string(),
+                CachedProject {
+                    inputs_hash: 200,
+                    diagnostics: vec![],
+                    external_inputs: BTreeMap::new(),
+                },
+            )]),
         };
         std::fs::write(&cache_path, serde_json::to_string(&cache).unwrap()).unwrap();
-        let result = load(dir.path(), 200, 100);
         assert!(
-            result.is_none(),
+            load(dir.path(), 100)
+                .project("dead-reference", 200)
+                .is_none(),
             "Different spectralint version should invalidate cache"
         );
     }
 
     #[test]
-    fn test_cached_diagnostic_with_fix_roundtrip() {
-        use crate::types::{Fix, Replacement};
-        let diag = Diagnostic {
-            file: Arc::new(PathBuf::from("CLAUDE.md")),
-            line: 5,
-            column: Some(10),
-            end_line: Some(5),
-            end_column: Some(20),
-            severity: Severity::Info,
-            category: Category::RepeatedWord,
-            message: "repeated 'the'".to_string(),
-            suggestion: Some("Remove the duplicate word".to_string()),
-            fix: Some(Box::new(Fix {
-                description: "Remove duplicate".to_string(),
-                replacements: vec![Replacement {
-                    line: 5,
-                    start_col: 10,
-                    end_col: 14,
-                    new_text: String::new(),
-                }],
-            })),
-        };
+    fn test_cache_corrupted_file_returns_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join(CACHE_FILE);
 
-        let cached = CachedDiagnostic::from_diagnostic(&diag);
-        let json = serde_json::to_string(&cached).unwrap();
-        let deserialized: CachedDiagnostic = serde_json::from_str(&json).unwrap();
-        let restored = deserialized.into_diagnostic();
+        // Write invalid JSON to simulate a corrupted cache file
+        std::fs::write(&cache_path, "{{not valid json at all!@#$%").unwrap();
 
-        assert_eq!(restored.line, 5);
-        assert_eq!(restored.column, Some(10));
-        assert!(restored.fix.is_some());
-        let fix = restored.fix.unwrap();
-        assert_eq!(fix.replacements.len(), 1);
-        assert_eq!(fix.replacements[0].start_col, 10);
-        assert_eq!(fix.replacements[0].end_col, 14);
-        assert_eq!(fix.replacements[0].new_text, "");
+        let cache = load(dir.path(), 0);
+        assert!(cache.files.is_empty() && cache.project.is_empty());
     }
 
     #[test]
-    fn test_cache_save_and_load_multiple_diagnostics() {
+    fn test_cache_rejected_when_exceeds_max_size() {
         let dir = tempfile::tempdir().unwrap();
-        let diags = vec![
-            Diagnostic {
-                file: Arc::new(PathBuf::from("a.md")),
-                line: 1,
-                column: None,
-                end_line: None,
-                end_column: None,
-                severity: Severity::Error,
-                category: Category::DeadReference,
-                message: "dead ref 1".to_string(),
-                suggestion: None,
-                fix: None,
-            },
-            Diagnostic {
-                file: Arc::new(PathBuf::from("b.md")),
-                line: 10,
-                column: Some(5),
-                end_line: Some(10),
-                end_column: Some(15),
-                severity: Severity::Warning,
-                category: Category::VagueDirective,
-                message: "vague".to_string(),
-                suggestion: Some("be specific".to_string()),
-                fix: None,
-            },
-            Diagnostic {
-                file: Arc::new(PathBuf::from("a.md")),
-                line: 20,
-                column: None,
-                end_line: None,
-                end_column: None,
-                severity: Severity::Info,
-                category: Category::TokenBudget,
-                message: "big file".to_string(),
-                suggestion: None,
-                fix: None,
-            },
-        ];
-
-        save(dir.path(), 111, 222, &diags);
-        let loaded = load(dir.path(), 111, 222).unwrap();
-        assert_eq!(loaded.len(), 3);
-        assert_eq!(loaded[0].category, Category::DeadReference);
-        assert_eq!(loaded[1].category, Category::VagueDirective);
-        assert_eq!(loaded[1].column, Some(5));
-        assert_eq!(loaded[2].category, Category::TokenBudget);
+        let cache_path = dir.path().join(CACHE_FILE);
+
+        // Create a cache file larger than MAX_CACHE_SIZE (50 MiB).
+        // We use a sparse/allocated file by writing a single byte past the limit.
+        let f = std::fs::File::create(&cache_path).unwrap();
+        f.set_len(MAX_CACHE_SIZE + 1).unwrap();
+        drop(f);
+
+        let cache = load(dir.path(), 0);
+        assert!(
+            cache.files.is_empty() && cache.project.is_empty(),
+            "Cache file exceeding MAX_CACHE_SIZE should be rejected"
+        );
     }
 
     #[test]
-    fn test_cache_empty_diagnostics() {
+    fn test_parsed_file_roundtrip() {
         let dir = tempfile::tempdir().unwrap();
-        save(dir.path(), 42, 84, &[]);
-        let loaded = load(dir.path(), 42, 84).unwrap();
-        assert!(loaded.is_empty(), "Empty diagnostics should roundtrip");
+        let mut file = parsed(
+            "agents/a.md",
+            &["---", "name: a", "---", "# Title", "```", "code", "```"],
+        );
+        file.sections = vec![crate::parser::types::Section {
+            level: 1,
+            title: "Title".to_string(),
+            line: 4,
+            end_line: 7,
+        }];
+        let entry = FileEntry {
+            parsed: &file,
+            content_hash: 1,
+            diagnostics: &[],
+        };
+        save(dir.path(), 0, &[entry], &BTreeMap::new());
+
+        let cache = load(dir.path(), 0);
+        let (loaded, _) = cache.file(Path::new("agents/a.md"), 1).unwrap();
+        assert_eq!(loaded.raw_lines, file.raw_lines);
+        assert_eq!(loaded.in_code_block, file.in_code_block);
+        assert_eq!(loaded.sections[0].title, "Title");
+        assert_eq!(loaded.frontmatter_get("name").unwrap().line, 2);
     }
 
     #[test]
     fn test_compute_config_hash_no_config_file() {
         let dir = tempfile::tempdir().unwrap();
-        let hash = compute_config_hash(None, dir.path());
+        let hash = compute_config_hash(None, dir.path(), &[]);
         // Should use the default sentinel
         assert_eq!(hash, hash_str("__default_config__"));
     }
@@ -501,7 +824,7 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let config_content = "[checkers.dead_reference]\nenabled = false\n";
         std::fs::write(dir.path().join(".spectralintrc.toml"), config_content).unwrap();
-        let hash = compute_config_hash(None, dir.path());
+        let hash = compute_config_hash(None, dir.path(), &[]);
         assert_eq!(hash, hash_str(config_content));
     }
 
@@ -509,179 +832,74 @@ mod tests {
     fn test_compute_config_hash_different_content_different_hash() {
         let dir = tempfile::tempdir().unwrap();
         std::fs::write(dir.path().join(".spectralintrc.toml"), "version1").unwrap();
-        let h1 = compute_config_hash(None, dir.path());
+        let h1 = compute_config_hash(None, dir.path(), &[]);
         std::fs::write(dir.path().join(".spectralintrc.toml"), "version2").unwrap();
-        let h2 = compute_config_hash(None, dir.path());
+        let h2 = compute_config_hash(None, dir.path(), &[]);
         assert_ne!(h1, h2);
     }
 
     #[test]
-    fn test_fnv1a_hash_known_value() {
-        // FNV-1a 64-bit hash of empty string should be the offset basis
-        assert_eq!(fnv1a_hash(b""), 0xcbf2_9ce4_8422_2325);
-    }
-
-    #[test]
-    fn test_compute_files_hash_deterministic() {
-        let dir = tempfile::tempdir().unwrap();
-        let f1 = dir.path().join("a.md");
-        let f2 = dir.path().join("b.md");
-        std::fs::write(&f1, "hello").unwrap();
-        std::fs::write(&f2, "world").unwrap();
-
-        let files = vec![f1.clone(), f2.clone()];
-        let h1 = compute_files_hash(&files);
-        let h2 = compute_files_hash(&files);
-        assert_eq!(h1, h2, "Same files should produce same hash");
-    }
-
-    #[test]
-    #[cfg(debug_assertions)]
-    #[should_panic(expected = "files must be sorted")]
-    fn test_compute_files_hash_rejects_unsorted() {
-        let dir = tempfile::tempdir().unwrap();
-        let f1 = dir.path().join("a.md");
-        let f2 = dir.path().join("b.md");
-        std::fs::write(&f1, "hello").unwrap();
-        std::fs::write(&f2, "world").unwrap();
-
-        // Unsorted input should trigger debug assertion
-        compute_files_hash(&[f2, f1]);
-    }
-
-    #[test]
-    fn test_cache_rejected_when_exceeds_max_size() {
-        let dir = tempfile::tempdir().unwrap();
-        let cache_path = dir.path().join(CACHE_FILE);
-
-        // Create a cache file larger than MAX_CACHE_SIZE (50 MiB).
-        // We use a sparse/allocated file by writing a single byte past the limit.
-        let f = std::fs::File::create(&cache_path).unwrap();
-        f.set_len(MAX_CACHE_SIZE + 1).unwrap();
-        drop(f);
+    fn test_project_hashes_track_inputs() {
+        let a = Path::new("a.md");
+        let b = Path::new("b.md");
+        let index = vec![PathBuf::from("a.md"), PathBuf::from("src/lib.rs")];
+        let base = ProjectHashes::new(&[(a, 1), (b, 2)], &[], &index);
+
+        assert_eq!(base, ProjectHashes::new(&[(a, 1), (b, 2)], &[], &index));
+        let edited = ProjectHashes::new(&[(a, 1), (b, 3)], &[], &index);
+        assert_ne!(
+            base.of(ProjectInputs::FILES),
+            edited.of(ProjectInputs::FILES)
+        );
+        let removed = ProjectHashes::new(&[(a, 1)], &[], &index);
+        assert_ne!(
+            base.of(ProjectInputs::FILES),
+            removed.of(ProjectInputs::FILES)
+        );
 
-        let result = load(dir.path(), 0, 0);
-        assert!(
-            result.is_none(),
-            "Cache file exceeding MAX_CACHE_SIZE should be rejected"
+        let relisted = ProjectHashes::new(&[(a, 1), (b, 2)], &[], &index[..1]);
+        assert_eq!(
+            base.of(ProjectInputs::FILES),
+            relisted.of(ProjectInputs::FILES)
+        );
+        assert_ne!(
+            base.of(ProjectInputs::FILES_AND_LISTING),
+            relisted.of(ProjectInputs::FILES_AND_LISTING)
         );
     }
 
     #[test]
-    fn test_cache_corrupted_file_returns_none() {
+    fn test_project_hashes_track_settings() {
         let dir = tempfile::tempdir().unwrap();
-        let cache_path = dir.path().join(CACHE_FILE);
+        let settings = vec![dir.path().join("settings.json")];
+        std::fs::write(&settings[0], "{}").unwrap();
+        let base = ProjectHashes::new(&[], &settings, &[]);
+        std::fs::write(&settings[0], "{\"permissions\": {}}").unwrap();
+        let edited = ProjectHashes::new(&[], &settings, &[]);
 
-        // Write invalid JSON to simulate a corrupted cache file
-        std::fs::write(&cache_path, "{{not valid json at all!@#$%").unwrap();
-
-        let result = load(dir.path(), 0, 0);
-        assert!(
-            result.is_none(),
-            "Corrupted cache file should return None gracefully"
+        assert_ne!(base.of(ProjectInputs::ALL), edited.of(ProjectInputs::ALL));
+        assert_eq!(
+            base.of(ProjectInputs::FILES_AND_LISTING),
+            edited.of(ProjectInputs::FILES_AND_LISTING)
         );
     }
 
     #[test]
-    fn test_cache_roundtrip_with_suggestions() {
-        use crate::types::{Fix, Replacement};
-
-        let dir = tempfile::tempdir().unwrap();
-        let diags = vec![
-            Diagnostic {
-                file: Arc::new(PathBuf::from("CLAUDE.md")),
-                line: 5,
-                column: Some(10),
-                end_line: Some(5),
-                end_column: Some(20),
-                severity: Severity::Warning,
-                category: Category::RepeatedWord,
-                message: "repeated 'the'".to_string(),
-                suggestion: Some("Remove the duplicate word".to_string()),
-                fix: Some(Box::new(Fix {
-                    description: "Remove duplicate".to_string(),
-                    replacements: vec![Replacement {
-                        line: 5,
-                        start_col: 10,
-                        end_col: 14,
-                        new_text: String::new(),
-                    }],
-                })),
-            },
-            Diagnostic {
-                file: Arc::new(PathBuf::from("AGENTS.md")),
-                line: 20,
-                column: None,
-                end_line: None,
-                end_column: None,
-                severity: Severity::Info,
-                category: Category::VagueDirective,
-                message: "vague language".to_string(),
-                suggestion: Some("Be more specific about what to do".to_string()),
-                fix: None,
-            },
-        ];
-
-        save(dir.path(), 100, 200, &diags);
-        let loaded = load(dir.path(), 100, 200).unwrap();
-
-        assert_eq!(loaded.len(), 2);
-
-        // First diagnostic: check suggestion and fix survive roundtrip
+    fn test_project_hashes_ignore_cache_file() {
+        let files = [(Path::new("a.md"), 1)];
+        let index = vec![PathBuf::from("a.md")];
+        let mut with_cache = index.clone();
+        with_cache.push(PathBuf::from(CACHE_FILE));
         assert_eq!(
-            loaded[0].suggestion,
-            Some("Remove the duplicate word".to_string())
+            ProjectHashes::new(&files, &[], &index),
+            ProjectHashes::new(&files, &[], &with_cache)
         );
-        assert!(loaded[0].fix.is_some());
-        let fix = loaded[0].fix.as_ref().unwrap();
-        assert_eq!(fix.description, "Remove duplicate");
-        assert_eq!(fix.replacements.len(), 1);
-        assert_eq!(fix.replacements[0].line, 5);
-        assert_eq!(fix.replacements[0].start_col, 10);
-        assert_eq!(fix.replacements[0].end_col, 14);
-        assert_eq!(fix.replacements[0].new_text, "");
-
-        // Second diagnostic: check suggestion survives, fix is None
-        assert_eq!(
-            loaded[1].suggestion,
-            Some("Be more specific about what to do".to_string())
-        );
-        assert!(loaded[1].fix.is_none());
     }
 
     #[test]
-    fn test_cache_invalidated_by_file_change() {
-        let dir = tempfile::tempdir().unwrap();
-        let original_files_hash: u64 = 12345;
-        let config_hash: u64 = 67890;
-
-        // Save cache with the original files_hash
-        let diags = vec![Diagnostic {
-            file: Arc::new(PathBuf::from("CLAUDE.md")),
-            line: 1,
-            column: None,
-            end_line: None,
-            end_column: None,
-            severity: Severity::Error,
-            category: Category::DeadReference,
-            message: "dead ref".to_string(),
-            suggestion: None,
-            fix: None,
-        }];
-
-        save(dir.path(), original_files_hash, config_hash, &diags);
-
-        // Verify cache loads with original hash
-        let loaded = load(dir.path(), original_files_hash, config_hash);
-        assert!(loaded.is_some(), "Cache should load with matching hashes");
-        assert_eq!(loaded.unwrap().len(), 1);
-
-        // Now try to load with a different files_hash (simulating a file change)
-        let changed_files_hash: u64 = 99999;
-        let loaded = load(dir.path(), changed_files_hash, config_hash);
-        assert!(
-            loaded.is_none(),
-            "Cache should be invalidated when files_hash changes"
-        );
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "files must be sorted")]
+    fn test_project_hashes_reject_unsorted() {
+        ProjectHashes::new(&[(Path::new("b.md"), 1), (Path::new("a.md"), 2)], &[], &[]);
     }
 }
@@ -0,0 +1,434 @@
+use anyhow::Result;
+use globset::Glob;
+use std::collections::HashSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::checkers::import_chain::{canonical_key, MAX_IMPORT_DEPTH};
+use crate::checkers::token_budget::estimate_tokens;
+use crate::checkers::utils::{copilot_apply_to, glob_matches_any, resolve_import};
+use crate::config::Config;
+use crate::parser::types::{FileRefKind, ParsedFile};
+use crate::parser::PLAIN_TEXT_FILE_NAMES;
+
+use super::scanner;
+
+/// Per-directory memory files, in the order agents load them.
+const MEMORY_FILES: &[&str] = &[
+    "CLAUDE.md",
+    ".claude/CLAUDE.md",
+    "AGENTS.md",
+    "GEMINI.md",
+    "CLAUDE.local.md",
+];
+
+/// Repository-wide Copilot instructions, applied to every request.
+const COPILOT_REPO_INSTRUCTIONS: &str = ".github/copilot-instructions.md";
+
+/// Why a file is part of the effective instruction set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContextReason {
+    /// A memory file in the target directory or one of its ancestors.
+    Memory,
+    /// Inlined through an `@path` import in another source.
+    Import { from: PathBuf },
+    /// A rule applied to every request (`alwaysApply`, legacy rule files,
+    /// repository-wide Copilot instructions).
+    Always,
+    /// A path-scoped rule whose glob matches the target.
+    Glob { pattern: String },
+}
+
+impl fmt::Display for ContextReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContextReason::Memory => write!(f, "memory"),
+            ContextReason::Import { from } => write!(f, "imported by {}", from.display()),
+            ContextReason::Always => write!(f, "always applied"),
+            ContextReason::Glob { pattern } => write!(f, "matches {pattern}"),
+        }
+    }
+}
+
+/// One file of the effective instruction set. Paths are project-relative.
+#[derive(Debug, Clone)]
+pub struct ContextSource {
+    pub path: PathBuf,
+    pub reason: ContextReason,
+    pub tokens: usize,
+    pub lines: Vec<String>,
+}
+
+/// The ordered instruction set an agent loads when working on a path.
+#[derive(Debug, Clone, Default)]
+pub struct ContextSet {
+    pub sources: Vec<ContextSource>,
+}
+
+impl ContextSet {
+    #[must_use]
+    pub fn total_tokens(&self) -> usize {
+        self.sources.iter().map(|s| s.tokens).sum()
+    }
+
+    /// The sources concatenated in load order, each preceded by a comment
+    /// naming the file it came from.
+    #[must_use]
+    pub fn text(&self) -> String {
+        let mut out = String::new();
+        for source in &self.sources {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(&format!("<!-- {} -->\n", source.path.display()));
+            for line in &source.lines {
+                out.push_str(line);
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+/// Compute the instruction set for `target` (a file or directory inside
+/// `project_root`) from the scanned files: memory files from the root down to
+/// the target with their imports inlined after them, then rules applied to
+/// every request, then path-scoped rules whose globs match the target.
+pub fn context(project_root: &Path, config: &Config, target: &Path) -> Result<ContextSet> {
+    let scan = scanner::scan(project_root, config);
+    let rel_target = relative_target(project_root, target)?;
+    let target_is_dir = project_root.join(&rel_target).is_dir();
+    let scanned: HashSet<&Path> = scan.files.iter().map(PathBuf::as_path).collect();
+
+    let mut builder = Builder {
+        project_root,
+        seen: HashSet::new(),
+        set: ContextSet::default(),
+    };
+
+    let target_dir = if target_is_dir {
+        rel_target.as_path()
+    } else {
+        rel_target.parent().unwrap_or(Path::new(""))
+    };
+    let mut dirs: Vec<&Path> = target_dir.ancestors().collect();
+    dirs.reverse();
+    for dir in dirs {
+        for name in MEMORY_FILES {
+            let path = project_root.join(dir).join(name);
+            if scanned.contains(path.as_path()) {
+                builder.add_with_imports(&path, ContextReason::Memory, 0);
+            }
+        }
+    }
+
+    let mut path_scoped = Vec::new();
+    for path in &scan.files {
+        let Ok(file) = crate::parser::parse_file(path) else {
+            continue;
+        };
+        match rule_reason(&file, project_root) {
+            Some(RuleScope::Always) => builder.add(file, ContextReason::Always),
+            Some(RuleScope::Globs(globs)) => {
+                let matched = globs.into_iter().find(|pattern| {
+                    applies_to(pattern, &rel_target, target_is_dir, &scan.path_index)
+                });
+                if let Some(pattern) = matched {
+                    path_scoped.push((file, ContextReason::Glob { pattern }));
+                }
+            }
+            None => {}
+        }
+    }
+    for (file, reason) in path_scoped {
+        builder.add(file, reason);
+    }
+
+    Ok(builder.set)
+}
+
+/// `target` relative to `project_root`, rejecting paths outside the project.
+fn relative_target(project_root: &Path, target: &Path) -> Result<PathBuf> {
+    let absolute = if target.is_absolute() {
+        target.to_path_buf()
+    } else {
+        project_root.join(target)
+    };
+    let absolute = absolute.canonicalize().unwrap_or(absolute);
+    let root = project_root
+        .canonicalize()
+        .unwrap_or_else(|_| project_root.to_path_buf());
+    match absolute.strip_prefix(&root) {
+        Ok(rel) => Ok(rel.to_path_buf()),
+        Err(_) => anyhow::bail!(
+            "{} is outside the project root {}",
+            target.display(),
+            project_root.display()
+        ),
+    }
+}
+
+enum RuleScope {
+    Always,
+    Globs(Vec<String>),
+}
+
+/// How a rule file is activated, or `None` for memory files and rules that
+/// are only loaded on request.
+fn rule_reason(file: &ParsedFile, project_root: &Path) -> Option<RuleScope> {
+    let rel = file.path.strip_prefix(project_root).ok()?;
+    let name = rel.file_name()?.to_str()?;
+
+    if rel == Path::new(COPILOT_REPO_INSTRUCTIONS) || PLAIN_TEXT_FILE_NAMES.contains(&name) {
+        return Some(RuleScope::Always);
+    }
+    if let Some((_, globs)) = copilot_apply_to(file, project_root) {
+        return Some(RuleScope::Globs(globs));
+    }
+    if rel.extension().is_some_and(|e| e == "mdc") {
+        if file
+            .frontmatter_get("alwaysApply")
+            .is_some_and(|e| e.value.as_bool() == Some(true))
+        {
+            return Some(RuleScope::Always);
+        }
+        let globs = file.frontmatter_get("globs")?.value.items();
+        return Some(RuleScope::Globs(
+            globs.into_iter().map(String::from).collect(),
+        ));
+    }
+    None
+}
+
+/// True if `pattern` matches the target file, or any project file under the
+/// target directory, by relative path or basename.
+fn applies_to(pattern: &str, target: &Path, target_is_dir: bool, path_index: &[PathBuf]) -> bool {
+    let Ok(glob) = Glob::new(pattern) else {
+        return false;
+    };
+    let matcher = glob.compile_matcher();
+    if !target_is_dir {
+        return glob_matches_any(&matcher, [target]);
+    }
+    glob_matches_any(
+        &matcher,
+        path_index.iter().filter(|rel| rel.starts_with(target)),
+    )
+}
+
+struct Builder<'a> {
+    project_root: &'a Path,
+    seen: HashSet<PathBuf>,
+    set: ContextSet,
+}
+
+impl Builder<'_> {
+    fn add(&mut self, file: ParsedFile, reason: ContextReason) {
+        if !self.seen.insert(canonical_key(&file.path)) {
+            return;
+        }
+        let path = file
+            .path
+            .strip_prefix(self.project_root)
+            .unwrap_or(&file.path)
+            .to_path_buf();
+        self.set.sources.push(ContextSource {
+            path,
+            reason,
+            tokens: estimate_tokens(&file.raw_lines),
+            lines: file.raw_lines,
+        });
+    }
+
+    /// Add `path` followed by its imports, depth-first, as agents inline them.
+    fn add_with_imports(&mut self, path: &Path, reason: ContextReason, depth: usize) {
+        if self.seen.contains(&canonical_key(path)) {
+            return;
+        }
+        let Ok(file) = crate::parser::parse_file(path) else {
+            return;
+        };
+        let imports: Vec<PathBuf> = file
+            .file_refs
+            .iter()
+            .filter(|r| r.kind == FileRefKind::Import)
+            .filter_map(|r| resolve_import(r, self.project_root))
+            .filter(|p| p.is_file())
+            .collect();
+        let from = file
+            .path
+            .strip_prefix(self.project_root)
+            .unwrap_or(&file.path)
+            .to_path_buf();
+        self.add(file, reason);
+        if depth >= MAX_IMPORT_DEPTH {
+            return;
+        }
+        for import in imports {
+            let reason = ContextReason::Import { from: from.clone() };
+            self.add_with_imports(&import, reason, depth + 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write(root: &Path, rel: &str, content: &str) {
+        let path = root.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn config() -> Config {
+        Config {
+            include: vec!["**/*.md".into(), "**/*.mdc".into()],
+            ..Config::default()
+        }
+    }
+
+    fn sources(root: &Path, target: &str) -> Vec<(String, String)> {
+        context(root, &config(), Path::new(target))
+            .unwrap()
+            .sources
+            .into_iter()
+            .map(|s| (s.path.display().to_string(), s.reason.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_ancestor_memory_files_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "CLAUDE.md", "# Root\n");
+        write(root, "AGENTS.md", "# Agents\n");
+        write(root, "services/CLAUDE.md", "# Services\n");
+        write(root, "services/billing/CLAUDE.md", "# Billing\n");
+        write(root, "services/other/CLAUDE.md", "# Other\n");
+        write(root, "services/billing/src/lib.rs", "");
+
+        let got = sources(root, "services/billing");
+        let paths: Vec<&str> = got.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "CLAUDE.md",
+                "AGENTS.md",
+                "services/CLAUDE.md",
+                "services/billing/CLAUDE.md"
+            ]
+        );
+        assert!(got.iter().all(|(_, r)| r == "memory"));
+    }
+
+    #[test]
+    fn test_gitignored_local_memory_file_is_loaded() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, ".gitignore", "CLAUDE.local.md\n");
+        write(root, "CLAUDE.md", "# Root\n");
+        write(root, "CLAUDE.local.md", "# Mine\n");
+
+        let got = sources(root, ".");
+        let paths: Vec<&str> = got.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(paths, ["CLAUDE.md", "CLAUDE.local.md"]);
+    }
+
+    #[test]
+    fn test_imports_follow_importing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "CLAUDE.md", "# Root\n@docs/style.md\n");
+        write(
+            root,
+            "docs/style.md",
+            "# Style\n@nested.md\n@../CLAUDE.md\n",
+        );
+        write(root, "docs/nested.md", "# Nested\n");
+        write(root, "AGENTS.md", "# Agents\n");
+
+        let got = sources(root, ".");
+        assert_eq!(
+            got,
+            [
+                ("CLAUDE.md".into(), "memory".into()),
+                ("docs/style.md".into(), "imported by CLAUDE.md".into()),
+                ("docs/nested.md".into(), "imported by docs/style.md".into()),
+                ("AGENTS.md".into(), "memory".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_path_scoped_rules() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "web/app.ts", "");
+        write(root, "api/main.rs", "");
+        write(
+            root,
+            ".cursor/rules/core.mdc",
+            "---\nalwaysApply: true\n---\n# Core\n",
+        );
+        write(
+            root,
+            ".cursor/rules/ts.mdc",
+            "---\nglobs: \"**/*.ts\"\n---\n# TS\n",
+        );
+        write(
+            root,
+            ".cursor/rules/on-request.mdc",
+            "---\ndescription: Only when asked\n---\n",
+        );
+        write(
+            root,
+            ".github/instructions/rust.instructions.md",
+            "---\napplyTo: \"**/*.rs\"\n---\nUse clippy.\n",
+        );
+        write(root, ".github/copilot-instructions.md", "# Repo\n");
+
+        let got = sources(root, "web");
+        assert_eq!(
+            got,
+            [
+                (".cursor/rules/core.mdc".into(), "always applied".into()),
+                (
+                    ".github/copilot-instructions.md".into(),
+                    "always applied".into()
+                ),
+                (".cursor/rules/ts.mdc".into(), "matches **/*.ts".into()),
+            ]
+        );
+
+        let got = sources(root, "api/main.rs");
+        assert!(got
+            .iter()
+            .any(|(p, _)| p == ".github/instructions/rust.instructions.md"));
+        assert!(!got.iter().any(|(p, _)| p == ".cursor/rules/ts.mdc"));
+    }
+
+    #[test]
+    fn test_tokens_and_text() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "CLAUDE.md", "12345678\n");
+        write(root, "AGENTS.md", "1234\n");
+
+        let set = context(root, &config(), Path::new(".")).unwrap();
+        assert_eq!(set.total_tokens(), 3);
+        assert_eq!(
+            set.text(),
+            "<!-- CLAUDE.md -->\n12345678\n\n<!-- AGENTS.md -->\n1234\n"
+        );
+    }
+
+    #[test]
+    fn test_target_outside_project() {
+        let dir = tempfile::tempdir().unwrap();
+        let other = tempfile::tempdir().unwrap();
+        let err = context(dir.path(), &config(), other.path()).unwrap_err();
+        assert!(err.to_string().contains("outside the project root"));
+    }
+}
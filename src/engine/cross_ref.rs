@@ -3,24 +3,32 @@ use std::path::{Path, PathBuf};
 
 use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
 
-use crate::parser::types::ParsedFile;
+use crate::parser::types::{ParsedFile, SettingsFile};
 
 use super::scanner::matches_glob;
 
+/// Everything a [`Checker`](crate::checkers::Checker) sees in one run.
 pub struct CheckerContext {
     pub(crate) files: Vec<ParsedFile>,
+    /// Parsed `.claude/settings*.json` files. Not part of `files`: they have
+    /// no markdown structure and are only read by settings-aware checkers.
+    pub(crate) settings_files: Vec<SettingsFile>,
     pub(crate) project_root: PathBuf,
     pub(crate) canonical_root: Option<PathBuf>,
     pub(crate) filename_index: HashSet<String>,
+    /// Project-relative paths of every non-ignored file (see `ScanResult`).
+    pub(crate) path_index: Vec<PathBuf>,
     pub(crate) historical_indices: HashSet<usize>,
 }
 
 impl CheckerContext {
     pub(crate) fn build(
         files: Vec<ParsedFile>,
+        settings_files: Vec<SettingsFile>,
         project_root: &Path,
         historical_patterns: &[String],
         filename_index: HashSet<String>,
+        path_index: Vec<PathBuf>,
         canonical_root: Option<PathBuf>,
     ) -> Self {
         let historical_set = build_glob_set(historical_patterns);
@@ -32,12 +40,33 @@ impl CheckerContext {
             .collect();
         Self {
             files,
+            settings_files,
             project_root: project_root.to_path_buf(),
             canonical_root,
             filename_index,
+            path_index,
             historical_indices,
         }
     }
+
+    /// The parsed instruction files being checked, sorted by path.
+    #[must_use]
+    pub fn files(&self) -> &[ParsedFile] {
+        &self.files
+    }
+
+    /// The project root that relative references resolve against.
+    #[must_use]
+    pub fn project_root(&self) -> &Path {
+        &self.project_root
+    }
+
+    /// Whether `files()[index]` matches `historical_files`. Checkers that
+    /// validate references skip such files.
+    #[must_use]
+    pub fn is_historical(&self, index: usize) -> bool {
+        self.historical_indices.contains(&index)
+    }
 }
 
 /// Simple filename index builder for tests (walks the tree collecting basenames).
@@ -89,6 +118,7 @@ mod tests {
             suppress_comments: vec![],
             raw_lines: vec![],
             in_code_block: vec![],
+            frontmatter: None,
         }
     }
 
@@ -104,7 +134,8 @@ mod tests {
 
         let patterns = vec!["changelog*".to_string(), "retro*".to_string()];
 
-        let ctx = CheckerContext::build(files, root, &patterns, HashSet::new(), None);
+        let ctx =
+            CheckerContext::build(files, vec![], root, &patterns, HashSet::new(), vec![], None);
 
         assert!(
             !ctx.historical_indices.contains(&0),
@@ -135,7 +166,8 @@ mod tests {
 
         let patterns = vec!["docs/history.md".to_string()];
 
-        let ctx = CheckerContext::build(files, root, &patterns, HashSet::new(), None);
+        let ctx =
+            CheckerContext::build(files, vec![], root, &patterns, HashSet::new(), vec![], None);
 
         assert!(!ctx.historical_indices.contains(&0));
         assert!(
@@ -156,7 +188,7 @@ mod tests {
             make_parsed_file(root, "CLAUDE.md"),
         ];
 
-        let ctx = CheckerContext::build(files, root, &[], HashSet::new(), None);
+        let ctx = CheckerContext::build(files, vec![], root, &[], HashSet::new(), vec![], None);
 
         assert!(
             ctx.historical_indices.is_empty(),
@@ -173,7 +205,7 @@ mod tests {
             make_parsed_file(root, "sub/deep/notes.md"),
         ];
 
-        let ctx = CheckerContext::build(files, root, &[], HashSet::new(), None);
+        let ctx = CheckerContext::build(files, vec![], root, &[], HashSet::new(), vec![], None);
 
         assert_eq!(ctx.files.len(), 3);
         assert_eq!(ctx.project_root, root);
@@ -190,9 +222,11 @@ mod tests {
 
         let ctx = CheckerContext::build(
             files,
+            vec![],
             root,
             &["changelog*".to_string()],
             HashSet::new(),
+            vec![],
             None,
         );
 
@@ -211,7 +245,7 @@ mod tests {
         index.insert("utils.rs".to_string());
         index.insert("Cargo.toml".to_string());
 
-        let ctx = CheckerContext::build(files, root, &[], index, None);
+        let ctx = CheckerContext::build(files, vec![], root, &[], index, vec![], None);
 
         assert_eq!(ctx.filename_index.len(), 3);
         assert!(ctx.filename_index.contains("CLAUDE.md"));
@@ -225,7 +259,7 @@ mod tests {
         let root = Path::new("/project");
         let files = vec![make_parsed_file(root, "CLAUDE.md")];
 
-        let ctx = CheckerContext::build(files, root, &[], HashSet::new(), None);
+        let ctx = CheckerContext::build(files, vec![], root, &[], HashSet::new(), vec![], None);
 
         assert!(ctx.filename_index.is_empty());
     }
@@ -236,7 +270,15 @@ mod tests {
         let files = vec![make_parsed_file(root, "CLAUDE.md")];
         let canonical = PathBuf::from("/resolved/project");
 
-        let ctx = CheckerContext::build(files, root, &[], HashSet::new(), Some(canonical.clone()));
+        let ctx = CheckerContext::build(
+            files,
+            vec![],
+            root,
+            &[],
+            HashSet::new(),
+            vec![],
+            Some(canonical.clone()),
+        );
 
         assert_eq!(ctx.canonical_root, Some(canonical));
     }
@@ -246,7 +288,7 @@ mod tests {
         let root = Path::new("/project");
         let files = vec![make_parsed_file(root, "CLAUDE.md")];
 
-        let ctx = CheckerContext::build(files, root, &[], HashSet::new(), None);
+        let ctx = CheckerContext::build(files, vec![], root, &[], HashSet::new(), vec![], None);
 
         assert!(ctx.canonical_root.is_none());
     }
@@ -270,7 +312,8 @@ mod tests {
             "history*".to_string(),
         ];
 
-        let ctx = CheckerContext::build(files, root, &patterns, HashSet::new(), None);
+        let ctx =
+            CheckerContext::build(files, vec![], root, &patterns, HashSet::new(), vec![], None);
 
         assert!(
             !ctx.historical_indices.contains(&0),
@@ -309,7 +352,8 @@ mod tests {
 
         let patterns = vec!["docs/archive/**".to_string()];
 
-        let ctx = CheckerContext::build(files, root, &patterns, HashSet::new(), None);
+        let ctx =
+            CheckerContext::build(files, vec![], root, &patterns, HashSet::new(), vec![], None);
 
         assert!(
             ctx.historical_indices.contains(&0),
@@ -0,0 +1,360 @@
+//! Diff-aware checking: restrict findings to the lines a change touches.
+//!
+//! Changed lines come from the local `git diff` with zero context lines. A
+//! diagnostic is kept when its own line range, or any of its related
+//! locations, overlaps a changed hunk, so cross-file findings survive as long
+//! as one side of them was edited. Untracked files have no diff yet, so
+//! `--since` treats every line in them as changed.
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::types::Diagnostic;
+
+/// What the working copy is compared against.
+#[derive(Debug, Clone, Copy)]
+pub enum DiffBase<'a> {
+    /// Changes since the merge base of this ref and `HEAD`, including
+    /// uncommitted edits and untracked files.
+    Since(&'a str),
+    /// Changes staged in the index relative to `HEAD`.
+    Staged,
+}
+
+/// Added or modified line ranges per file, keyed by absolute path.
+#[derive(Debug, Default)]
+pub struct ChangedLines {
+    files: HashMap<PathBuf, Vec<(usize, usize)>>,
+}
+
+impl ChangedLines {
+    pub fn from_git(project_root: &Path, base: DiffBase<'_>) -> Result<Self> {
+        let mut args = vec![
+            "diff".to_string(),
+            "--unified=0".to_string(),
+            "--no-color".to_string(),
+            "--no-ext-diff".to_string(),
+            "--relative".to_string(),
+            // `parse` expects `b/` paths whatever `diff.noprefix` or
+            // `diff.mnemonicPrefix` say.
+            "--src-prefix=a/".to_string(),
+            "--dst-prefix=b/".to_string(),
+        ];
+        match base {
+            DiffBase::Since(rev) => {
+                let merge_base = git(project_root, &["merge-base", rev, "HEAD"])
+                    .with_context(|| format!("No merge base between '{rev}' and HEAD"))?;
+                args.push(merge_base.trim().to_string());
+            }
+            DiffBase::Staged => args.push("--cached".to_string()),
+        }
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        let diff = git(project_root, &args)?;
+        let mut changed = Self::parse(&diff, project_root);
+        if let DiffBase::Since(_) = base {
+            let untracked = git(
+                project_root,
+                &["ls-files", "--others", "--exclude-standard", "-z"],
+            )?;
+            changed.add_whole_files(
+                untracked.split('\0').filter(|n| !n.is_empty()),
+                project_root,
+            );
+        }
+        Ok(changed)
+    }
+
+    /// Mark every line of the given files, relative to `project_root`, as
+    /// changed.
+    fn add_whole_files<'a>(&mut self, names: impl Iterator<Item = &'a str>, project_root: &Path) {
+        for name in names {
+            self.files
+                .insert(project_root.join(name), vec![(1, usize::MAX)]);
+        }
+    }
+
+    /// Parse unified diff output whose paths are relative to `project_root`.
+    #[must_use]
+    pub fn parse(diff: &str, project_root: &Path) -> Self {
+        let mut files: HashMap<PathBuf, Vec<(usize, usize)>> = HashMap::new();
+        let mut current: Option<PathBuf> = None;
+        for line in diff.lines() {
+            if let Some(path) = line.strip_prefix("+++ ") {
+                current = new_file_path(path).map(|p| project_root.join(p));
+            } else if let (Some(rest), Some(path)) = (line.strip_prefix("@@ "), &current) {
+                if let Some(range) = parse_hunk_header(rest) {
+                    files.entry(path.clone()).or_default().push(range);
+                }
+            }
+        }
+        Self { files }
+    }
+
+    /// True if any line in `line..=end_line` was added or modified in `file`.
+    #[must_use]
+    pub fn touches(&self, file: &Path, line: usize, end_line: Option<usize>) -> bool {
+        let end = end_line.unwrap_or(line).max(line);
+        self.files.get(file).is_some_and(|ranges| {
+            ranges
+                .iter()
+                .any(|&(start, last)| start <= end && line <= last)
+        })
+    }
+
+    /// Keep only diagnostics that touch a changed line, directly or through
+    /// one of their related locations.
+    pub fn filter(&self, diagnostics: &mut Vec<Diagnostic>) {
+        diagnostics.retain(|d| {
+            self.touches(&d.file, d.line, d.end_line)
+                || d.related
+                    .iter()
+                    .any(|r| self.touches(&r.file, r.line, r.end_line))
+        });
+    }
+}
+
+/// Contents of staged files whose index version differs from the working
+/// tree, keyed by absolute path. Files without unstaged edits read the same
+/// from disk, so they are left out.
+pub fn staged_sources(project_root: &Path) -> Result<HashMap<PathBuf, String>> {
+    let names = git(
+        project_root,
+        &["diff", "--name-only", "--relative", "--no-renames", "-z"],
+    )?;
+    let mut sources = HashMap::new();
+    for name in names.split('\0').filter(|n| !n.is_empty()) {
+        // `:./path` resolves relative to the current directory, not the repo root.
+        let Ok(content) = git(project_root, &["show", &format!(":./{name}")]) else {
+            // Deleted from the index or never staged; nothing to read.
+            continue;
+        };
+        sources.insert(project_root.join(name), content);
+    }
+    Ok(sources)
+}
+
+/// The path on a `+++ b/path` line, or `None` for `/dev/null`. Git wraps
+/// paths with unusual characters in double quotes with C-style escapes.
+fn new_file_path(raw: &str) -> Option<String> {
+    let raw = raw.trim_end_matches('\t');
+    let path = match raw.strip_prefix('"').and_then(|r| r.strip_suffix('"')) {
+        Some(quoted) => unescape_c_style(quoted),
+        None => raw.to_string(),
+    };
+    path.strip_prefix("b/").map(String::from)
+}
+
+/// Undo git's C-style path quoting: backslash escapes and octal bytes.
+fn unescape_c_style(quoted: &str) -> String {
+    let mut bytes = Vec::with_capacity(quoted.len());
+    let mut rest = quoted.as_bytes();
+    while let Some((&b, tail)) = rest.split_first() {
+        rest = tail;
+        if b != b'\\' {
+            bytes.push(b);
+            continue;
+        }
+        let Some((&escaped, tail)) = rest.split_first() else {
+            bytes.push(b);
+            break;
+        };
+        rest = tail;
+        match escaped {
+            b'a' => bytes.push(0x07),
+            b'b' => bytes.push(0x08),
+            b'f' => bytes.push(0x0c),
+            b'n' => bytes.push(b'\n'),
+            b'r' => bytes.push(b'\r'),
+            b't' => bytes.push(b'\t'),
+            b'v' => bytes.push(0x0b),
+            b'0'..=b'7' => {
+                let digits: Vec<u8> = std::iter::once(escaped)
+                    .chain(
+                        rest.iter()
+                            .copied()
+                            .take(2)
+                            .take_while(|d| matches!(d, b'0'..=b'7')),
+                    )
+                    .collect();
+                rest = &rest[digits.len() - 1..];
+                let value = digits
+                    .iter()
+                    .fold(0u32, |acc, d| acc * 8 + u32::from(d - b'0'));
+                bytes.push(u8::try_from(value).unwrap_or(u8::MAX));
+            }
+            other => bytes.push(other),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Parse the new-file side of a hunk header (`-a,b +c,d @@`) into an
+/// inclusive line range. Pure deletions have no new lines and yield `None`.
+fn parse_hunk_header(rest: &str) -> Option<(usize, usize)> {
+    let new = rest.split_whitespace().find_map(|s| s.strip_prefix('+'))?;
+    let (start, count) = match new.split_once(',') {
+        Some((start, count)) => (start.parse().ok()?, count.parse().ok()?),
+        None => (new.parse().ok()?, 1),
+    };
+    (count > 0).then(|| (start, start + count - 1))
+}
+
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Category, RelatedLocation, Severity};
+    use std::sync::Arc;
+
+    const DIFF: &str = "\
+diff --git a/CLAUDE.md b/CLAUDE.md
+index 1111111..2222222 100644
+--- a/CLAUDE.md
++++ b/CLAUDE.md
+@@ -3,0 +4,2 @@ intro
++new line
++another
+@@ -10 +12 @@ section
+-old
++new
+@@ -20,3 +21,0 @@
+-gone
+-gone
+-gone
+diff --git a/docs/new.md b/docs/new.md
+new file mode 100644
+--- /dev/null
++++ b/docs/new.md
+@@ -0,0 +1,3 @@
++a
++b
++c
+diff --git a/old.md b/old.md
+deleted file mode 100644
+--- a/old.md
++++ /dev/null
+@@ -1,2 +0,0 @@
+-x
+-y
+";
+
+    fn make_diag(file: &str, line: usize) -> Diagnostic {
+        Diagnostic {
+            file: Arc::new(PathBuf::from(file)),
+            line,
+            column: None,
+            end_line: None,
+            end_column: None,
+            severity: Severity::Warning,
+            category: Category::NamingInconsistency,
+            message: "msg".to_string(),
+            suggestion: None,
+            fix: None,
+            related: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_hunk_header() {
+        assert_eq!(parse_hunk_header("-3,0 +4,2 @@ intro"), Some((4, 5)));
+        assert_eq!(parse_hunk_header("-10 +12 @@"), Some((12, 12)));
+        assert_eq!(parse_hunk_header("-20,3 +21,0 @@"), None);
+        assert_eq!(parse_hunk_header("garbage"), None);
+    }
+
+    #[test]
+    fn test_parse_collects_ranges_per_file() {
+        let changed = ChangedLines::parse(DIFF, Path::new("/p"));
+        assert_eq!(
+            changed.files[Path::new("/p/CLAUDE.md")],
+            vec![(4, 5), (12, 12)]
+        );
+        assert_eq!(changed.files[Path::new("/p/docs/new.md")], vec![(1, 3)]);
+        assert!(!changed.files.contains_key(Path::new("/p/old.md")));
+    }
+
+    #[test]
+    fn test_whole_files_touch_every_line() {
+        let mut changed = ChangedLines::parse(DIFF, Path::new("/p"));
+        changed.add_whole_files(["notes/draft.md"].into_iter(), Path::new("/p"));
+        let file = Path::new("/p/notes/draft.md");
+        assert!(changed.touches(file, 1, None));
+        assert!(changed.touches(file, 500, Some(510)));
+        assert!(!changed.touches(Path::new("/p/notes/other.md"), 1, None));
+    }
+
+    #[test]
+    fn test_parse_quoted_paths() {
+        let diff = "\
++++ \"b/na\\\"me.md\"
+@@ -1 +1 @@
++x
++++ \"b/caf\\303\\251.md\"
+@@ -0,0 +1,2 @@
++y
+";
+        let changed = ChangedLines::parse(diff, Path::new("/p"));
+        assert_eq!(changed.files[Path::new("/p/na\"me.md")], vec![(1, 1)]);
+        assert_eq!(changed.files[Path::new("/p/café.md")], vec![(1, 2)]);
+    }
+
+    #[test]
+    fn test_touches_overlapping_ranges() {
+        let changed = ChangedLines::parse(DIFF, Path::new("/p"));
+        let file = Path::new("/p/CLAUDE.md");
+        assert!(changed.touches(file, 4, None));
+        assert!(changed.touches(file, 1, Some(4)));
+        assert!(changed.touches(file, 12, Some(30)));
+        assert!(!changed.touches(file, 6, Some(11)));
+        assert!(!changed.touches(file, 21, None));
+        assert!(!changed.touches(Path::new("/p/other.md"), 4, None));
+    }
+
+    #[test]
+    fn test_filter_keeps_findings_with_changed_related_location() {
+        let changed = ChangedLines::parse(DIFF, Path::new("/p"));
+        let mut linked = make_diag("/p/AGENTS.md", 7);
+        linked.related = vec![RelatedLocation {
+            file: Arc::new(PathBuf::from("/p/docs/new.md")),
+            line: 2,
+            end_line: None,
+        }];
+        let mut diagnostics = vec![
+            make_diag("/p/CLAUDE.md", 5),
+            make_diag("/p/CLAUDE.md", 8),
+            linked,
+        ];
+
+        changed.filter(&mut diagnostics);
+
+        let kept: Vec<_> = diagnostics
+            .iter()
+            .map(|d| (d.file.display().to_string(), d.line))
+            .collect();
+        assert_eq!(
+            kept,
+            vec![
+                ("/p/CLAUDE.md".to_string(), 5),
+                ("/p/AGENTS.md".to_string(), 7)
+            ]
+        );
+    }
+}
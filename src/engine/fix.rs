@@ -155,6 +155,7 @@ mod tests {
             message: "test".to_string(),
             suggestion: None,
             fix: Some(Box::new(fix)),
+            related: Vec::new(),
         }
     }
 
@@ -196,6 +197,7 @@ mod tests {
             message: "test".to_string(),
             suggestion: None,
             fix: None,
+            related: Vec::new(),
         };
 
         let fixed = apply_fixes(&[diag]);
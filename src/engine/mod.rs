@@ -1,64 +1,210 @@
+mod baseline;
 mod cache;
+mod context;
 pub(crate) mod cross_ref;
+mod diff;
 pub(crate) mod fix;
 
+pub use baseline::{Baseline, BaselineEntry};
+pub use context::{context, ContextReason, ContextSet, ContextSource};
+pub use cross_ref::CheckerContext;
+pub use diff::{staged_sources, ChangedLines, DiffBase};
 /// Re-export `apply_fixes` so the binary crate can use `engine::apply_fixes`.
 pub use fix::apply_fixes;
 pub(crate) mod scanner;
 mod suppress;
+mod timings;
+
+pub use timings::Timings;
 
 use anyhow::Result;
 use rayon::prelude::*;
-use std::path::Path;
-
-use crate::checkers;
-use crate::config::Config;
-use crate::types::CheckResult;
-use cross_ref::CheckerContext;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::checkers::{self, Checker};
+use crate::config::{Config, FileConfigs, CONFIG_FILE};
+use crate::parser::types::ParsedFile;
+use crate::parser::ParseMode;
+use crate::types::{Category, CheckResult, Diagnostic};
+use scanner::ScanResult;
 
 /// Return the list of markdown files that would be scanned for the given project root and config.
 pub fn scanned_files(project_root: &Path, config: &Config) -> Vec<std::path::PathBuf> {
     scanner::scan(project_root, config).files
 }
 
+/// Return the `.spectralintrc.toml` files in subdirectories of the project.
+pub fn nested_config_files(project_root: &Path, config: &Config) -> Vec<std::path::PathBuf> {
+    scanner::scan(project_root, config).config_files
+}
+
+/// Nearest ancestor of `start` (itself included) with a `.git` entry. Outside
+/// a repository, the outermost ancestor holding a config file, since configs
+/// below it are directory configs layered over it; `start` itself if there is
+/// neither.
+pub fn find_project_root(start: &Path) -> PathBuf {
+    if let Some(repo) = start.ancestors().find(|dir| dir.join(".git").exists()) {
+        return repo.to_path_buf();
+    }
+    start
+        .ancestors()
+        .filter(|dir| dir.join(CONFIG_FILE).is_file())
+        .last()
+        .unwrap_or(start)
+        .to_path_buf()
+}
+
+/// What [`run_with_inputs`] checks beyond the files on disk.
+#[derive(Debug, Clone, Default)]
+pub struct Inputs {
+    /// Content to check instead of what is on disk, keyed by absolute path
+    /// (e.g. staged versions or an editor buffer).
+    pub sources: HashMap<PathBuf, String>,
+    /// Absolute files and directories to report on; the whole project when
+    /// empty. Files named here are checked even if `include` does not match
+    /// them, and need not exist on disk if they have an entry in `sources`.
+    pub paths: Vec<PathBuf>,
+}
+
 pub fn run(
     project_root: &Path,
     config: &Config,
     use_cache: bool,
     config_path: Option<&Path>,
 ) -> Result<CheckResult> {
-    let scan_result = scanner::scan(project_root, config);
-    if scan_result.files.is_empty() {
+    run_with_inputs(
+        project_root,
+        config,
+        use_cache,
+        config_path,
+        &Inputs::default(),
+    )
+}
+
+/// Like [`run`], but with substituted file contents and explicit target
+/// paths. The whole project is still scanned so that cross-file and
+/// path-relative checks see the real tree.
+pub fn run_with_inputs(
+    project_root: &Path,
+    config: &Config,
+    use_cache: bool,
+    config_path: Option<&Path>,
+    inputs: &Inputs,
+) -> Result<CheckResult> {
+    run_with_timings(project_root, config, use_cache, config_path, inputs).map(|(result, _)| result)
+}
+
+/// Like [`run_with_inputs`], also reporting how long each phase took.
+pub fn run_with_timings(
+    project_root: &Path,
+    config: &Config,
+    use_cache: bool,
+    config_path: Option<&Path>,
+    inputs: &Inputs,
+) -> Result<(CheckResult, Timings)> {
+    let mut timings = Timings::default();
+    let start = Instant::now();
+    let mut scan_result = scanner::scan(project_root, config);
+    for path in &inputs.paths {
+        if inputs.sources.contains_key(path) || path.is_file() {
+            scan_result.add_file(path, project_root);
+        }
+    }
+    timings.scan = start.elapsed();
+    let result = check(
+        project_root,
+        config,
+        use_cache,
+        config_path,
+        inputs,
+        scan_result,
+        &[],
+        &mut timings,
+    )?;
+    Ok((result, timings))
+}
+
+/// Check the files in `scan_result`. `extra_checkers` and the config's
+/// plugins run once over the whole project after the built-in ones; their
+/// results are never cached.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn check(
+    project_root: &Path,
+    config: &Config,
+    use_cache: bool,
+    config_path: Option<&Path>,
+    inputs: &Inputs,
+    scan_result: ScanResult,
+    extra_checkers: &[Box<dyn Checker>],
+    timings: &mut Timings,
+) -> Result<CheckResult> {
+    let start = Instant::now();
+    let sources = &inputs.sources;
+    if scan_result.files.is_empty() && scan_result.settings_files.is_empty() {
         anyhow::bail!("No markdown files found in {}", project_root.display());
     }
 
-    // Compute cache keys and try to load from cache
-    let (files_hash, config_hash) = if use_cache {
-        let fh = cache::compute_files_hash(&scan_result.files);
-        let ch = cache::compute_config_hash(config_path, project_root);
-        if let Some(diagnostics) = cache::load(project_root, fh, ch) {
-            return Ok(CheckResult { diagnostics });
-        }
-        (fh, ch)
+    let config_paths: Vec<&Path> = scan_result
+        .files
+        .iter()
+        .chain(&scan_result.settings_files)
+        .map(PathBuf::as_path)
+        .collect();
+    let configs = FileConfigs::load(
+        config,
+        project_root,
+        &scan_result.config_files,
+        &config_paths,
+    )?;
+
+    let config_hash = if use_cache {
+        cache::compute_config_hash(config_path, project_root, &configs.sources())
     } else {
-        (0, 0)
+        0
+    };
+    let cached = if use_cache {
+        cache::load(project_root, config_hash)
+    } else {
+        cache::Cache::default()
     };
 
     let total_files = scan_result.files.len();
-    let parsed: Vec<_> = scan_result
+    let plain_text_set = cross_ref::build_glob_set(&config.plain_text);
+    let config_plain_text = |p: &Path| scanner::matches_glob(p, project_root, &plain_text_set);
+    // Unchanged files reuse their cached parse and single-file diagnostics.
+    let loaded: Vec<(ParsedFile, u64, Option<Vec<Diagnostic>>)> = scan_result
         .files
         .par_iter()
-        .filter_map(|p| match crate::parser::parse_file(p) {
-            Ok(f) => Some(f),
-            Err(e) => {
-                tracing::warn!("Failed to parse {}: {e}", p.display());
-                None
+        .filter_map(|p| {
+            let source = match sources.get(p) {
+                Some(content) => Ok(content.clone()),
+                None => crate::parser::read_source(p),
+            };
+            let content = match source {
+                Ok(content) => content,
+                Err(e) => {
+                    tracing::warn!("Failed to parse {}: {e}", p.display());
+                    return None;
+                }
+            };
+            let hash = cache::content_hash(&content);
+            if let Some((parsed, diagnostics)) = cached.file(p, hash) {
+                return Some((parsed.clone(), hash, Some(diagnostics.to_vec())));
             }
+            let mode = if config_plain_text(p) {
+                ParseMode::PlainText
+            } else {
+                ParseMode::for_path(p)
+            };
+            Some((crate::parser::parse_source(p, &content, mode), hash, None))
         })
         .collect();
 
-    let parse_failures = total_files - parsed.len();
-    if parsed.is_empty() {
+    let parse_failures = total_files - loaded.len();
+    if loaded.is_empty() && total_files > 0 {
         anyhow::bail!(
             "All {} markdown file(s) failed to parse in {}",
             total_files,
@@ -68,32 +214,229 @@ pub fn run(
     if parse_failures > 0 {
         tracing::warn!(
             "Checked {}/{} files ({} failed to parse)",
-            parsed.len(),
+            loaded.len(),
             total_files,
             parse_failures
         );
     }
 
+    let settings: Vec<_> = scan_result
+        .settings_files
+        .iter()
+        .filter_map(|p| match crate::parser::parse_settings_file(p) {
+            Ok(f) => Some(f),
+            Err(e) => {
+                tracing::warn!("Failed to read {}: {e}", p.display());
+                None
+            }
+        })
+        .collect();
+    timings.parse = start.elapsed();
+
+    // One checker set per distinct file config (directory configs and
+    // overrides); each only reports on the files that config owns.
+    let (project_checkers, file_checkers): (Vec<_>, Vec<_>) = (0..configs.len())
+        .map(|i| {
+            checkers::all_checkers(configs.get(i))
+                .into_iter()
+                .partition::<Vec<_>, _>(|c| c.is_project_wide())
+        })
+        .unzip();
+
+    let mut content_hashes: HashMap<Arc<PathBuf>, u64> = HashMap::with_capacity(loaded.len());
+    let mut file_diagnostics: HashMap<Arc<PathBuf>, Vec<Diagnostic>> = HashMap::new();
+    let mut parsed = Vec::with_capacity(loaded.len());
+    let mut changed: Vec<Vec<ParsedFile>> = (0..configs.len()).map(|_| Vec::new()).collect();
+    for (file, hash, diagnostics) in loaded {
+        content_hashes.insert(Arc::clone(&file.path), hash);
+        match diagnostics {
+            Some(diagnostics) => {
+                file_diagnostics.insert(Arc::clone(&file.path), diagnostics);
+                parsed.push(file);
+            }
+            None => {
+                file_diagnostics.insert(Arc::clone(&file.path), Vec::new());
+                changed[configs.owner(&file.path)].push(file);
+            }
+        }
+    }
+
+    // Single-file checkers only need to see the files that changed.
+    let start = Instant::now();
+    for (i, changed) in changed.into_iter().enumerate() {
+        if changed.is_empty() {
+            continue;
+        }
+        let changed_context = CheckerContext::build(
+            changed,
+            Vec::new(),
+            project_root,
+            &configs.get(i).historical_files,
+            HashSet::new(),
+            Vec::new(),
+            scan_result.canonical_root.clone(),
+        );
+        let checkers = labeled(&file_checkers[i]);
+        for d in run_checkers(&checkers, &changed_context, timings) {
+            file_diagnostics
+                .entry(Arc::clone(&d.file))
+                .or_default()
+                .push(d);
+        }
+        parsed.extend(changed_context.files);
+    }
+    parsed.sort_by(|a, b| a.path.cmp(&b.path));
+    timings.checks = start.elapsed();
+
+    let hashed_files: Vec<(&Path, u64)> = parsed
+        .iter()
+        .map(|f| (f.path.as_path(), content_hashes[&f.path]))
+        .collect();
+    let project_hashes = cache::ProjectHashes::new(
+        &hashed_files,
+        &scan_result.settings_files,
+        &scan_result.path_index,
+    );
+
     // Validate suppress comment rule names
-    let known_rules = suppress::all_known_rule_names(&config.checkers.custom_patterns);
-    let mut invalid_suppress_diags = suppress::validate_suppress_rules(&parsed, &known_rules);
+    let custom_patterns: Vec<_> = (0..configs.len())
+        .flat_map(|i| configs.get(i).checkers.custom_patterns.iter().cloned())
+        .collect();
+    let mut known_rules = suppress::all_known_rule_names(&custom_patterns);
+    known_rules.extend(
+        extra_checkers
+            .iter()
+            .map(|c| Category::CustomPattern(c.meta().name.into()).to_string()),
+    );
+    known_rules.extend(
+        config
+            .plugins
+            .iter()
+            .map(|p| Category::CustomPattern(p.name.as_str().into()).to_string()),
+    );
+
+    let plain_text_files: HashSet<Arc<PathBuf>> = parsed
+        .iter()
+        .filter(|f| {
+            config_plain_text(&f.path) || ParseMode::for_path(&f.path) == ParseMode::PlainText
+        })
+        .map(|f| Arc::clone(&f.path))
+        .collect();
 
     let suppressions = suppress::build_suppression_set(&parsed);
     let context = CheckerContext::build(
         parsed,
+        settings,
         project_root,
         &config.historical_files,
         scan_result.filename_index,
+        scan_result.path_index,
         scan_result.canonical_root,
     );
 
-    let all = checkers::all_checkers(config);
-    let mut diagnostics: Vec<_> = all
-        .par_iter()
-        .flat_map(|checker| checker.check(&context).diagnostics)
+    // Each project-wide checker re-runs only when something it reads changed.
+    let start = Instant::now();
+    let mut project_results: BTreeMap<String, cache::ProjectEntry> = BTreeMap::new();
+    let mut stale: HashMap<&str, u64> = HashMap::new();
+    for checker in project_checkers.iter().flatten() {
+        let name = checker.meta().name;
+        if project_results.contains_key(name) || stale.contains_key(name) {
+            continue;
+        }
+        let inputs_hash = project_hashes.of(checker.project_inputs());
+        match cached.project(name, inputs_hash) {
+            Some(entry) => {
+                project_results.insert(name.to_string(), entry.clone());
+            }
+            None => {
+                stale.insert(name, inputs_hash);
+            }
+        }
+    }
+    for (owners, checkers) in project_runs(&project_checkers, &configs) {
+        let checkers: Labeled<'_> = checkers
+            .into_iter()
+            .filter(|(name, _)| stale.contains_key(name.as_str()))
+            .collect();
+        for ((name, checker), found) in checkers.iter().zip(run_each(&checkers, &context, timings))
+        {
+            let entry =
+                project_results
+                    .entry(name.clone())
+                    .or_insert_with(|| cache::ProjectEntry {
+                        inputs_hash: stale[name.as_str()],
+                        ..Default::default()
+                    });
+            entry.diagnostics.extend(
+                found
+                    .into_iter()
+                    .filter(|d| owners.contains(&configs.owner(&d.file))),
+            );
+            entry
+                .external
+                .extend(cache::hash_external_inputs(checker.external_inputs()));
+        }
+    }
+    let project_diagnostics: Vec<Diagnostic> = project_results
+        .values()
+        .flat_map(|entry| entry.diagnostics.iter().cloned())
+        .collect();
+    timings.checks += start.elapsed();
+
+    // Cache raw checker output; suppressions and overrides are re-applied on load.
+    if use_cache {
+        let entries: Vec<_> = context
+            .files
+            .iter()
+            .map(|file| cache::FileEntry {
+                parsed: file,
+                content_hash: content_hashes[&file.path],
+                diagnostics: file_diagnostics
+                    .get(&file.path)
+                    .map_or(&[][..], Vec::as_slice),
+            })
+            .collect();
+        cache::save(project_root, config_hash, &entries, &project_results);
+    }
+
+    let start = Instant::now();
+    let plugins = checkers::plugin_checkers(config, project_root);
+    let extra: Vec<_> = extra_checkers
+        .iter()
+        .map(|c| (format!("custom:{}", c.meta().name), c.as_ref()))
+        .chain(
+            config
+                .plugins
+                .iter()
+                .zip(&plugins)
+                .map(|(p, c)| (format!("plugin:{}", p.name), c.as_ref())),
+        )
+        .collect();
+    let extra_diagnostics = run_checkers(&extra, &context, timings);
+    timings.checks += start.elapsed();
+    timings.sort_checkers();
+
+    let start = Instant::now();
+
+    // Plugins choose their own rule names, so whatever they report is known.
+    known_rules.extend(
+        extra_diagnostics
+            .iter()
+            .filter(|d| matches!(d.category, Category::CustomPattern(_)))
+            .map(|d| d.category.to_string()),
+    );
+    let mut invalid_suppress_diags =
+        suppress::validate_suppress_rules(&context.files, &known_rules);
+
+    let mut diagnostics: Vec<_> = file_diagnostics
+        .into_values()
+        .flatten()
+        .chain(project_diagnostics)
+        .chain(extra_diagnostics)
         .collect();
 
     diagnostics.retain(|d| !suppress::is_suppressed(&suppressions, &d.file, d.line, &d.category));
+    diagnostics.retain(|d| !(d.category.is_structural() && plain_text_files.contains(&d.file)));
 
     // Detect unused suppressions (must run after suppression filtering)
     let mut unused_suppress_diags = suppress::find_unused_suppressions(&suppressions);
@@ -101,9 +444,17 @@ pub fn run(
     diagnostics.append(&mut invalid_suppress_diags);
     diagnostics.append(&mut unused_suppress_diags);
 
+    // Everything was checked for context; report only on the requested paths.
+    if !inputs.paths.is_empty() {
+        diagnostics.retain(|d| inputs.paths.iter().any(|p| d.file.starts_with(p)));
+    }
+
     // Apply per-checker severity overrides
     for d in &mut diagnostics {
-        if let Some(sev) = config.severity_override(&d.category) {
+        if let Some(sev) = configs
+            .get(configs.owner(&d.file))
+            .severity_override(&d.category)
+        {
             d.severity = sev;
         }
     }
@@ -124,13 +475,89 @@ pub fn run(
             && a.category == b.category
             && a.message == b.message
     });
+    timings.suppression = start.elapsed();
 
-    // Save to cache
-    if use_cache {
-        cache::save(project_root, files_hash, config_hash, &diagnostics);
+    Ok(CheckResult { diagnostics })
+}
+
+/// Checkers paired with the labels their timings are recorded under.
+type Labeled<'a> = Vec<(String, &'a dyn Checker)>;
+
+/// Built-in checkers paired with their rule names.
+fn labeled(checkers: &[Box<dyn Checker>]) -> Labeled<'_> {
+    checkers
+        .iter()
+        .map(|c| (c.meta().name.to_string(), c.as_ref()))
+        .collect()
+}
+
+/// Group project-wide checkers into runs over the whole project. Configs that
+/// resolve a checker to the same settings share one run of it, whose findings
+/// go to the files any of those configs own; each run lists those configs.
+fn project_runs<'a>(
+    project_checkers: &'a [Vec<Box<dyn Checker>>],
+    configs: &FileConfigs,
+) -> Vec<(Vec<usize>, Labeled<'a>)> {
+    let mut index: HashMap<(&'static str, String), usize> = HashMap::new();
+    let mut shared: Vec<(&'a dyn Checker, Vec<usize>)> = Vec::new();
+    for (i, checkers) in project_checkers.iter().enumerate() {
+        for checker in checkers {
+            let name = checker.meta().name;
+            let key = (name, configs.get(i).checker_settings(name));
+            match index.get(&key) {
+                Some(&at) => shared[at].1.push(i),
+                None => {
+                    index.insert(key, shared.len());
+                    shared.push((checker.as_ref(), vec![i]));
+                }
+            }
+        }
+    }
+    let mut runs: Vec<(Vec<usize>, Labeled<'a>)> = Vec::new();
+    for (checker, owners) in shared {
+        let entry = (checker.meta().name.to_string(), checker);
+        match runs.iter_mut().find(|(o, _)| *o == owners) {
+            Some((_, checkers)) => checkers.push(entry),
+            None => runs.push((owners, vec![entry])),
+        }
     }
+    runs
+}
 
-    Ok(CheckResult { diagnostics })
+/// Run `checkers` in parallel, recording each one's time under its label.
+fn run_checkers(
+    checkers: &[(String, &dyn Checker)],
+    context: &CheckerContext,
+    timings: &mut Timings,
+) -> Vec<Diagnostic> {
+    run_each(checkers, context, timings)
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Like [`run_checkers`], but keep each checker's findings apart, in order.
+fn run_each(
+    checkers: &[(String, &dyn Checker)],
+    context: &CheckerContext,
+    timings: &mut Timings,
+) -> Vec<Vec<Diagnostic>> {
+    let results: Vec<_> = checkers
+        .par_iter()
+        .map(|(_, checker)| {
+            let start = Instant::now();
+            let diagnostics = checker.check(context).diagnostics;
+            (start.elapsed(), diagnostics)
+        })
+        .collect();
+    checkers
+        .iter()
+        .zip(results)
+        .map(|((label, _), (elapsed, found))| {
+            timings.add_checker(label.clone(), elapsed);
+            found
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -159,6 +586,7 @@ mod tests {
             message: message.to_string(),
             suggestion: None,
             fix: None,
+            related: Vec::new(),
         }
     }
 
@@ -530,6 +958,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn run_on_settings_only_project_checks_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join(".claude")).unwrap();
+        std::fs::write(
+            dir.path().join(".claude/settings.json"),
+            "{\"permissions\": {\"allow\": [\"Bash(npm run test\"]}}",
+        )
+        .unwrap();
+
+        let result = run(dir.path(), &Config::default(), false, None).unwrap();
+        assert!(
+            result
+                .diagnostics
+                .iter()
+                .any(|d| d.category == Category::ClaudeSettings),
+            "{:?}",
+            result.diagnostics
+        );
+    }
+
+    #[test]
+    fn project_runs_share_checkers_with_equal_settings() {
+        let config = Config::parse(
+            r#"
+            [[overrides]]
+            files = ["docs/**"]
+            checkers.file_size.warn_lines = 100
+            checkers.dead_reference.scope = ["docs/**"]
+            checkers.naming_inconsistency.severity = "error"
+            "#,
+        )
+        .unwrap();
+        let root = Path::new("/p");
+        let docs = root.join("docs/AGENTS.md");
+        let configs = FileConfigs::load(&config, root, &[], &[docs.as_path()]).unwrap();
+        let project_checkers: Vec<Vec<Box<dyn Checker>>> = (0..configs.len())
+            .map(|i| {
+                checkers::all_checkers(configs.get(i))
+                    .into_iter()
+                    .filter(|c| c.is_project_wide())
+                    .collect()
+            })
+            .collect();
+
+        let runs = project_runs(&project_checkers, &configs);
+        let names = |owners: &[usize]| -> Vec<String> {
+            runs.iter()
+                .filter(|(o, _)| o == owners)
+                .flat_map(|(_, checkers)| checkers.iter().map(|(name, _)| name.clone()))
+                .collect()
+        };
+        assert_eq!(runs.len(), 3);
+        assert_eq!(names(&[0]), vec!["dead-reference"]);
+        assert_eq!(names(&[1]), vec!["dead-reference"]);
+        assert_eq!(names(&[0, 1]).len(), project_checkers[0].len() - 1);
+    }
+
+    #[test]
+    fn project_checkers_rerun_only_when_their_inputs_change() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::write(
+            root.join("CLAUDE.md"),
+            "# Project\n\nRead `docs/guide.md`.\n",
+        )
+        .unwrap();
+        let ran = || -> Vec<String> {
+            let (_, timings) =
+                run_with_timings(root, &Config::default(), true, None, &Inputs::default()).unwrap();
+            timings.checkers.into_iter().map(|(name, _)| name).collect()
+        };
+
+        let first = ran();
+        assert!(first.iter().any(|n| n == "dead-reference"));
+        assert!(first.iter().any(|n| n == "naming-inconsistency"));
+        assert!(ran().is_empty());
+
+        // A new file changes the listing, which only reference checks read.
+        std::fs::write(root.join("build.log"), "").unwrap();
+        let after_new_file = ran();
+        assert!(after_new_file.iter().any(|n| n == "dead-reference"));
+        assert!(!after_new_file.iter().any(|n| n == "naming-inconsistency"));
+    }
+
     #[test]
     fn scanned_files_empty_for_no_md_files() {
         let dir = tempfile::tempdir().unwrap();
@@ -784,6 +1297,143 @@ mod tests {
         );
     }
 
+    #[test]
+    fn run_skips_structural_checks_for_plain_text_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let content = "# Rules\n\n#### Deep heading\n\nSee [missing](./does-not-exist.md).\n";
+        std::fs::write(dir.path().join(".windsurfrules"), content).unwrap();
+        std::fs::write(dir.path().join("rules.md"), content).unwrap();
+
+        let mut config = Config {
+            include: vec![".windsurfrules".into(), "rules.md".into()],
+            plain_text: vec!["rules.md".into()],
+            ..Config::default()
+        };
+        config.checkers.heading_hierarchy.enabled = true;
+        let result = run(dir.path(), &config, false, None).unwrap();
+        assert!(
+            !result
+                .diagnostics
+                .iter()
+                .any(|d| d.category.is_structural()),
+            "structural diagnostics on plain-text files: {:?}",
+            result.diagnostics
+        );
+        let dead_refs = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.category == Category::DeadReference)
+            .count();
+        assert_eq!(dead_refs, 2, "content checks should still run");
+
+        config.plain_text.clear();
+        let result = run(dir.path(), &config, false, None).unwrap();
+        assert!(result
+            .diagnostics
+            .iter()
+            .any(|d| d.category == Category::HeadingHierarchy && d.file.ends_with("rules.md")));
+    }
+
+    #[test]
+    fn run_applies_nested_config_to_its_directory_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let content = "# Rules\n\nSee [missing](./does-not-exist.md).\n";
+        std::fs::create_dir_all(dir.path().join("pkg")).unwrap();
+        std::fs::write(dir.path().join("CLAUDE.md"), content).unwrap();
+        std::fs::write(dir.path().join("pkg/CLAUDE.md"), content).unwrap();
+        std::fs::write(
+            dir.path().join(".spectralintrc.toml"),
+            "include = [\"**/CLAUDE.md\"]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("pkg/.spectralintrc.toml"),
+            "[checkers.dead_reference]\nseverity = \"info\"\n",
+        )
+        .unwrap();
+
+        let config = Config::load(None, dir.path()).unwrap();
+        let result = run(dir.path(), &config, false, None).unwrap();
+        let dead_refs: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.category == Category::DeadReference)
+            .map(|d| (d.file.starts_with(dir.path().join("pkg")), d.severity))
+            .collect();
+        assert_eq!(
+            dead_refs,
+            vec![(false, Severity::Error), (true, Severity::Info)]
+        );
+    }
+
+    #[test]
+    fn run_applies_overrides_per_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let content = "# Deploy\n\n```\ngit push --force\n```\n";
+        std::fs::create_dir_all(dir.path().join(".claude/commands")).unwrap();
+        std::fs::write(dir.path().join("CLAUDE.md"), content).unwrap();
+        std::fs::write(dir.path().join(".claude/commands/deploy.md"), content).unwrap();
+
+        let config = Config::parse(
+            "[[overrides]]\nfiles = [\".claude/commands/**\"]\ncheckers.dangerous_command.severity = \"error\"\n",
+        )
+        .unwrap();
+        let result = run(dir.path(), &config, false, None).unwrap();
+        let severities: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.category == Category::DangerousCommand)
+            .map(|d| (d.file.ends_with("CLAUDE.md"), d.severity))
+            .collect();
+        assert_eq!(
+            severities,
+            vec![(false, Severity::Error), (true, Severity::Warning)]
+        );
+    }
+
+    #[test]
+    fn run_with_inputs_checks_unsaved_file_against_real_tree() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+        std::fs::write(root.join("docs/guide.md"), "# Guide\n").unwrap();
+        std::fs::write(root.join("CLAUDE.md"), "See [gone](./gone.md).\n").unwrap();
+
+        // Not on disk and not matched by `include`.
+        let buffer = root.join("docs/draft.md");
+        let inputs = Inputs {
+            sources: HashMap::from([(
+                buffer.clone(),
+                "See [guide](./guide.md) and [old](./old.md).\n".to_string(),
+            )]),
+            paths: vec![buffer.clone()],
+        };
+        let result = run_with_inputs(&root, &Config::default(), false, None, &inputs).unwrap();
+        let dead_refs: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.category == Category::DeadReference)
+            .map(|d| (d.file.as_path(), d.message.contains("old.md")))
+            .collect();
+        assert_eq!(dead_refs, vec![(buffer.as_path(), true)]);
+    }
+
+    #[test]
+    fn find_project_root_prefers_git_then_outermost_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("a/b");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(dir.path().join("a/.spectralintrc.toml"), "").unwrap();
+        std::fs::write(nested.join(".spectralintrc.toml"), "").unwrap();
+        assert_eq!(find_project_root(&nested), dir.path().join("a"));
+
+        std::fs::create_dir(dir.path().join(".git")).unwrap();
+        assert_eq!(find_project_root(&nested), dir.path());
+
+        std::fs::create_dir(nested.join(".git")).unwrap();
+        assert_eq!(find_project_root(&nested), nested);
+    }
+
     #[test]
     fn run_without_cache_does_not_create_cache_file() {
         let dir = tempfile::tempdir().unwrap();
@@ -813,4 +1463,71 @@ mod tests {
             "Cache file should be created when use_cache is true"
         );
     }
+
+    #[test]
+    fn run_with_cache_matches_uncached_after_edits() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::write(
+            root.join("CLAUDE.md"),
+            "# Rules\n\nTry to keep it short.\nSee [guide](./guide.md).\n",
+        )
+        .unwrap();
+        std::fs::write(root.join("AGENTS.md"), "# Agents\n\nRun the tests.\n").unwrap();
+        let config = Config::default();
+        let messages = |result: CheckResult| -> Vec<String> {
+            result
+                .diagnostics
+                .into_iter()
+                .map(|d| format!("{}:{} {}", d.file.display(), d.line, d.message))
+                .collect()
+        };
+
+        let cold = messages(run(root, &config, true, None).unwrap());
+        let warm = messages(run(root, &config, true, None).unwrap());
+        assert_eq!(cold, warm);
+
+        // Editing one file and creating a referenced one changes both
+        // single-file and project-wide results.
+        std::fs::write(
+            root.join("AGENTS.md"),
+            "# Agents\n\nTry to run the tests.\n",
+        )
+        .unwrap();
+        std::fs::write(root.join("guide.md"), "# Guide\n").unwrap();
+        let cached = messages(run(root, &config, true, None).unwrap());
+        let fresh = messages(run(root, &config, false, None).unwrap());
+        assert_eq!(cached, fresh);
+        assert_ne!(cached, cold);
+    }
+
+    #[test]
+    fn run_with_cache_reuses_results_for_unchanged_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::write(root.join("CLAUDE.md"), "# Rules\n\nTry to run `make`.\n").unwrap();
+        std::fs::write(root.join("AGENTS.md"), "# Agents\n\nTry to run `just`.\n").unwrap();
+        let mut config = Config::default();
+        config.checkers.vague_directive.enabled = true;
+        run(root, &config, true, None).unwrap();
+
+        // Rewrite every cached message, so reused results are recognisable.
+        let cache_path = root.join(".spectralint-cache.json");
+        let json = std::fs::read_to_string(&cache_path).unwrap();
+        let json = json.replace("\"message\":\"", "\"message\":\"CACHED ");
+        std::fs::write(&cache_path, json).unwrap();
+
+        std::fs::write(root.join("AGENTS.md"), "# Agents\n\nTry to run `cargo`.\n").unwrap();
+        let result = run(root, &config, true, None).unwrap();
+        let vague: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.category == Category::VagueDirective)
+            .collect();
+        assert_eq!(vague.len(), 2, "{vague:?}");
+        for d in vague {
+            let reused = d.message.starts_with("CACHED ");
+            assert_eq!(reused, d.file.ends_with("CLAUDE.md"), "{d:?}");
+        }
+    }
 }
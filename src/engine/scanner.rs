@@ -1,46 +1,201 @@
 use globset::GlobSet;
+use ignore::{DirEntry, WalkBuilder, WalkState};
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
 
 use super::cross_ref::build_glob_set;
 use crate::checkers::utils::SKIP_DIRS;
-use crate::config::Config;
+use crate::config::{Config, CONFIG_FILE};
+use crate::parser::ParseMode;
 
 const MAX_WALK_DEPTH: usize = 256;
 
-/// Result of scanning a project tree: matched `.md` files plus a filename index.
+/// File extensions parsed as instruction files: markdown and Cursor `.mdc` rules.
+/// Legacy plain-text rule files and `plain_text` globs are matched separately.
+const INSTRUCTION_EXTENSIONS: &[&str] = &["md", "mdc"];
+
+/// Claude Code settings files, recognised by name inside a `.claude` directory.
+const SETTINGS_FILE_NAMES: &[&str] = &["settings.json", "settings.local.json"];
+
+/// Personal files that are usually gitignored but still loaded by agents, so
+/// they are scanned in every walked directory whatever `.gitignore` says.
+const LOCAL_FILES: &[&str] = &["CLAUDE.local.md", ".claude/settings.local.json"];
+
+/// Result of scanning a project tree: matched instruction files plus filename
+/// and path indices of every non-ignored file.
+#[derive(Default)]
 pub(crate) struct ScanResult {
     pub files: Vec<PathBuf>,
+    /// Matched `.claude/settings*.json` files, sorted.
+    pub settings_files: Vec<PathBuf>,
     pub filename_index: HashSet<String>,
+    /// Project-relative paths of every non-ignored file, sorted. Used to check
+    /// whether rule globs (e.g. Cursor `globs`) match anything.
+    pub path_index: Vec<PathBuf>,
     pub canonical_root: Option<PathBuf>,
+    /// `.spectralintrc.toml` files in subdirectories, sorted.
+    pub config_files: Vec<PathBuf>,
+}
+
+impl ScanResult {
+    /// Check `path` even if the walk skipped it or it does not exist on disk.
+    pub fn add_file(&mut self, path: &Path, root: &Path) {
+        if let Err(i) = self.files.binary_search_by(|f| f.as_path().cmp(path)) {
+            self.files.insert(i, path.to_path_buf());
+        }
+        if let Some(name) = path.file_name() {
+            self.filename_index
+                .insert(name.to_string_lossy().into_owned());
+        }
+        if let Ok(rel) = path.strip_prefix(root) {
+            if let Err(i) = self.path_index.binary_search_by(|p| p.as_path().cmp(rel)) {
+                self.path_index.insert(i, rel.to_path_buf());
+            }
+        }
+    }
 }
 
 /// Immutable configuration for a single walk pass.
 struct WalkConfig {
     root: PathBuf,
     canonical_root: Option<PathBuf>,
-    ignore: GlobSet,
     ignore_files: GlobSet,
     include: GlobSet,
+    plain_text: GlobSet,
 }
 
 pub(crate) fn scan(root: &Path, config: &Config) -> ScanResult {
     let canonical_root = root.canonicalize().ok();
+    let ignore = build_glob_set(&config.ignore);
     let walk = WalkConfig {
         root: root.to_path_buf(),
         canonical_root: canonical_root.clone(),
-        ignore: build_glob_set(&config.ignore),
         ignore_files: build_glob_set(&config.ignore_files),
         include: build_glob_set(&config.include),
+        plain_text: build_glob_set(&config.plain_text),
     };
-    let mut files = Vec::new();
-    let mut filename_index = HashSet::new();
-    walk_dir(root, &walk, &mut files, &mut filename_index, 0);
-    files.sort();
+
+    let prune_root = root.to_path_buf();
+    let prune = ignore.clone();
+    let shared = Mutex::new(WalkOutput::default());
+    WalkBuilder::new(root)
+        .max_depth(Some(MAX_WALK_DEPTH))
+        .hidden(false)
+        .git_global(false)
+        .git_ignore(config.respect_gitignore)
+        .git_exclude(config.respect_gitignore)
+        .ignore(config.respect_gitignore)
+        .parents(config.respect_gitignore)
+        .require_git(false)
+        .filter_entry(move |entry| {
+            let is_dir = entry.file_type().is_some_and(|ft| ft.is_dir());
+            entry.depth() == 0
+                || !(matches_glob(entry.path(), &prune_root, &prune)
+                    || (is_dir
+                        && SKIP_DIRS.contains(&entry.file_name().to_string_lossy().as_ref())))
+        })
+        .build_parallel()
+        .run(|| {
+            let mut collector = Collector {
+                local: WalkOutput::default(),
+                shared: &shared,
+            };
+            let walk = &walk;
+            Box::new(move |entry| {
+                if let Ok(entry) = entry {
+                    visit(&entry, walk, &mut collector.local);
+                }
+                WalkState::Continue
+            })
+        });
+
+    let mut out = shared.into_inner().unwrap_or_else(PoisonError::into_inner);
+    out.path_index.sort();
+    if config.respect_gitignore {
+        let local = local_files(&out, &walk, &ignore);
+        out.merge(local);
+        out.path_index.sort();
+    }
+    out.files.sort();
+    out.settings_files.sort();
+    out.config_files.sort();
     ScanResult {
-        files,
-        filename_index,
+        files: out.files,
+        settings_files: out.settings_files,
+        filename_index: out.filename_index,
+        path_index: out.path_index,
         canonical_root,
+        config_files: out.config_files,
+    }
+}
+
+/// Record the [`LOCAL_FILES`] of every walked directory that the walk left
+/// out because `.gitignore` (or `.ignore`) excludes them. `out.path_index`
+/// must be sorted.
+fn local_files(out: &WalkOutput, cfg: &WalkConfig, ignore: &GlobSet) -> WalkOutput {
+    let mut local = WalkOutput::default();
+    for dir in &out.dirs {
+        for name in LOCAL_FILES {
+            let path = dir.join(name);
+            let Ok(rel) = path.strip_prefix(&cfg.root) else {
+                continue;
+            };
+            let ignored = path
+                .ancestors()
+                .take_while(|p| p != dir)
+                .any(|p| matches_glob(p, &cfg.root, ignore));
+            if !ignored
+                && out
+                    .path_index
+                    .binary_search_by(|p| p.as_path().cmp(rel))
+                    .is_err()
+                && path.is_file()
+            {
+                record(&path, 1, cfg, &mut local);
+            }
+        }
+    }
+    local
+}
+
+/// Accumulators filled during a walk.
+#[derive(Default)]
+struct WalkOutput {
+    files: Vec<PathBuf>,
+    settings_files: Vec<PathBuf>,
+    filename_index: HashSet<String>,
+    path_index: Vec<PathBuf>,
+    config_files: Vec<PathBuf>,
+    /// Every walked directory, including the root.
+    dirs: Vec<PathBuf>,
+}
+
+impl WalkOutput {
+    fn merge(&mut self, other: WalkOutput) {
+        self.files.extend(other.files);
+        self.settings_files.extend(other.settings_files);
+        self.filename_index.extend(other.filename_index);
+        self.path_index.extend(other.path_index);
+        self.config_files.extend(other.config_files);
+        self.dirs.extend(other.dirs);
+    }
+}
+
+/// A walker thread's output, merged into the shared result when the thread
+/// finishes and drops its visitor.
+struct Collector<'a> {
+    local: WalkOutput,
+    shared: &'a Mutex<WalkOutput>,
+}
+
+impl Drop for Collector<'_> {
+    fn drop(&mut self) {
+        let local = std::mem::take(&mut self.local);
+        self.shared
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .merge(local);
     }
 }
 
@@ -52,61 +207,78 @@ pub(crate) fn matches_glob(path: &Path, root: &Path, set: &GlobSet) -> bool {
         || path.strip_prefix(root).is_ok_and(|rel| set.is_match(rel))
 }
 
-fn walk_dir(
-    dir: &Path,
-    cfg: &WalkConfig,
-    files: &mut Vec<PathBuf>,
-    filename_index: &mut HashSet<String>,
-    depth: usize,
-) {
-    if depth >= MAX_WALK_DEPTH {
+/// True for `.claude/settings.json` and `.claude/settings.local.json`.
+fn is_settings_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| SETTINGS_FILE_NAMES.contains(&n))
+        && path
+            .parent()
+            .and_then(Path::file_name)
+            .is_some_and(|dir| dir == ".claude")
+}
+
+/// Record one walked file. Directories are descended by the walker itself.
+fn visit(entry: &DirEntry, cfg: &WalkConfig, out: &mut WalkOutput) {
+    let path = entry.path();
+    let Some(ft) = entry.file_type() else {
+        return;
+    };
+    if ft.is_dir() {
+        out.dirs.push(path.to_path_buf());
         return;
     }
-    let Ok(entries) = std::fs::read_dir(dir) else {
+    if entry.depth() == 0 {
         return;
-    };
-
-    for entry in entries.flatten() {
-        let path = entry.path();
-
-        let Ok(ft) = entry.file_type() else {
-            continue;
-        };
+    }
 
-        // Symlinked directories are skipped to prevent cycles.
-        // Symlinked files are allowed only when they resolve within the project.
-        if ft.is_symlink() {
-            let within_root = !path.is_dir()
-                && cfg.canonical_root.as_ref().is_some_and(|root| {
-                    path.canonicalize()
-                        .is_ok_and(|resolved| resolved.starts_with(root))
-                });
-            if !within_root {
-                continue;
-            }
+    // Symlinked directories are skipped to prevent cycles.
+    // Symlinked files are allowed only when they resolve within the project.
+    if ft.is_symlink() {
+        let within_root = !path.is_dir()
+            && cfg.canonical_root.as_ref().is_some_and(|root| {
+                path.canonicalize()
+                    .is_ok_and(|resolved| resolved.starts_with(root))
+            });
+        if !within_root {
+            return;
         }
+    }
 
-        let name = entry.file_name();
-        let name_str = name.to_string_lossy();
-
-        if matches_glob(&path, &cfg.root, &cfg.ignore) {
-            continue;
-        }
+    record(path, entry.depth(), cfg, out);
+}
 
-        if ft.is_dir() {
-            if !SKIP_DIRS.contains(&name_str.as_ref()) {
-                walk_dir(&path, cfg, files, filename_index, depth + 1);
-            }
-        } else {
-            filename_index.insert(name_str.into_owned());
+/// Index a file found `depth` levels below the root and collect it if it is
+/// an instruction, settings or nested config file.
+fn record(path: &Path, depth: usize, cfg: &WalkConfig, out: &mut WalkOutput) {
+    let Some(name) = path.file_name() else {
+        return;
+    };
+    let name_str = name.to_string_lossy();
+    if name_str == CONFIG_FILE && depth > 1 {
+        out.config_files.push(path.to_path_buf());
+    }
+    out.filename_index.insert(name_str.into_owned());
+    if let Ok(rel) = path.strip_prefix(&cfg.root) {
+        out.path_index.push(rel.to_path_buf());
+    }
 
-            if path.extension().and_then(|e| e.to_str()) == Some("md")
-                && !matches_glob(&path, &cfg.root, &cfg.ignore_files)
-                && matches_glob(&path, &cfg.root, &cfg.include)
-            {
-                files.push(path);
-            }
-        }
+    if matches_glob(path, &cfg.root, &cfg.ignore_files)
+        || !matches_glob(path, &cfg.root, &cfg.include)
+    {
+        return;
+    }
+    let instruction_ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| INSTRUCTION_EXTENSIONS.contains(&e));
+    if instruction_ext
+        || ParseMode::for_path(path) == ParseMode::PlainText
+        || matches_glob(path, &cfg.root, &cfg.plain_text)
+    {
+        out.files.push(path.to_path_buf());
+    } else if is_settings_file(path) {
+        out.settings_files.push(path.to_path_buf());
     }
 }
 
@@ -310,6 +482,50 @@ mod tests {
         assert_eq!(result.filename_index.len(), 4);
     }
 
+    #[test]
+    fn test_scan_path_index_holds_relative_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("CLAUDE.md"), "# Hello").unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/lib.rs"), "// lib").unwrap();
+        fs::create_dir(dir.path().join("node_modules")).unwrap();
+        fs::write(dir.path().join("node_modules/dep.js"), "").unwrap();
+
+        let result = scan(dir.path(), &Config::default());
+        assert_eq!(
+            result.path_index,
+            vec![PathBuf::from("CLAUDE.md"), PathBuf::from("src/lib.rs")]
+        );
+    }
+
+    #[test]
+    fn test_scan_finds_cursor_mdc_rules() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join(".cursor/rules")).unwrap();
+        fs::write(dir.path().join(".cursor/rules/style.mdc"), "# Style").unwrap();
+        fs::write(dir.path().join("notes.mdc"), "# Not a rule").unwrap();
+
+        let files = scan(dir.path(), &Config::default()).files;
+        assert_eq!(files.len(), 1);
+        assert!(files[0].ends_with(".cursor/rules/style.mdc"));
+    }
+
+    #[test]
+    fn test_scan_finds_claude_settings_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join(".claude")).unwrap();
+        fs::write(dir.path().join(".claude/settings.json"), "{}").unwrap();
+        fs::write(dir.path().join(".claude/settings.local.json"), "{}").unwrap();
+        fs::write(dir.path().join(".claude/other.json"), "{}").unwrap();
+        fs::write(dir.path().join("settings.json"), "{}").unwrap();
+
+        let result = scan(dir.path(), &Config::default());
+        assert!(result.files.is_empty());
+        assert_eq!(result.settings_files.len(), 2);
+        assert!(result.settings_files[0].ends_with(".claude/settings.json"));
+        assert!(result.settings_files[1].ends_with(".claude/settings.local.json"));
+    }
+
     #[test]
     fn test_scan_canonical_root_is_populated() {
         let dir = tempfile::tempdir().unwrap();
@@ -517,4 +733,138 @@ mod tests {
         assert!(result.filename_index.contains("changelog.md"));
         assert!(result.filename_index.contains("readme.md"));
     }
+
+    #[test]
+    fn test_scan_plain_text_rule_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(".cursorrules"), "Use tabs.").unwrap();
+        fs::write(dir.path().join(".windsurfrules"), "Use spaces.").unwrap();
+        fs::create_dir(dir.path().join("rules")).unwrap();
+        fs::write(dir.path().join("rules/python"), "Use black.").unwrap();
+        fs::write(dir.path().join("rules/notes"), "Not a rule.").unwrap();
+
+        let config = Config {
+            include: vec![
+                ".cursorrules".into(),
+                ".windsurfrules".into(),
+                "rules/**".into(),
+            ],
+            plain_text: vec!["rules/python".into()],
+            ..Config::default()
+        };
+        let names: Vec<String> = scan(dir.path(), &config)
+            .files
+            .iter()
+            .map(|p| p.strip_prefix(dir.path()).unwrap().display().to_string())
+            .collect();
+        assert_eq!(
+            names,
+            vec![".cursorrules", ".windsurfrules", "rules/python"]
+        );
+    }
+
+    #[test]
+    fn test_scan_respects_gitignore_hierarchically() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("gen")).unwrap();
+        fs::create_dir_all(root.join("docs/drafts")).unwrap();
+        fs::write(root.join(".gitignore"), "gen/\n").unwrap();
+        fs::write(root.join("docs/.gitignore"), "drafts/\n").unwrap();
+        fs::write(root.join("docs/.ignore"), "scratch.md\n").unwrap();
+        fs::write(root.join("CLAUDE.md"), "# Root").unwrap();
+        fs::write(root.join("gen/CLAUDE.md"), "# Generated").unwrap();
+        fs::write(root.join("gen/out.js"), "").unwrap();
+        fs::write(root.join("docs/guide.md"), "# Guide").unwrap();
+        fs::write(root.join("docs/scratch.md"), "# Scratch").unwrap();
+        fs::write(root.join("docs/drafts/wip.md"), "# WIP").unwrap();
+
+        let result = scan(root, &all_md_config());
+        let rel: Vec<_> = result
+            .files
+            .iter()
+            .map(|f| f.strip_prefix(root).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(
+            rel,
+            vec![PathBuf::from("CLAUDE.md"), PathBuf::from("docs/guide.md")]
+        );
+        assert!(!result.filename_index.contains("out.js"));
+        assert!(!result.path_index.contains(&PathBuf::from("gen/out.js")));
+    }
+
+    #[test]
+    fn test_scan_respects_git_info_exclude() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join(".git/info")).unwrap();
+        fs::write(root.join(".git/info/exclude"), "local.md\n").unwrap();
+        fs::write(root.join("CLAUDE.md"), "# Root").unwrap();
+        fs::write(root.join("local.md"), "# Local").unwrap();
+
+        let files = scan(root, &all_md_config()).files;
+        assert_eq!(files, vec![root.join("CLAUDE.md")]);
+    }
+
+    #[test]
+    fn test_scan_keeps_gitignored_local_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join(".claude")).unwrap();
+        fs::create_dir_all(root.join("pkg")).unwrap();
+        fs::create_dir_all(root.join("gen")).unwrap();
+        fs::write(
+            root.join(".gitignore"),
+            "CLAUDE.local.md\n.claude/settings.local.json\ngen/\n",
+        )
+        .unwrap();
+        fs::write(root.join("CLAUDE.md"), "# Root").unwrap();
+        fs::write(root.join("CLAUDE.local.md"), "# Mine").unwrap();
+        fs::write(root.join("pkg/CLAUDE.local.md"), "# Mine too").unwrap();
+        fs::write(root.join("gen/CLAUDE.local.md"), "# Generated").unwrap();
+        fs::write(root.join(".claude/settings.local.json"), "{}").unwrap();
+        let config = Config {
+            include: vec!["**/*.md".to_string(), ".claude/**".to_string()],
+            ..Config::default()
+        };
+
+        let result = scan(root, &config);
+        assert_eq!(
+            result.files,
+            vec![
+                root.join("CLAUDE.local.md"),
+                root.join("CLAUDE.md"),
+                root.join("pkg/CLAUDE.local.md"),
+            ]
+        );
+        assert_eq!(
+            result.settings_files,
+            vec![root.join(".claude/settings.local.json")]
+        );
+        assert!(result
+            .path_index
+            .contains(&PathBuf::from("pkg/CLAUDE.local.md")));
+
+        let config = Config {
+            ignore_files: vec!["CLAUDE.local.md".to_string()],
+            ..config
+        };
+        assert_eq!(scan(root, &config).files, vec![root.join("CLAUDE.md")]);
+    }
+
+    #[test]
+    fn test_scan_respect_gitignore_can_be_disabled() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::write(root.join(".gitignore"), "generated.md\n").unwrap();
+        fs::write(root.join("CLAUDE.md"), "# Root").unwrap();
+        fs::write(root.join("generated.md"), "# Generated").unwrap();
+
+        assert_eq!(scan(root, &all_md_config()).files.len(), 1);
+        let config = Config {
+            respect_gitignore: false,
+            ..all_md_config()
+        };
+        assert_eq!(scan(root, &config).files.len(), 2);
+    }
 }
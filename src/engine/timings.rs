@@ -0,0 +1,88 @@
+//! Wall-clock timings of a check, reported by `check --timings`.
+
+use std::time::Duration;
+
+/// Time spent in each phase of a check. Checkers run in parallel, so their
+/// individual times can add up to more than `checks`.
+#[derive(Debug, Clone, Default)]
+pub struct Timings {
+    /// Walking the project for files to check.
+    pub scan: Duration,
+    /// Loading configs and the cache, and reading and parsing files.
+    pub parse: Duration,
+    /// Running every checker.
+    pub checks: Duration,
+    /// Time in each checker, summed over the configs it ran for, slowest
+    /// first. Project-wide checkers are absent when their results came from
+    /// the cache.
+    pub checkers: Vec<(String, Duration)>,
+    /// Applying suppressions and severity overrides, and sorting.
+    pub suppression: Duration,
+    /// Rendering the report; set by the output format.
+    pub output: Duration,
+}
+
+impl Timings {
+    /// The phases in the order they run.
+    #[must_use]
+    pub fn phases(&self) -> [(&'static str, Duration); 5] {
+        [
+            ("scan", self.scan),
+            ("parse", self.parse),
+            ("checks", self.checks),
+            ("suppression", self.suppression),
+            ("output", self.output),
+        ]
+    }
+
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        self.phases().iter().map(|(_, d)| *d).sum()
+    }
+
+    pub(crate) fn add_checker(&mut self, name: String, elapsed: Duration) {
+        match self.checkers.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, total)) => *total += elapsed,
+            None => self.checkers.push((name, elapsed)),
+        }
+    }
+
+    pub(crate) fn sort_checkers(&mut self) {
+        self.checkers
+            .sort_by(|(a_name, a), (b_name, b)| b.cmp(a).then_with(|| a_name.cmp(b_name)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checker_times_accumulate_and_sort_slowest_first() {
+        let mut timings = Timings::default();
+        timings.add_checker("dead-reference".into(), Duration::from_millis(2));
+        timings.add_checker("file-size".into(), Duration::from_millis(3));
+        timings.add_checker("dead-reference".into(), Duration::from_millis(4));
+        timings.sort_checkers();
+        assert_eq!(
+            timings.checkers,
+            vec![
+                ("dead-reference".to_string(), Duration::from_millis(6)),
+                ("file-size".to_string(), Duration::from_millis(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_total_sums_phases() {
+        let timings = Timings {
+            scan: Duration::from_millis(1),
+            parse: Duration::from_millis(2),
+            checks: Duration::from_millis(3),
+            checkers: vec![("file-size".into(), Duration::from_millis(30))],
+            suppression: Duration::from_millis(4),
+            output: Duration::from_millis(5),
+        };
+        assert_eq!(timings.total(), Duration::from_millis(15));
+    }
+}
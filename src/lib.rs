@@ -2,7 +2,11 @@ pub mod checkers;
 pub mod cli;
 pub mod config;
 pub mod engine;
+mod linter;
 #[cfg(feature = "lsp")]
 pub mod lsp;
 pub mod parser;
 pub mod types;
+
+pub use checkers::Checker;
+pub use linter::Linter;
@@ -0,0 +1,297 @@
+//! Embedding API: lint instruction text held in memory.
+//!
+//! [`Linter`] runs the same pipeline as `spectralint check` (per-path
+//! config, suppressions, severity overrides) on sources passed in by the
+//! caller, optionally against a project tree on disk, and can run extra
+//! [`Checker`]s alongside the built-in ones.
+
+use anyhow::Result;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::checkers::Checker;
+use crate::config::Config;
+use crate::engine::{self, scanner, Inputs, Timings};
+use crate::types::CheckResult;
+
+/// Stand-in project root when no filesystem root is given. It must not
+/// exist, so that checks looking at the disk see an empty project.
+const DETACHED_ROOT: &str = "/.spectralint-detached";
+
+/// Lints in-memory instruction files.
+///
+/// Source paths are relative to the root (absolute paths are used as-is).
+/// With a [`root`](Self::root), the project tree is scanned as
+/// `spectralint check` would, sources replace or add to the files on disk,
+/// and only the sources are reported on; with no sources, the whole project
+/// is. Without a root, only the sources exist: references to any other
+/// file are dead, and diagnostics carry the source paths as given.
+///
+/// ```no_run
+/// use spectralint::config::Config;
+/// use spectralint::Linter;
+///
+/// let result = Linter::new(Config::default())
+///     .root("/path/to/repo")
+///     .source("CLAUDE.md", "# Project\n\nSee `docs/setup.md`.\n")
+///     .run()?;
+/// for d in &result.diagnostics {
+///     println!("{}:{}: {}", d.file.display(), d.line, d.message);
+/// }
+/// # anyhow::Ok(())
+/// ```
+pub struct Linter {
+    config: Config,
+    root: Option<PathBuf>,
+    sources: Vec<(PathBuf, String)>,
+    checkers: Vec<Box<dyn Checker>>,
+}
+
+impl Linter {
+    /// A linter using `config`, with no root, sources or extra checkers.
+    #[must_use]
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            root: None,
+            sources: Vec::new(),
+            checkers: Vec::new(),
+        }
+    }
+
+    /// Resolve references and scan for other files under `root`.
+    #[must_use]
+    pub fn root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.root = Some(root.into());
+        self
+    }
+
+    /// Lint `contents` as the file at `path`.
+    #[must_use]
+    pub fn source(mut self, path: impl Into<PathBuf>, contents: impl Into<String>) -> Self {
+        self.sources.push((path.into(), contents.into()));
+        self
+    }
+
+    /// Run `checker` in addition to the built-in checkers enabled by the
+    /// config. It sees every file and is not scoped by `[[overrides]]`.
+    /// Its findings should use `Category::CustomPattern` with the checker's
+    /// `meta().name`, which suppression comments then refer to as
+    /// `custom:<name>`.
+    ///
+    /// ```
+    /// use spectralint::engine::CheckerContext;
+    /// use spectralint::types::{Category, CheckResult, Diagnostic, RuleMeta, Severity};
+    /// use spectralint::{config::Config, Checker, Linter};
+    ///
+    /// struct NoTabs;
+    ///
+    /// impl Checker for NoTabs {
+    ///     fn meta(&self) -> RuleMeta {
+    ///         RuleMeta {
+    ///             name: "no-tabs",
+    ///             description: "Tab characters in instruction files",
+    ///             default_severity: Severity::Info,
+    ///             strict_only: false,
+    ///         }
+    ///     }
+    ///
+    ///     fn check(&self, ctx: &CheckerContext) -> CheckResult {
+    ///         let mut result = CheckResult::default();
+    ///         for file in ctx.files() {
+    ///             for (i, line) in file.raw_lines.iter().enumerate() {
+    ///                 if line.contains('\t') {
+    ///                     result.diagnostics.push(Diagnostic {
+    ///                         file: file.path.clone(),
+    ///                         line: i + 1,
+    ///                         column: None,
+    ///                         end_line: None,
+    ///                         end_column: None,
+    ///                         severity: Severity::Info,
+    ///                         category: Category::CustomPattern("no-tabs".into()),
+    ///                         message: "Tab character".to_string(),
+    ///                         suggestion: None,
+    ///                         fix: None,
+    ///                         related: Vec::new(),
+    ///                     });
+    ///                 }
+    ///             }
+    ///         }
+    ///         result
+    ///     }
+    /// }
+    ///
+    /// let result = Linter::new(Config::default())
+    ///     .source("CLAUDE.md", "# Rules\n\n\tIndented with a tab.\n")
+    ///     .checker(NoTabs)
+    ///     .run()?;
+    /// assert!(result
+    ///     .diagnostics
+    ///     .iter()
+    ///     .any(|d| d.category.to_string() == "custom:no-tabs"));
+    /// # anyhow::Ok(())
+    /// ```
+    #[must_use]
+    pub fn checker(mut self, checker: impl Checker + 'static) -> Self {
+        self.checkers.push(Box::new(checker));
+        self
+    }
+
+    /// Lint the sources (or, with a root and no sources, the whole project).
+    pub fn run(&self) -> Result<CheckResult> {
+        let root = match &self.root {
+            Some(root) => root.canonicalize().unwrap_or_else(|_| root.clone()),
+            None => PathBuf::from(DETACHED_ROOT),
+        };
+        let inputs = Inputs {
+            sources: self
+                .sources
+                .iter()
+                .map(|(path, contents)| (root.join(path), contents.clone()))
+                .collect::<HashMap<_, _>>(),
+            paths: self
+                .sources
+                .iter()
+                .map(|(path, _)| root.join(path))
+                .collect(),
+        };
+
+        let mut scan_result = match self.root {
+            Some(_) => scanner::scan(&root, &self.config),
+            None => scanner::ScanResult::default(),
+        };
+        for path in &inputs.paths {
+            scan_result.add_file(path, &root);
+        }
+        let mut result = engine::check(
+            &root,
+            &self.config,
+            false,
+            None,
+            &inputs,
+            scan_result,
+            &self.checkers,
+            &mut Timings::default(),
+        )?;
+
+        if self.root.is_none() {
+            for d in &mut result.diagnostics {
+                d.file = detach(&d.file, &root);
+                for related in &mut d.related {
+                    related.file = detach(&related.file, &root);
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// `path` relative to the detached root, as the caller named it.
+fn detach(path: &Arc<PathBuf>, root: &Path) -> Arc<PathBuf> {
+    match path.strip_prefix(root) {
+        Ok(rel) => Arc::new(rel.to_path_buf()),
+        Err(_) => Arc::clone(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::CheckerContext;
+    use crate::types::{Category, Diagnostic, RuleMeta, Severity};
+
+    /// Flags notes meant for maintainers rather than agents.
+    struct NoInternalNotes;
+
+    impl Checker for NoInternalNotes {
+        fn meta(&self) -> RuleMeta {
+            RuleMeta {
+                name: "internal-note",
+                description: "Maintainer notes left in instructions",
+                default_severity: Severity::Warning,
+                strict_only: false,
+            }
+        }
+
+        fn check(&self, ctx: &CheckerContext) -> CheckResult {
+            let mut result = CheckResult::default();
+            for file in ctx.files() {
+                for (i, line) in file.non_code_lines() {
+                    if line.starts_with("INTERNAL:") {
+                        result.diagnostics.push(Diagnostic {
+                            file: Arc::clone(&file.path),
+                            line: i + 1,
+                            column: None,
+                            end_line: None,
+                            end_column: None,
+                            severity: Severity::Warning,
+                            category: Category::CustomPattern("internal-note".into()),
+                            message: "Maintainer note left in instructions".to_string(),
+                            suggestion: None,
+                            fix: None,
+                            related: Vec::new(),
+                        });
+                    }
+                }
+            }
+            result
+        }
+    }
+
+    fn categories(result: &CheckResult) -> Vec<(String, usize, String)> {
+        result
+            .diagnostics
+            .iter()
+            .map(|d| (d.file.display().to_string(), d.line, d.category.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_detached_sources_keep_their_paths() {
+        let result = Linter::new(Config::default())
+            .source("CLAUDE.md", "# Rules\n\nSee [setup](./setup.md).\n")
+            .run()
+            .unwrap();
+        assert_eq!(
+            categories(&result),
+            vec![("CLAUDE.md".to_string(), 3, "dead-reference".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_rooted_sources_resolve_against_tree() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("setup.md"), "# Setup\n").unwrap();
+        std::fs::write(dir.path().join("AGENTS.md"), "See [gone](./gone.md).\n").unwrap();
+
+        let result = Linter::new(Config::default())
+            .root(dir.path())
+            .source("CLAUDE.md", "# Rules\n\nSee [setup](./setup.md).\n")
+            .run()
+            .unwrap();
+        assert!(result.diagnostics.is_empty(), "{:?}", categories(&result));
+    }
+
+    #[test]
+    fn test_custom_checker_runs_and_can_be_suppressed() {
+        let result = Linter::new(Config::default())
+            .source(
+                "CLAUDE.md",
+                "# Rules\n\nINTERNAL: tighten this.\n\n\
+                 <!-- spectralint-disable-next-line custom:internal-note -->\n\
+                 INTERNAL: kept on purpose.\n",
+            )
+            .checker(NoInternalNotes)
+            .run()
+            .unwrap();
+        assert_eq!(
+            categories(&result),
+            vec![(
+                "CLAUDE.md".to_string(),
+                3,
+                "custom:internal-note".to_string()
+            )]
+        );
+    }
+}
@@ -56,7 +56,7 @@ impl SpectralintServer {
 
         // Group diagnostics by file, validating paths stay within workspace
         let canonical_root = root.canonicalize().ok();
-        let mut by_file: HashMap<PathBuf, Vec<Diagnostic>> = HashMap::new();
+        let mut by_file: HashMap<PathBuf, Vec<&SpectralDiag>> = HashMap::new();
         for d in &check_result.diagnostics {
             let abs_path = if d.file.is_absolute() {
                 (*d.file).clone()
@@ -71,15 +71,24 @@ impl SpectralintServer {
                     }
                 }
             }
-            by_file
-                .entry(abs_path)
-                .or_default()
-                .push(to_lsp_diagnostic(d));
+            by_file.entry(abs_path).or_default().push(d);
         }
 
         // Publish diagnostics for files with issues
         let mut new_uris = HashSet::new();
         for (path, diags) in by_file {
+            // Columns are converted to UTF-16 against the file's text, which
+            // is only needed when some diagnostic has them.
+            let content = if diags.iter().any(|d| d.column.is_some()) {
+                std::fs::read_to_string(&path).unwrap_or_default()
+            } else {
+                String::new()
+            };
+            let lines: Vec<&str> = content.lines().collect();
+            let diags = diags
+                .into_iter()
+                .map(|d| to_lsp_diagnostic(d, &lines))
+                .collect();
             if let Ok(uri) = Url::from_file_path(&path) {
                 self.client
                     .publish_diagnostics(uri.clone(), diags, None)
@@ -99,7 +108,24 @@ impl SpectralintServer {
     }
 }
 
-fn to_lsp_diagnostic(d: &SpectralDiag) -> Diagnostic {
+/// The LSP position of the 1-based character `column` on `line`. LSP
+/// counts UTF-16 code units, so characters outside the Basic Multilingual
+/// Plane count twice. Without the line's text, characters are assumed to be
+/// a single unit.
+fn utf16_column(line: Option<&str>, column: usize) -> u32 {
+    let chars = column.saturating_sub(1);
+    let units = match line {
+        Some(line) => {
+            let counted: usize = line.chars().take(chars).map(char::len_utf16).sum();
+            counted + chars.saturating_sub(line.chars().count())
+        }
+        None => chars,
+    };
+    u32::try_from(units).unwrap_or(u32::MAX)
+}
+
+/// Convert a diagnostic, using the checked file's `lines` to place columns.
+fn to_lsp_diagnostic(d: &SpectralDiag, lines: &[&str]) -> Diagnostic {
     let severity = Some(match d.severity {
         Severity::Error => DiagnosticSeverity::ERROR,
         Severity::Warning => DiagnosticSeverity::WARNING,
@@ -115,11 +141,20 @@ fn to_lsp_diagnostic(d: &SpectralDiag) -> Diagnostic {
         message.push_str(suggestion);
     }
 
-    let line_u32 = u32::try_from(line).unwrap_or(u32::MAX);
+    // Columns are 1-based with an exclusive end; without them the
+    // diagnostic covers whole lines.
+    let to_u32 = |n: usize| u32::try_from(n).unwrap_or(u32::MAX);
+    let end_line = d.end_line.map_or(line, |l| l.saturating_sub(1));
+    let start_col = d
+        .column
+        .map_or(0, |c| utf16_column(lines.get(line).copied(), c));
+    let end_col = d
+        .end_column
+        .map_or(u32::MAX, |c| utf16_column(lines.get(end_line).copied(), c));
     Diagnostic {
         range: Range {
-            start: Position::new(line_u32, 0),
-            end: Position::new(line_u32, u32::MAX),
+            start: Position::new(to_u32(line), start_col),
+            end: Position::new(to_u32(end_line), end_col),
         },
         severity,
         code: Some(NumberOrString::String(d.category.to_string())),
@@ -216,39 +251,78 @@ mod tests {
             message: "broken ref to `foo.md`".to_string(),
             suggestion: suggestion.map(String::from),
             fix: None,
+            related: Vec::new(),
         }
     }
 
     #[test]
     fn test_severity_mapping() {
-        let d = to_lsp_diagnostic(&make_diag(Severity::Error, 5, None));
+        let d = to_lsp_diagnostic(&make_diag(Severity::Error, 5, None), &[]);
         assert_eq!(d.severity, Some(DiagnosticSeverity::ERROR));
 
-        let d = to_lsp_diagnostic(&make_diag(Severity::Warning, 5, None));
+        let d = to_lsp_diagnostic(&make_diag(Severity::Warning, 5, None), &[]);
         assert_eq!(d.severity, Some(DiagnosticSeverity::WARNING));
 
-        let d = to_lsp_diagnostic(&make_diag(Severity::Info, 5, None));
+        let d = to_lsp_diagnostic(&make_diag(Severity::Info, 5, None), &[]);
         assert_eq!(d.severity, Some(DiagnosticSeverity::INFORMATION));
     }
 
     #[test]
     fn test_line_conversion() {
         // 1-based line 5 → 0-based line 4
-        let d = to_lsp_diagnostic(&make_diag(Severity::Warning, 5, None));
+        let d = to_lsp_diagnostic(&make_diag(Severity::Warning, 5, None), &[]);
         assert_eq!(d.range.start.line, 4);
 
         // line 0 edge case → stays 0
-        let d = to_lsp_diagnostic(&make_diag(Severity::Warning, 0, None));
+        let d = to_lsp_diagnostic(&make_diag(Severity::Warning, 0, None), &[]);
         assert_eq!(d.range.start.line, 0);
 
         // line 1 → 0
-        let d = to_lsp_diagnostic(&make_diag(Severity::Warning, 1, None));
+        let d = to_lsp_diagnostic(&make_diag(Severity::Warning, 1, None), &[]);
         assert_eq!(d.range.start.line, 0);
     }
 
+    #[test]
+    fn test_column_span_conversion() {
+        let d = to_lsp_diagnostic(&make_diag(Severity::Warning, 3, None), &[]);
+        assert_eq!(d.range.start, Position::new(2, 0));
+        assert_eq!(d.range.end, Position::new(2, u32::MAX));
+
+        // 1-based exclusive columns 5..11 → 0-based 4..10
+        let mut diag = make_diag(Severity::Warning, 3, None);
+        diag.column = Some(5);
+        diag.end_column = Some(11);
+        let d = to_lsp_diagnostic(&diag, &[]);
+        assert_eq!(d.range.start, Position::new(2, 4));
+        assert_eq!(d.range.end, Position::new(2, 10));
+
+        diag.end_line = Some(4);
+        diag.end_column = None;
+        let d = to_lsp_diagnostic(&diag, &[]);
+        assert_eq!(d.range.end, Position::new(3, u32::MAX));
+    }
+
+    #[test]
+    fn test_columns_count_utf16_units() {
+        // The rocket is one character but two UTF-16 code units.
+        let line = "🚀 See https://example.com now";
+        let mut diag = make_diag(Severity::Warning, 2, None);
+        diag.column = Some(7);
+        diag.end_column = Some(26);
+        let d = to_lsp_diagnostic(&diag, &["# Rules", line]);
+        assert_eq!(d.range.start, Position::new(1, 7));
+        assert_eq!(d.range.end, Position::new(1, 26));
+
+        let utf16: Vec<u16> = line.encode_utf16().collect();
+        assert_eq!(
+            String::from_utf16(&utf16[7..26]).unwrap(),
+            "https://example.com"
+        );
+    }
+
     #[test]
     fn test_category_as_code() {
-        let d = to_lsp_diagnostic(&make_diag(Severity::Warning, 1, None));
+        let d = to_lsp_diagnostic(&make_diag(Severity::Warning, 1, None), &[]);
         assert_eq!(
             d.code,
             Some(NumberOrString::String("dead-reference".to_string()))
@@ -257,20 +331,23 @@ mod tests {
 
     #[test]
     fn test_suggestion_appended() {
-        let d = to_lsp_diagnostic(&make_diag(Severity::Warning, 1, Some("use bar.md instead")));
+        let d = to_lsp_diagnostic(
+            &make_diag(Severity::Warning, 1, Some("use bar.md instead")),
+            &[],
+        );
         assert!(d.message.contains("broken ref to `foo.md`"));
         assert!(d.message.contains("Suggestion: use bar.md instead"));
     }
 
     #[test]
     fn test_no_suggestion() {
-        let d = to_lsp_diagnostic(&make_diag(Severity::Warning, 1, None));
+        let d = to_lsp_diagnostic(&make_diag(Severity::Warning, 1, None), &[]);
         assert!(!d.message.contains("Suggestion"));
     }
 
     #[test]
     fn test_source_is_spectralint() {
-        let d = to_lsp_diagnostic(&make_diag(Severity::Warning, 1, None));
+        let d = to_lsp_diagnostic(&make_diag(Severity::Warning, 1, None), &[]);
         assert_eq!(d.source, Some("spectralint".to_string()));
     }
 }
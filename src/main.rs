@@ -1,15 +1,83 @@
 use anyhow::Result;
 use clap::Parser;
 use notify::{recommended_watcher, RecursiveMode, Watcher};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::mpsc;
 use std::time::Duration;
 
-use spectralint::cli::{Cli, Commands, OutputFormat, Preset};
-use spectralint::config::Config;
-use spectralint::engine;
+use spectralint::cli::{Cli, Commands, ConfigCommand, OutputFormat, Preset};
+use spectralint::config::{Config, ConfigIssue};
+use spectralint::engine::{self, Baseline, ChangedLines, DiffBase, Inputs};
 use spectralint::types::Severity;
 
+/// How `check` uses a baseline file.
+#[derive(Clone, Copy)]
+enum BaselineMode<'a> {
+    Off,
+    /// Drop findings recorded in the baseline and report stale entries.
+    Filter(&'a Baseline),
+    /// Record all findings to this path instead of reporting them.
+    Write(&'a Path),
+}
+
+/// Print config issues to stderr with paths relative to the project root.
+fn print_config_issues(issues: &[ConfigIssue], project_root: &Path, prefix: &str) {
+    for issue in issues {
+        let mut issue = issue.clone();
+        if let Ok(rel) = issue.file.strip_prefix(project_root) {
+            issue.file = rel.to_path_buf();
+        }
+        eprintln!("{prefix}{issue}");
+    }
+}
+
+/// Absolute form of `path`, with symlinks resolved as far as it exists.
+fn resolve_path(path: &Path) -> Result<PathBuf> {
+    if let Ok(resolved) = path.canonicalize() {
+        return Ok(resolved);
+    }
+    let absolute = std::path::absolute(path)?;
+    match (absolute.parent(), absolute.file_name()) {
+        (Some(parent), Some(name)) => Ok(parent
+            .canonicalize()
+            .unwrap_or_else(|_| parent.to_path_buf())
+            .join(name)),
+        _ => Ok(absolute),
+    }
+}
+
+/// Work out the project root and the paths to report on from the `check`
+/// arguments. A single directory is the project root itself; otherwise the
+/// root is discovered upwards from the first path.
+fn check_targets(paths: &[PathBuf], stdin: Option<&Path>) -> Result<(PathBuf, Vec<PathBuf>)> {
+    if let (None, [dir]) = (stdin, paths) {
+        if dir.is_dir() {
+            return Ok((
+                dir.canonicalize().unwrap_or_else(|_| dir.clone()),
+                Vec::new(),
+            ));
+        }
+    }
+    let targets = match stdin {
+        Some(file) => vec![resolve_path(file)?],
+        None => paths
+            .iter()
+            .map(|p| {
+                if !p.exists() {
+                    anyhow::bail!("No such file or directory: {}", p.display());
+                }
+                resolve_path(p)
+            })
+            .collect::<Result<_>>()?,
+    };
+    let start = if targets[0].is_dir() {
+        &targets[0]
+    } else {
+        targets[0].parent().unwrap_or(Path::new("/"))
+    };
+    Ok((engine::find_project_root(start), targets))
+}
+
 /// Run a single check pass. Returns true if diagnostics meet the fail_on threshold.
 #[allow(clippy::too_many_arguments)]
 fn run_check(
@@ -23,8 +91,18 @@ fn run_check(
     fail_on: Severity,
     use_cache: bool,
     apply_fix: bool,
+    baseline: BaselineMode<'_>,
+    diff_base: Option<DiffBase<'_>>,
+    inputs: &Inputs,
+    timings: bool,
 ) -> Result<bool> {
-    let mut result = engine::run(project_root, cfg, use_cache, config_path)?;
+    let mut inputs = inputs.clone();
+    if let Some(DiffBase::Staged) = diff_base {
+        inputs.sources = engine::staged_sources(project_root)?;
+    }
+    let (mut result, engine_timings) =
+        engine::run_with_timings(project_root, cfg, use_cache, config_path, &inputs)?;
+    let timings = timings.then_some(engine_timings);
 
     // Apply --rule filter
     if !rule.is_empty() {
@@ -41,6 +119,41 @@ fn run_check(
         });
     }
 
+    // Apply --since / --staged filter
+    if let Some(base) = diff_base {
+        ChangedLines::from_git(project_root, base)?.filter(&mut result.diagnostics);
+    }
+
+    match baseline {
+        BaselineMode::Off => {}
+        BaselineMode::Write(path) => {
+            let baseline =
+                Baseline::from_diagnostics(&result.diagnostics, project_root, &inputs.sources);
+            baseline.save(path)?;
+            if !quiet {
+                println!(
+                    "Wrote {} finding(s) to baseline {}",
+                    baseline.entries.len(),
+                    path.display()
+                );
+            }
+            return Ok(false);
+        }
+        BaselineMode::Filter(baseline) => {
+            let stale = baseline.filter(&mut result.diagnostics, project_root, &inputs.sources);
+            if !stale.is_empty() && !quiet {
+                eprintln!(
+                    "{} baseline entr{} no longer match any finding; re-run with --write-baseline to prune:",
+                    stale.len(),
+                    if stale.len() == 1 { "y" } else { "ies" }
+                );
+                for entry in stale {
+                    eprintln!("  {} [{}] {}", entry.file, entry.rule, entry.message);
+                }
+            }
+        }
+    }
+
     // Apply autofixes if --fix is set
     if apply_fix {
         let fixed = engine::apply_fixes(&result.diagnostics);
@@ -66,8 +179,14 @@ fn run_check(
                 .collect();
                 println!("{}", parts.join(", "));
             }
+            if let Some(timings) = &timings {
+                print!(
+                    "\n{}",
+                    spectralint::cli::output::text::render_timings(timings)
+                );
+            }
         } else {
-            spectralint::cli::output::render(&result, project_root, output_format);
+            spectralint::cli::output::render(&result, project_root, output_format, timings);
         }
     }
 
@@ -83,7 +202,9 @@ fn main() -> Result<()> {
 
     match cli.command {
         Commands::Check {
-            path,
+            paths,
+            stdin,
+            stdin_filename,
             format,
             config,
             fail_on,
@@ -93,24 +214,55 @@ fn main() -> Result<()> {
             no_color,
             count,
             no_cache,
+            timings,
             watch,
             fix,
+            baseline,
+            write_baseline,
+            since,
+            staged,
         } => {
             // Handle --no-color and NO_COLOR env var
             if no_color || std::env::var("NO_COLOR").is_ok() {
                 owo_colors::set_override(false);
             }
 
-            let project_root = path.canonicalize().unwrap_or(path);
+            let (project_root, targets) = check_targets(&paths, stdin_filename.as_deref())?;
+            let mut inputs = Inputs {
+                paths: targets,
+                ..Inputs::default()
+            };
+            if stdin {
+                let content = std::io::read_to_string(std::io::stdin())?;
+                inputs.sources.insert(inputs.paths[0].clone(), content);
+            }
+
             let mut cfg = Config::load(config.as_deref(), &project_root)?;
             if strict {
                 cfg.strict = true;
             }
 
+            if !quiet {
+                print_config_issues(&cfg.validate(), &project_root, "warning: ");
+            }
+
             let output_format = format.unwrap_or(cfg.format);
 
             let use_cache = !no_cache;
 
+            let loaded_baseline = baseline.as_deref().map(Baseline::load).transpose()?;
+            let baseline_mode = match (&loaded_baseline, &write_baseline) {
+                (Some(b), _) => BaselineMode::Filter(b),
+                (None, Some(path)) => BaselineMode::Write(path),
+                (None, None) => BaselineMode::Off,
+            };
+
+            let diff_base = match (&since, staged) {
+                (Some(rev), _) => Some(DiffBase::Since(rev)),
+                (None, true) => Some(DiffBase::Staged),
+                (None, false) => None,
+            };
+
             // First run
             let failed = run_check(
                 &project_root,
@@ -123,6 +275,10 @@ fn main() -> Result<()> {
                 fail_on,
                 use_cache,
                 fix,
+                baseline_mode,
+                diff_base,
+                &inputs,
+                timings,
             )?;
 
             if !watch {
@@ -159,6 +315,10 @@ fn main() -> Result<()> {
                             fail_on,
                             use_cache,
                             fix,
+                            baseline_mode,
+                            diff_base,
+                            &inputs,
+                            timings,
                         ) {
                             Ok(_) => {}
                             Err(e) => tracing::error!("Error: {e}"),
@@ -173,12 +333,15 @@ fn main() -> Result<()> {
         }
         Commands::Init { preset } => {
             use std::io::Write;
-            let path = std::env::current_dir()?.join(".spectralintrc.toml");
-            let content = match preset {
+            let cwd = std::env::current_dir()?;
+            let path = cwd.join(".spectralintrc.toml");
+            let template = match preset {
                 Some(Preset::Minimal) => Config::minimal_toml(),
                 Some(Preset::Strict) => Config::strict_toml(),
                 Some(Preset::Standard) | None => Config::default_toml(),
             };
+            let profiles = spectralint::config::detect_profiles(&cwd);
+            let content = Config::with_profiles(template, &profiles);
             match std::fs::OpenOptions::new()
                 .write(true)
                 .create_new(true)
@@ -192,6 +355,10 @@ fn main() -> Result<()> {
                         _ => "",
                     };
                     println!("Created .spectralintrc.toml{label}");
+                    if !profiles.is_empty() {
+                        let names: Vec<&str> = profiles.iter().map(|p| p.name()).collect();
+                        println!("Detected profiles: {}", names.join(", "));
+                    }
                 }
                 Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
                     eprintln!(".spectralintrc.toml already exists");
@@ -200,6 +367,64 @@ fn main() -> Result<()> {
                 Err(e) => return Err(e.into()),
             }
         }
+        Commands::Context {
+            path,
+            root,
+            config,
+            text,
+        } => {
+            let project_root = root.canonicalize().unwrap_or(root);
+            let cfg = Config::load(config.as_deref(), &project_root)?;
+            let set = engine::context(&project_root, &cfg, &path)?;
+
+            println!("Instructions loaded for {}:\n", path.display());
+            let width = set
+                .sources
+                .iter()
+                .map(|s| s.path.as_os_str().len())
+                .max()
+                .unwrap_or(0);
+            for source in &set.sources {
+                println!(
+                    "  {:<width$}  {:>7} tokens  ({})",
+                    source.path.display().to_string(),
+                    source.tokens,
+                    source.reason
+                );
+            }
+            let n = set.sources.len();
+            println!(
+                "\n{n} file{}, ~{} tokens",
+                if n == 1 { "" } else { "s" },
+                set.total_tokens()
+            );
+            if text {
+                println!("\n{}", set.text());
+            }
+        }
+        Commands::Config {
+            command: ConfigCommand::Check { path, config },
+        } => {
+            let project_root = path.canonicalize().unwrap_or(path);
+            let cfg = Config::load(config.as_deref(), &project_root)?;
+            let mut issues = cfg.validate();
+            for file in engine::nested_config_files(&project_root, &cfg) {
+                // Loading on its own catches invalid values as well as unknown keys.
+                Config::load(Some(&file), &project_root)?;
+                issues.extend(spectralint::config::validate_file(&file)?);
+            }
+            print_config_issues(&issues, &project_root, "");
+            if !issues.is_empty() {
+                std::process::exit(1);
+            }
+            println!("No config problems found");
+        }
+        Commands::Config {
+            command: ConfigCommand::Schema,
+        } => {
+            let schema = spectralint::config::json_schema();
+            println!("{}", serde_json::to_string_pretty(&schema)?);
+        }
         Commands::Explain { rule: None } => {
             println!("{}", spectralint::cli::explain::list_rules());
         }
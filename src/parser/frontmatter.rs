@@ -0,0 +1,465 @@
+//! Minimal YAML frontmatter reader.
+//!
+//! Instruction files only use a flat subset of YAML in their frontmatter:
+//! `key: value` scalars, inline `[a, b]` lists, and block `- item` lists.
+//! This reader handles that subset and keeps the line number of every key so
+//! checkers can point diagnostics at the offending entry. Syntax it cannot
+//! make sense of is recorded as a [`FrontmatterProblem`] instead of failing.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A frontmatter value: a scalar string or a list of strings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum FrontmatterValue {
+    Scalar(String),
+    List(Vec<String>),
+}
+
+impl FrontmatterValue {
+    /// The scalar text, or `None` for lists.
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Scalar(s) => Some(s),
+            Self::List(_) => None,
+        }
+    }
+
+    /// Items of a list, or the comma-separated parts of a scalar. Commas
+    /// inside quotes or `{..}` / `[..]` (glob alternatives) do not split.
+    /// Empty items are dropped.
+    pub(crate) fn items(&self) -> Vec<&str> {
+        let items: Vec<&str> = match self {
+            Self::Scalar(s) => split_items(s).into_iter().map(str::trim).collect(),
+            Self::List(items) => items.iter().map(|s| s.trim()).collect(),
+        };
+        items.into_iter().filter(|s| !s.is_empty()).collect()
+    }
+
+    /// Interpret the value as a YAML boolean.
+    pub(crate) fn as_bool(&self) -> Option<bool> {
+        match self.as_str()?.to_ascii_lowercase().as_str() {
+            "true" | "yes" | "on" => Some(true),
+            "false" | "no" | "off" => Some(false),
+            _ => None,
+        }
+    }
+
+    /// True for an empty scalar or an empty list.
+    pub(crate) fn is_empty(&self) -> bool {
+        match self {
+            Self::Scalar(s) => s.is_empty(),
+            Self::List(items) => items.is_empty(),
+        }
+    }
+}
+
+/// One top-level `key: value` entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct FrontmatterEntry {
+    pub key: String,
+    pub value: FrontmatterValue,
+    /// 1-based line of the key.
+    pub line: usize,
+}
+
+/// Malformed syntax found while reading the block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct FrontmatterProblem {
+    /// 1-based line of the offending text.
+    pub line: usize,
+    pub kind: FrontmatterProblemKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum FrontmatterProblemKind {
+    /// Invalid YAML for this subset; the message says what was expected.
+    Syntax(String),
+    /// A key that already appeared on `first_line`.
+    DuplicateKey { key: String, first_line: usize },
+    /// Tab characters in indentation, which YAML forbids.
+    Tab,
+}
+
+/// Parsed frontmatter block.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct Frontmatter {
+    pub entries: Vec<FrontmatterEntry>,
+    /// 1-based line of the closing delimiter; the body starts on the next line.
+    pub end_line: usize,
+    pub problems: Vec<FrontmatterProblem>,
+}
+
+impl Frontmatter {
+    /// First entry for `key`.
+    pub(crate) fn get(&self, key: &str) -> Option<&FrontmatterEntry> {
+        self.entries.iter().find(|e| e.key == key)
+    }
+}
+
+/// Index of the closing frontmatter delimiter, if `lines` opens with `---`.
+fn closing_delimiter(lines: &[String]) -> Option<usize> {
+    if !lines.first().is_some_and(|l| l.trim() == "---") {
+        return None;
+    }
+    lines
+        .iter()
+        .enumerate()
+        .skip(1)
+        .find(|(_, l)| {
+            let trimmed = l.trim();
+            trimmed == "---" || trimmed == "..."
+        })
+        .map(|(i, _)| i)
+}
+
+/// Strip one layer of matching single or double quotes.
+fn unquote(s: &str) -> &str {
+    let s = s.trim();
+    if s.len() >= 2
+        && ((s.starts_with('"') && s.ends_with('"')) || (s.starts_with('\'') && s.ends_with('\'')))
+    {
+        &s[1..s.len() - 1]
+    } else {
+        s
+    }
+}
+
+/// Remove a trailing ` # comment` outside quotes.
+fn strip_comment(s: &str) -> &str {
+    let mut quote: Option<char> = None;
+    let mut prev_space = true;
+    for (i, c) in s.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '#' && prev_space => return s[..i].trim_end(),
+            None => {}
+        }
+        prev_space = c.is_whitespace();
+    }
+    s
+}
+
+/// Split `s` on commas that are outside quotes and brackets.
+fn split_items(s: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut quote: Option<char> = None;
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '{' | '[' => depth += 1,
+                '}' | ']' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => {
+                    items.push(&s[start..i]);
+                    start = i + 1;
+                }
+                _ => {}
+            },
+        }
+    }
+    items.push(&s[start..]);
+    items
+}
+
+/// Why an inline value is not valid YAML, if it is not.
+fn inline_value_problem(raw: &str) -> Option<&'static str> {
+    let raw = strip_comment(raw).trim();
+    if raw.starts_with('[') && !raw.ends_with(']') {
+        return Some("unclosed `[` in flow list");
+    }
+    if raw.starts_with('{') && !raw.ends_with('}') {
+        return Some("unclosed `{` in flow mapping");
+    }
+    for quote in ['"', '\''] {
+        if raw.starts_with(quote) && (raw.len() < 2 || !raw.ends_with(quote)) {
+            return Some("unterminated quoted string");
+        }
+    }
+    None
+}
+
+fn parse_inline_value(raw: &str) -> FrontmatterValue {
+    let raw = strip_comment(raw).trim();
+    if let Some(inner) = raw.strip_prefix('[').and_then(|r| r.strip_suffix(']')) {
+        let items = split_items(inner)
+            .into_iter()
+            .map(|item| unquote(item).to_string())
+            .filter(|item| !item.is_empty())
+            .collect();
+        return FrontmatterValue::List(items);
+    }
+    FrontmatterValue::Scalar(unquote(raw).to_string())
+}
+
+/// Parse the leading frontmatter block of `lines`. Returns `None` when the
+/// file has no (closed) frontmatter.
+pub(crate) fn parse_frontmatter(lines: &[String]) -> Option<Frontmatter> {
+    let close = closing_delimiter(lines)?;
+    let mut entries: Vec<FrontmatterEntry> = Vec::new();
+    let mut problems: Vec<FrontmatterProblem> = Vec::new();
+    let mut first_lines: HashMap<String, usize> = HashMap::new();
+    let body = &lines[1..close];
+
+    for (i, line) in body.iter().enumerate() {
+        let indent = &line[..line.len() - line.trim_start().len()];
+        if indent.contains('\t') {
+            problems.push(FrontmatterProblem {
+                line: i + 2,
+                kind: FrontmatterProblemKind::Tab,
+            });
+        }
+    }
+
+    let mut i = 0;
+    while i < body.len() {
+        let line = &body[i];
+        let line_num = i + 2;
+        i += 1;
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || line.starts_with([' ', '\t']) {
+            continue;
+        }
+        let Some((key, rest)) = line.split_once(':') else {
+            problems.push(FrontmatterProblem {
+                line: line_num,
+                kind: FrontmatterProblemKind::Syntax("expected `key: value`".into()),
+            });
+            continue;
+        };
+        let key = unquote(key).to_string();
+        if key.is_empty() {
+            problems.push(FrontmatterProblem {
+                line: line_num,
+                kind: FrontmatterProblemKind::Syntax("empty key".into()),
+            });
+            continue;
+        }
+        if let Some(problem) = inline_value_problem(rest) {
+            problems.push(FrontmatterProblem {
+                line: line_num,
+                kind: FrontmatterProblemKind::Syntax(problem.into()),
+            });
+        }
+        match first_lines.get(&key) {
+            Some(&first_line) => problems.push(FrontmatterProblem {
+                line: line_num,
+                kind: FrontmatterProblemKind::DuplicateKey {
+                    key: key.clone(),
+                    first_line,
+                },
+            }),
+            None => {
+                first_lines.insert(key.clone(), line_num);
+            }
+        }
+        let rest = strip_comment(rest).trim();
+
+        let value = if rest.is_empty() || rest.starts_with(['|', '>']) {
+            // Block list or block scalar: consume the indented lines below.
+            // List items may also sit at the key's own indent.
+            let folded = rest.starts_with('>');
+            let mut items = Vec::new();
+            let mut text = Vec::new();
+            while i < body.len() {
+                let next = &body[i];
+                let is_item = next.starts_with("- ") || next.trim_end() == "-";
+                let in_block = next.trim().is_empty()
+                    || next.starts_with([' ', '\t'])
+                    || (rest.is_empty() && is_item);
+                if !in_block {
+                    break;
+                }
+                let next = next.trim();
+                if let Some(item) = next.strip_prefix("- ").or((next == "-").then_some("")) {
+                    items.push(unquote(strip_comment(item)).to_string());
+                } else if !next.is_empty() {
+                    text.push(next);
+                }
+                i += 1;
+            }
+            if rest.is_empty() && !items.is_empty() {
+                FrontmatterValue::List(items)
+            } else {
+                FrontmatterValue::Scalar(text.join(if folded { " " } else { "\n" }))
+            }
+        } else {
+            parse_inline_value(rest)
+        };
+
+        entries.push(FrontmatterEntry {
+            key,
+            value,
+            line: line_num,
+        });
+    }
+
+    Some(Frontmatter {
+        entries,
+        end_line: close + 1,
+        problems,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(s: &str) -> Vec<String> {
+        s.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_block_list_at_key_indent() {
+        let fm = parse_frontmatter(&lines(
+            "---\nname: reviewer\ntools:\n- Read\n- Grep # search\nmodel: sonnet\n---\n",
+        ))
+        .unwrap();
+        assert!(fm.problems.is_empty(), "{:?}", fm.problems);
+        assert_eq!(
+            fm.get("tools").unwrap().value,
+            FrontmatterValue::List(vec!["Read".into(), "Grep".into()])
+        );
+        assert_eq!(fm.get("model").unwrap().value.as_str(), Some("sonnet"));
+    }
+
+    #[test]
+    fn test_scalars_and_line_numbers() {
+        let fm = parse_frontmatter(&lines(
+            "---\ndescription: \"Rust style\"\nalwaysApply: false # note\n---\n# Body",
+        ))
+        .unwrap();
+        let desc = fm.get("description").unwrap();
+        assert_eq!(desc.value.as_str(), Some("Rust style"));
+        assert_eq!(desc.line, 2);
+        assert_eq!(fm.end_line, 4);
+        assert_eq!(fm.get("alwaysApply").unwrap().value.as_bool(), Some(false));
+    }
+
+    #[test]
+    fn test_inline_and_block_lists() {
+        let fm = parse_frontmatter(&lines(
+            "---\nglobs: [\"src/**/*.rs\", 'tests/*.rs']\ntools:\n  - Read\n  - Grep\n---\n",
+        ))
+        .unwrap();
+        assert_eq!(
+            fm.get("globs").unwrap().value.items(),
+            vec!["src/**/*.rs", "tests/*.rs"]
+        );
+        assert_eq!(fm.get("tools").unwrap().value.items(), vec!["Read", "Grep"]);
+    }
+
+    #[test]
+    fn test_comma_separated_scalar_items() {
+        let fm = parse_frontmatter(&lines("---\nglobs: *.ts, src/**/*.tsx\n---\n")).unwrap();
+        assert_eq!(
+            fm.get("globs").unwrap().value.items(),
+            vec!["*.ts", "src/**/*.tsx"]
+        );
+    }
+
+    #[test]
+    fn test_commas_inside_braces_and_quotes_do_not_split() {
+        let fm = parse_frontmatter(&lines(
+            "---\nglobs: src/**/*.{ts,tsx}, docs/*.md\napplyTo: [\"**/*.{js,jsx}\", 'a,b.md', lib/**]\n---\n",
+        ))
+        .unwrap();
+        assert_eq!(
+            fm.get("globs").unwrap().value.items(),
+            vec!["src/**/*.{ts,tsx}", "docs/*.md"]
+        );
+        assert_eq!(
+            fm.get("applyTo").unwrap().value.items(),
+            vec!["**/*.{js,jsx}", "a,b.md", "lib/**"]
+        );
+    }
+
+    #[test]
+    fn test_block_scalar() {
+        let fm = parse_frontmatter(&lines("---\ndescription: >\n  Line one\n  line two\n---\n"))
+            .unwrap();
+        assert_eq!(
+            fm.get("description").unwrap().value.as_str(),
+            Some("Line one line two")
+        );
+    }
+
+    #[test]
+    fn test_empty_value() {
+        let fm = parse_frontmatter(&lines("---\nglobs:\nalwaysApply: true\n---\n")).unwrap();
+        assert!(fm.get("globs").unwrap().value.is_empty());
+        assert_eq!(fm.get("alwaysApply").unwrap().value.as_bool(), Some(true));
+    }
+
+    #[test]
+    fn test_no_frontmatter() {
+        assert!(parse_frontmatter(&lines("# Title\n---\n")).is_none());
+        assert!(parse_frontmatter(&lines("---\nkey: value\n")).is_none());
+    }
+
+    #[test]
+    fn test_well_formed_has_no_problems() {
+        let fm = parse_frontmatter(&lines(
+            "---\nname: a\n# comment\ntools:\n  - Read\nglobs: [\"*.rs\"]\n---\n",
+        ))
+        .unwrap();
+        assert!(fm.problems.is_empty(), "{:?}", fm.problems);
+    }
+
+    #[test]
+    fn test_duplicate_keys() {
+        let fm = parse_frontmatter(&lines("---\nname: a\nmodel: x\nname: b\n---\n")).unwrap();
+        assert_eq!(
+            fm.problems,
+            vec![FrontmatterProblem {
+                line: 4,
+                kind: FrontmatterProblemKind::DuplicateKey {
+                    key: "name".into(),
+                    first_line: 2
+                },
+            }]
+        );
+        // The first definition wins for lookups.
+        assert_eq!(fm.get("name").unwrap().value.as_str(), Some("a"));
+    }
+
+    #[test]
+    fn test_tab_indentation() {
+        let fm = parse_frontmatter(&lines("---\ntools:\n\t- Read\n---\n")).unwrap();
+        assert_eq!(fm.problems.len(), 1);
+        assert_eq!(fm.problems[0].line, 3);
+        assert_eq!(fm.problems[0].kind, FrontmatterProblemKind::Tab);
+    }
+
+    #[test]
+    fn test_syntax_problems() {
+        let fm = parse_frontmatter(&lines(
+            "---\njust some prose\nglobs: [a, b\ndescription: \"unterminated\n: orphan\n---\n",
+        ))
+        .unwrap();
+        let lines_and_kinds: Vec<(usize, String)> = fm
+            .problems
+            .iter()
+            .map(|p| match &p.kind {
+                FrontmatterProblemKind::Syntax(msg) => (p.line, msg.clone()),
+                other => panic!("unexpected {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            lines_and_kinds,
+            vec![
+                (2, "expected `key: value`".to_string()),
+                (3, "unclosed `[` in flow list".to_string()),
+                (4, "unterminated quoted string".to_string()),
+                (5, "empty key".to_string()),
+            ]
+        );
+    }
+}
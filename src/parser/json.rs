@@ -0,0 +1,344 @@
+//! Position-tracking JSON reader.
+//!
+//! `serde_json` discards source positions, but settings diagnostics need to
+//! point at the exact entry they complain about. This reader produces a small
+//! tree where every value and object key carries its 1-based line and column.
+
+/// 1-based line and column (in characters) of a token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum JsonValue {
+    Null,
+    Bool(bool),
+    /// Numbers are kept as their source text; settings never need arithmetic.
+    Number(String),
+    String(String),
+    Array(Vec<JsonNode>),
+    Object(Vec<JsonMember>),
+}
+
+/// A JSON value and the position of its first character.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct JsonNode {
+    pub value: JsonValue,
+    pub pos: Position,
+}
+
+/// One `"key": value` pair of an object, in source order.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct JsonMember {
+    pub key: String,
+    pub key_pos: Position,
+    pub value: JsonNode,
+}
+
+/// A syntax error and where it was detected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct JsonError {
+    pub message: String,
+    pub pos: Position,
+}
+
+impl JsonNode {
+    /// Value of the first member named `key`, if this is an object.
+    pub(crate) fn get(&self, key: &str) -> Option<&JsonNode> {
+        match &self.value {
+            JsonValue::Object(members) => members.iter().find(|m| m.key == key).map(|m| &m.value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match &self.value {
+            JsonValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_array(&self) -> Option<&[JsonNode]> {
+        match &self.value {
+            JsonValue::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_object(&self) -> Option<&[JsonMember]> {
+        match &self.value {
+            JsonValue::Object(members) => Some(members),
+            _ => None,
+        }
+    }
+}
+
+/// Maximum nesting depth, to keep crafted inputs from overflowing the stack.
+const MAX_DEPTH: usize = 128;
+
+struct Reader<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl Reader<'_> {
+    fn pos(&self) -> Position {
+        Position {
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, JsonError> {
+        Err(JsonError {
+            message: message.into(),
+            pos: self.pos(),
+        })
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .peek()
+            .is_some_and(|c| matches!(c, ' ' | '\t' | '\n' | '\r'))
+        {
+            self.bump();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), JsonError> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.bump();
+                Ok(())
+            }
+            Some(c) => self.error(format!("expected '{expected}', found '{c}'")),
+            None => self.error(format!("expected '{expected}', found end of file")),
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Result<JsonNode, JsonError> {
+        if depth > MAX_DEPTH {
+            return self.error("nesting too deep");
+        }
+        self.skip_whitespace();
+        let pos = self.pos();
+        let value = match self.peek() {
+            Some('{') => self.object(depth)?,
+            Some('[') => self.array(depth)?,
+            Some('"') => JsonValue::String(self.string()?),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            Some(c) if c.is_ascii_alphabetic() => self.literal()?,
+            Some(c) => return self.error(format!("unexpected character '{c}'")),
+            None => return self.error("unexpected end of file"),
+        };
+        Ok(JsonNode { value, pos })
+    }
+
+    fn object(&mut self, depth: usize) -> Result<JsonValue, JsonError> {
+        self.bump();
+        let mut members = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.bump();
+            return Ok(JsonValue::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('"') {
+                return self.error("expected a quoted object key");
+            }
+            let key_pos = self.pos();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            let value = self.value(depth + 1)?;
+            members.push(JsonMember {
+                key,
+                key_pos,
+                value,
+            });
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                }
+                Some('}') => {
+                    self.bump();
+                    return Ok(JsonValue::Object(members));
+                }
+                _ => return self.error("expected ',' or '}' after object member"),
+            }
+        }
+    }
+
+    fn array(&mut self, depth: usize) -> Result<JsonValue, JsonError> {
+        self.bump();
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.bump();
+            return Ok(JsonValue::Array(items));
+        }
+        loop {
+            items.push(self.value(depth + 1)?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                }
+                Some(']') => {
+                    self.bump();
+                    return Ok(JsonValue::Array(items));
+                }
+                _ => return self.error("expected ',' or ']' after array element"),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, JsonError> {
+        self.bump();
+        let mut out = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(out),
+                Some('\\') => match self.bump() {
+                    Some('n') => out.push('\n'),
+                    Some('t') => out.push('\t'),
+                    Some('r') => out.push('\r'),
+                    Some('b') => out.push('\u{8}'),
+                    Some('f') => out.push('\u{c}'),
+                    Some('u') => {
+                        let hex: String = (0..4).filter_map(|_| self.bump()).collect();
+                        let Some(c) = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32)
+                        else {
+                            // Surrogate pairs and bad escapes are kept verbatim;
+                            // no settings value depends on them.
+                            out.push_str("\\u");
+                            out.push_str(&hex);
+                            continue;
+                        };
+                        out.push(c);
+                    }
+                    Some(c @ ('"' | '\\' | '/')) => out.push(c),
+                    Some(c) => return self.error(format!("invalid escape '\\{c}'")),
+                    None => return self.error("unterminated string"),
+                },
+                Some('\n') | None => return self.error("unterminated string"),
+                Some(c) => out.push(c),
+            }
+        }
+    }
+
+    fn number(&mut self) -> JsonValue {
+        let mut text = String::new();
+        while let Some(c) = self
+            .peek()
+            .filter(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+        {
+            text.push(c);
+            self.bump();
+        }
+        JsonValue::Number(text)
+    }
+
+    fn literal(&mut self) -> Result<JsonValue, JsonError> {
+        let pos = self.pos();
+        let mut word = String::new();
+        while let Some(c) = self.peek().filter(char::is_ascii_alphabetic) {
+            word.push(c);
+            self.bump();
+        }
+        match word.as_str() {
+            "true" => Ok(JsonValue::Bool(true)),
+            "false" => Ok(JsonValue::Bool(false)),
+            "null" => Ok(JsonValue::Null),
+            _ => Err(JsonError {
+                message: format!("unexpected token '{word}'"),
+                pos,
+            }),
+        }
+    }
+}
+
+/// Parse a complete JSON document.
+pub(crate) fn parse_json(src: &str) -> Result<JsonNode, JsonError> {
+    let mut reader = Reader {
+        chars: src
+            .strip_prefix('\u{feff}')
+            .unwrap_or(src)
+            .chars()
+            .peekable(),
+        line: 1,
+        column: 1,
+    };
+    let root = reader.value(0)?;
+    reader.skip_whitespace();
+    if let Some(c) = reader.peek() {
+        return reader.error(format!("unexpected trailing character '{c}'"));
+    }
+    Ok(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions_of_keys_and_values() {
+        let root = parse_json("{\n  \"allow\": [\n    \"Bash(ls)\"\n  ]\n}").unwrap();
+        let members = root.as_object().unwrap();
+        assert_eq!(members[0].key_pos, Position { line: 2, column: 3 });
+        let item = &root.get("allow").unwrap().as_array().unwrap()[0];
+        assert_eq!(item.as_str(), Some("Bash(ls)"));
+        assert_eq!(item.pos, Position { line: 3, column: 5 });
+    }
+
+    #[test]
+    fn test_scalars_and_escapes() {
+        let root = parse_json(r#"{"a": true, "b": null, "c": -1.5e3, "d": "x\"é"}"#).unwrap();
+        assert_eq!(root.get("a").unwrap().value, JsonValue::Bool(true));
+        assert_eq!(root.get("b").unwrap().value, JsonValue::Null);
+        assert_eq!(
+            root.get("c").unwrap().value,
+            JsonValue::Number("-1.5e3".into())
+        );
+        assert_eq!(root.get("d").unwrap().as_str(), Some("x\"é"));
+    }
+
+    #[test]
+    fn test_error_position() {
+        let err = parse_json("{\n  \"a\": 1,\n  \"b\" 2\n}").unwrap_err();
+        assert_eq!(err.pos, Position { line: 3, column: 7 });
+        assert!(err.message.contains("expected ':'"));
+    }
+
+    #[test]
+    fn test_trailing_comma_rejected() {
+        assert!(parse_json("[1, 2,]").is_err());
+        assert!(parse_json("{} {}").is_err());
+    }
+
+    #[test]
+    fn test_depth_limit() {
+        let deep = "[".repeat(MAX_DEPTH + 2);
+        assert!(parse_json(&deep).unwrap_err().message.contains("too deep"));
+    }
+}
@@ -1,4 +1,6 @@
-pub(crate) mod types;
+pub(crate) mod frontmatter;
+pub(crate) mod json;
+pub mod types;
 
 use comrak::nodes::NodeValue;
 use comrak::{parse_document, Arena, Options};
@@ -6,7 +8,10 @@ use regex::Regex;
 use std::path::Path;
 use std::sync::{Arc, LazyLock};
 
-use types::{Directive, FileRef, InlineSuppress, ParsedFile, Section, SuppressKind, Table};
+use types::{
+    Directive, FileRef, FileRefKind, InlineSuppress, ParsedFile, Section, SettingsFile,
+    SuppressKind, Table,
+};
 
 /// Build a pre-computed mask of which lines are inside fenced code blocks
 /// or YAML frontmatter. Fence markers and frontmatter delimiters are marked
@@ -105,14 +110,34 @@ pub(crate) fn is_directive_line(line: &str) -> bool {
     true
 }
 
+/// Returns `true` if byte offset `pos` falls inside an inline backtick span.
+/// Used by checkers that scan prose lines and need to skip inline code.
+/// Operates on bytes to avoid panicking when `pos` falls inside a multi-byte
+/// UTF-8 character.
+#[must_use]
+pub(crate) fn inside_inline_code(line: &str, pos: usize) -> bool {
+    let bytes = line.as_bytes();
+    let end = pos.min(bytes.len());
+    bytes[..end].iter().filter(|&&b| b == b'`').count() % 2 == 1
+}
+
 static FILE_REF_BACKTICK: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"`([^`]+\.md)`").unwrap());
 static FILE_REF_LINK: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"\[([^\]]*)\]\(([^)]+\.md)\)").unwrap());
 static FILE_REF_BARE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"(?:^|[\s,|])([a-zA-Z0-9_/.:-]+\.md)(?:[\s,|]|$)").unwrap());
+/// Claude-style `@path/to/file` import. Must start a word so email addresses
+/// and `npm@latest`-style tokens are not captured.
+static FILE_REF_IMPORT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:^|[\s(\[])@((?:~/|\.{1,2}/|/)?[\w.-]+(?:/[\w.-]+)*)").unwrap()
+});
+/// Imports must name a file with an extension; this filters out `@username`
+/// mentions and scoped package names like `@types/node`.
+static IMPORT_EXTENSION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\.[A-Za-z][A-Za-z0-9]*$").unwrap());
 
 static SUPPRESS_COMMENT: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"<!--\s*spectralint-(disable|enable|disable-next-line)(?:\s+([\w-]+))?\s*-->")
+    Regex::new(r"<!--\s*spectralint-(disable|enable|disable-next-line)(?:\s+([\w:-]+))?\s*-->")
         .unwrap()
 });
 
@@ -190,12 +215,50 @@ pub(crate) fn is_mediawiki_content(content: &str) -> bool {
 }
 
 /// Maximum file size (10 MiB) that the parser will accept.
-const MAX_FILE_SIZE: u64 = 10 * 1024 * 1024;
+pub(crate) const MAX_FILE_SIZE: u64 = 10 * 1024 * 1024;
 
 /// Maximum AST traversal depth to prevent stack overflow on crafted inputs.
 const MAX_AST_DEPTH: usize = 128;
 
+/// Legacy single-file rule formats that are usually loose prose, not markdown.
+pub(crate) const PLAIN_TEXT_FILE_NAMES: &[&str] =
+    &[".cursorrules", ".windsurfrules", ".clinerules"];
+
+/// How a file's text is interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ParseMode {
+    Markdown,
+    /// No markdown structure: sections and tables are left empty, while
+    /// references, directives and suppress comments are still extracted.
+    PlainText,
+}
+
+impl ParseMode {
+    /// Plain text for the legacy rule file names, markdown otherwise.
+    pub(crate) fn for_path(path: &Path) -> Self {
+        let plain = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| PLAIN_TEXT_FILE_NAMES.contains(&n));
+        if plain {
+            ParseMode::PlainText
+        } else {
+            ParseMode::Markdown
+        }
+    }
+}
+
 pub(crate) fn parse_file(path: &Path) -> anyhow::Result<ParsedFile> {
+    parse_file_as(path, ParseMode::for_path(path))
+}
+
+pub(crate) fn parse_file_as(path: &Path, mode: ParseMode) -> anyhow::Result<ParsedFile> {
+    let content = read_source(path)?;
+    Ok(parse_source(path, &content, mode))
+}
+
+/// Read an instruction file, refusing files over [`MAX_FILE_SIZE`].
+pub(crate) fn read_source(path: &Path) -> anyhow::Result<String> {
     let meta = std::fs::metadata(path)?;
     if meta.len() > MAX_FILE_SIZE {
         anyhow::bail!(
@@ -204,14 +267,19 @@ pub(crate) fn parse_file(path: &Path) -> anyhow::Result<ParsedFile> {
             meta.len() as f64 / (1024.0 * 1024.0)
         );
     }
-    let content = std::fs::read_to_string(path)?;
+    Ok(std::fs::read_to_string(path)?)
+}
+
+/// Parse already-read file content. `path` is recorded on the result and
+/// used as the source of extracted references.
+pub(crate) fn parse_source(path: &Path, content: &str, mode: ParseMode) -> ParsedFile {
     let raw_lines: Vec<String> = content.lines().map(String::from).collect();
 
     // Skip MediaWiki markup files (not standard markdown — causes false positives)
-    if is_mediawiki_content(&content) {
+    if is_mediawiki_content(content) {
         tracing::info!("Skipping MediaWiki markup: {}", path.display());
         let in_code_block = build_code_block_mask(&raw_lines);
-        return Ok(ParsedFile {
+        return ParsedFile {
             path: Arc::new(path.to_path_buf()),
             sections: vec![],
             tables: vec![],
@@ -220,14 +288,36 @@ pub(crate) fn parse_file(path: &Path) -> anyhow::Result<ParsedFile> {
             suppress_comments: vec![],
             raw_lines,
             in_code_block,
-        });
+            frontmatter: None,
+        };
+    }
+
+    if mode == ParseMode::PlainText {
+        let mut file_refs = Vec::new();
+        let mut directives = Vec::new();
+        let mut suppress_comments = Vec::new();
+        extract_file_refs(&raw_lines, path, &mut file_refs);
+        extract_directives(&raw_lines, &mut directives);
+        extract_suppress_comments(&raw_lines, &mut suppress_comments);
+        let in_code_block = build_code_block_mask(&raw_lines);
+        return ParsedFile {
+            path: Arc::new(path.to_path_buf()),
+            sections: vec![],
+            tables: vec![],
+            file_refs,
+            directives,
+            suppress_comments,
+            frontmatter: frontmatter::parse_frontmatter(&raw_lines),
+            raw_lines,
+            in_code_block,
+        };
     }
 
     let arena = Arena::new();
     let mut options = Options::default();
     options.extension.table = true;
     options.extension.front_matter_delimiter = Some("---".to_owned());
-    let root = parse_document(&arena, &content, &options);
+    let root = parse_document(&arena, content, &options);
 
     let mut sections = Vec::new();
     let mut tables = Vec::new();
@@ -244,15 +334,32 @@ pub(crate) fn parse_file(path: &Path) -> anyhow::Result<ParsedFile> {
 
     let in_code_block = build_code_block_mask(&raw_lines);
 
-    Ok(ParsedFile {
+    ParsedFile {
         path: Arc::new(path.to_path_buf()),
         sections,
         tables,
         file_refs,
         directives,
         suppress_comments,
+        frontmatter: frontmatter::parse_frontmatter(&raw_lines),
         raw_lines,
         in_code_block,
+    }
+}
+
+pub(crate) fn parse_settings_file(path: &Path) -> anyhow::Result<SettingsFile> {
+    let meta = std::fs::metadata(path)?;
+    if meta.len() > MAX_FILE_SIZE {
+        anyhow::bail!(
+            "{}: file too large ({:.1} MiB, limit is 10 MiB)",
+            path.display(),
+            meta.len() as f64 / (1024.0 * 1024.0)
+        );
+    }
+    let content = std::fs::read_to_string(path)?;
+    Ok(SettingsFile {
+        path: Arc::new(path.to_path_buf()),
+        root: json::parse_json(&content),
     })
 }
 
@@ -363,11 +470,15 @@ fn is_url(path: &str) -> bool {
 
 fn extract_file_refs(lines: &[String], source_path: &Path, refs: &mut Vec<FileRef>) {
     let push_unique = |refs: &mut Vec<FileRef>, path: String, line_num: usize| {
-        if !refs.iter().any(|r| r.path == path && r.line == line_num) {
+        if !refs
+            .iter()
+            .any(|r| r.path == path && r.line == line_num && r.kind == FileRefKind::Mention)
+        {
             refs.push(FileRef {
                 path,
                 line: line_num,
                 source_file: source_path.to_path_buf(),
+                kind: FileRefKind::Mention,
             });
         }
     };
@@ -396,6 +507,41 @@ fn extract_file_refs(lines: &[String], source_path: &Path, refs: &mut Vec<FileRe
             }
         }
     }
+
+    refs.extend(extract_import_refs(lines, source_path));
+}
+
+/// Extract `@path` imports from non-code lines. Paths are stored without the
+/// leading `@`. Exposed separately so the import graph can follow imports
+/// into files that were not part of the scan.
+pub(crate) fn extract_import_refs(lines: &[String], source_path: &Path) -> Vec<FileRef> {
+    let mut imports: Vec<FileRef> = Vec::new();
+
+    for (i, line) in non_code_lines(lines) {
+        let line_num = i + 1;
+
+        for cap in FILE_REF_IMPORT.captures_iter(line) {
+            let m = cap.get(1).unwrap();
+            // Position of the `@` itself, for the inline-code check.
+            if inside_inline_code(line, m.start() - 1) {
+                continue;
+            }
+            let path = m.as_str().trim_end_matches(['.', ',', ';', ':']);
+            if !IMPORT_EXTENSION.is_match(path) {
+                continue;
+            }
+            if !imports.iter().any(|r| r.path == path && r.line == line_num) {
+                imports.push(FileRef {
+                    path: path.to_string(),
+                    line: line_num,
+                    source_file: source_path.to_path_buf(),
+                    kind: FileRefKind::Import,
+                });
+            }
+        }
+    }
+
+    imports
 }
 
 fn extract_directives(lines: &[String], directives: &mut Vec<Directive>) {
@@ -419,6 +565,7 @@ fn extract_directives(lines: &[String], directives: &mut Vec<Directive>) {
             }
             directives.push(Directive {
                 line: i + 1,
+                column: line[..m.start()].chars().count() + 1,
                 pattern_matched: m.as_str().to_string(),
             });
         }
@@ -456,6 +603,48 @@ mod tests {
         parse_file(f.path()).unwrap()
     }
 
+    #[test]
+    fn test_inside_inline_code() {
+        assert!(!inside_inline_code("hello world", 3));
+        assert!(inside_inline_code("use `foo` here", 5)); // inside backticks
+        assert!(!inside_inline_code("use `foo` here", 10)); // after backticks
+        assert!(inside_inline_code("`all code`", 3)); // inside
+        assert!(!inside_inline_code("`all code`", 0)); // before first backtick
+    }
+
+    #[test]
+    fn test_plain_text_mode_skips_markdown_structure() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".cursorrules");
+        std::fs::write(
+            &path,
+            "# Rules\n\n| a | b |\n|---|---|\n| 1 | 2 |\n\nYou must read `docs/setup.md` first.\n",
+        )
+        .unwrap();
+        assert_eq!(ParseMode::for_path(&path), ParseMode::PlainText);
+        let parsed = parse_file(&path).unwrap();
+        assert!(parsed.sections.is_empty());
+        assert!(parsed.tables.is_empty());
+        assert_eq!(parsed.file_refs.len(), 1);
+        assert_eq!(parsed.raw_lines.len(), 7);
+
+        let markdown = parse_file_as(&path, ParseMode::Markdown).unwrap();
+        assert_eq!(markdown.sections.len(), 1);
+        assert_eq!(markdown.tables.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_mode_for_path() {
+        assert_eq!(
+            ParseMode::for_path(Path::new("/p/.windsurfrules")),
+            ParseMode::PlainText
+        );
+        assert_eq!(
+            ParseMode::for_path(Path::new("/p/CLAUDE.md")),
+            ParseMode::Markdown
+        );
+    }
+
     #[test]
     fn test_sections() {
         let parsed = parse_str("# Hello\n\nSome text\n\n## World\n\nMore text\n");
@@ -642,6 +831,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_import_ref_detected() {
+        let parsed = parse_str("See @docs/guide.md and @./local.md for details.\n");
+        let imports: Vec<_> = parsed
+            .file_refs
+            .iter()
+            .filter(|r| r.kind == FileRefKind::Import)
+            .map(|r| r.path.as_str())
+            .collect();
+        assert_eq!(imports, vec!["docs/guide.md", "./local.md"]);
+    }
+
+    #[test]
+    fn test_import_ref_home_and_trailing_punctuation() {
+        let parsed = parse_str("- @~/.claude/my-instructions.md.\n");
+        let import = parsed
+            .file_refs
+            .iter()
+            .find(|r| r.kind == FileRefKind::Import)
+            .unwrap();
+        assert_eq!(import.path, "~/.claude/my-instructions.md");
+    }
+
+    #[test]
+    fn test_import_ref_non_file_mentions_skipped() {
+        let parsed =
+            parse_str("Ping @alice, install @types/node, mail me@example.com, run `@foo.md`.\n");
+        assert!(
+            parsed
+                .file_refs
+                .iter()
+                .all(|r| r.kind != FileRefKind::Import),
+            "Mentions, scoped packages, emails and inline code are not imports"
+        );
+    }
+
+    #[test]
+    fn test_import_ref_in_code_block_skipped() {
+        let parsed = parse_str("```\n@docs/guide.md\n```\n");
+        assert!(parsed.file_refs.is_empty());
+    }
+
     #[test]
     fn test_link_ref_detected() {
         let parsed = parse_str("See [guide](docs/guide.md) for details.\n");
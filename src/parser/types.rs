@@ -1,7 +1,11 @@
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use std::sync::Arc;
 
-#[derive(Debug, Clone)]
+use super::frontmatter::{Frontmatter, FrontmatterEntry};
+use super::json::{JsonError, JsonNode};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParsedFile {
     pub path: Arc<PathBuf>,
     pub sections: Vec<Section>,
@@ -13,9 +17,20 @@ pub struct ParsedFile {
     /// Pre-computed code block mask: `true` if line is inside a fenced code block.
     /// Fence markers themselves are marked `true` (excluded from non-code iteration).
     pub in_code_block: Vec<bool>,
+    /// Leading YAML frontmatter, if the file opens with a closed `---` block.
+    pub(crate) frontmatter: Option<Frontmatter>,
 }
 
+/// A Claude Code settings file (`.claude/settings.json` or
+/// `.claude/settings.local.json`). Malformed JSON is kept as the parse error
+/// so it can be reported like any other finding.
 #[derive(Debug, Clone)]
+pub(crate) struct SettingsFile {
+    pub path: Arc<PathBuf>,
+    pub root: Result<JsonNode, JsonError>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Section {
     pub level: u8,
     pub title: String,
@@ -23,7 +38,7 @@ pub struct Section {
     pub end_line: usize,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Table {
     pub headers: Vec<String>,
     pub rows: Vec<Vec<String>>,
@@ -31,27 +46,43 @@ pub struct Table {
     pub parent_section: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileRef {
     pub path: String,
     pub line: usize,
     pub source_file: PathBuf,
+    pub kind: FileRefKind,
 }
 
-#[derive(Debug, Clone)]
+/// How a file reference was written in the source document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FileRefKind {
+    /// A backtick, markdown link, or bare `.md` mention. Resolved relative to
+    /// the source file first, then the project root.
+    Mention,
+    /// A Claude-style `@path` import whose contents are inlined into the
+    /// importing file. `path` is stored without the leading `@` and is
+    /// resolved relative to the importing file only.
+    Import,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Directive {
     pub line: usize,
+    /// 1-based character column where `pattern_matched` starts.
+    #[serde(default)]
+    pub column: usize,
     pub pattern_matched: String,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SuppressKind {
     Disable,
     Enable,
     DisableNextLine,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InlineSuppress {
     pub line: usize,
     pub kind: SuppressKind,
@@ -59,6 +90,11 @@ pub struct InlineSuppress {
 }
 
 impl ParsedFile {
+    /// Frontmatter entry for `key`, if the file has frontmatter that sets it.
+    pub(crate) fn frontmatter_get(&self, key: &str) -> Option<&FrontmatterEntry> {
+        self.frontmatter.as_ref()?.get(key)
+    }
+
     /// Whether line `i` is inside a fenced code block.
     /// Returns `false` when the mask is empty (test convenience).
     pub fn is_code(&self, i: usize) -> bool {
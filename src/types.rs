@@ -123,6 +123,14 @@ pub enum Category {
     StaleFileTree,
     CommandValidation,
     TokenBudget,
+    ImportChain,
+    CursorRule,
+    ClaudeSettings,
+    SubagentDefinition,
+    SlashCommand,
+    SkillPackage,
+    CopilotInstructions,
+    MalformedFrontmatter,
     InvalidSuppression,
     UnusedSuppression,
     CustomPattern(Box<str>),
@@ -203,11 +211,41 @@ impl Category {
             Category::StaleFileTree => "stale-file-tree",
             Category::CommandValidation => "command-validation",
             Category::TokenBudget => "token-budget",
+            Category::ImportChain => "import-chain",
+            Category::CursorRule => "cursor-rule",
+            Category::ClaudeSettings => "claude-settings",
+            Category::SubagentDefinition => "subagent-definition",
+            Category::SlashCommand => "slash-command",
+            Category::SkillPackage => "skill-package",
+            Category::CopilotInstructions => "copilot-instructions",
+            Category::MalformedFrontmatter => "malformed-frontmatter",
             Category::InvalidSuppression => "invalid-suppression",
             Category::UnusedSuppression => "unused-suppression",
             Category::CustomPattern(name) => name,
         }
     }
+
+    /// True for checks about markdown structure (headings, sections, fences,
+    /// tables). These are skipped for files parsed as plain text.
+    #[must_use]
+    pub fn is_structural(&self) -> bool {
+        matches!(
+            self,
+            Category::HeadingHierarchy
+                | Category::MissingEssentialSections
+                | Category::DuplicateSection
+                | Category::SectionLengthImbalance
+                | Category::UnclosedFence
+                | Category::UntaggedCodeBlock
+                | Category::BrokenAnchorLink
+                | Category::BrokenTable
+                | Category::OrphanedSection
+                | Category::ExcessiveNesting
+                | Category::EmptyCodeBlock
+                | Category::ImperativeHeading
+                | Category::EmptyHeading
+        )
+    }
 }
 
 impl Serialize for Category {
@@ -305,6 +343,14 @@ impl std::str::FromStr for Category {
             "stale-file-tree" => Ok(Category::StaleFileTree),
             "command-validation" => Ok(Category::CommandValidation),
             "token-budget" => Ok(Category::TokenBudget),
+            "import-chain" => Ok(Category::ImportChain),
+            "cursor-rule" => Ok(Category::CursorRule),
+            "claude-settings" => Ok(Category::ClaudeSettings),
+            "subagent-definition" => Ok(Category::SubagentDefinition),
+            "slash-command" => Ok(Category::SlashCommand),
+            "skill-package" => Ok(Category::SkillPackage),
+            "copilot-instructions" => Ok(Category::CopilotInstructions),
+            "malformed-frontmatter" => Ok(Category::MalformedFrontmatter),
             "invalid-suppression" => Ok(Category::InvalidSuppression),
             "unused-suppression" => Ok(Category::UnusedSuppression),
             other => {
@@ -330,10 +376,14 @@ pub struct Diagnostic {
     #[serde(serialize_with = "serialize_arc_pathbuf")]
     pub file: Arc<PathBuf>,
     pub line: usize,
+    /// 1-based character column where the finding starts. `None` when the
+    /// diagnostic covers whole lines.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub column: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub end_line: Option<usize>,
+    /// 1-based character column just past the end of the finding, on
+    /// `end_line` if set and `line` otherwise.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub end_column: Option<usize>,
     pub severity: Severity,
@@ -343,6 +393,20 @@ pub struct Diagnostic {
     pub suggestion: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fix: Option<Box<Fix>>,
+    /// Other locations that are part of the same finding, e.g. the other
+    /// side of a cross-file inconsistency.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<RelatedLocation>,
+}
+
+/// A secondary location of a diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelatedLocation {
+    #[serde(serialize_with = "serialize_arc_pathbuf")]
+    pub file: Arc<PathBuf>,
+    pub line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<usize>,
 }
 
 #[derive(Debug, Default)]
@@ -412,6 +476,7 @@ mod tests {
             message: "test".to_string(),
             suggestion: None,
             fix: None,
+            related: Vec::new(),
         }
     }
 
@@ -647,6 +712,23 @@ mod tests {
             "command-validation"
         );
         assert_eq!(Category::TokenBudget.to_string(), "token-budget");
+        assert_eq!(Category::ImportChain.to_string(), "import-chain");
+        assert_eq!(Category::CursorRule.to_string(), "cursor-rule");
+        assert_eq!(Category::ClaudeSettings.to_string(), "claude-settings");
+        assert_eq!(
+            Category::SubagentDefinition.to_string(),
+            "subagent-definition"
+        );
+        assert_eq!(Category::SlashCommand.to_string(), "slash-command");
+        assert_eq!(Category::SkillPackage.to_string(), "skill-package");
+        assert_eq!(
+            Category::CopilotInstructions.to_string(),
+            "copilot-instructions"
+        );
+        assert_eq!(
+            Category::MalformedFrontmatter.to_string(),
+            "malformed-frontmatter"
+        );
         assert_eq!(
             Category::InvalidSuppression.to_string(),
             "invalid-suppression"
@@ -726,6 +808,7 @@ mod tests {
                 message: "msg1".to_string(),
                 suggestion: None,
                 fix: None,
+                related: Vec::new(),
             },
             Diagnostic {
                 file: Arc::new(PathBuf::from("a.md")),
@@ -738,6 +821,7 @@ mod tests {
                 message: "msg2".to_string(),
                 suggestion: None,
                 fix: None,
+                related: Vec::new(),
             },
             Diagnostic {
                 file: Arc::new(PathBuf::from("a.md")),
@@ -750,6 +834,7 @@ mod tests {
                 message: "msg3".to_string(),
                 suggestion: None,
                 fix: None,
+                related: Vec::new(),
             },
         ];
 
@@ -777,6 +862,7 @@ mod tests {
                 message: "first".to_string(),
                 suggestion: None,
                 fix: None,
+                related: Vec::new(),
             },
             Diagnostic {
                 file: Arc::new(PathBuf::from("a.md")),
@@ -789,6 +875,7 @@ mod tests {
                 message: "first".to_string(),
                 suggestion: None,
                 fix: None,
+                related: Vec::new(),
             },
             Diagnostic {
                 file: Arc::new(PathBuf::from("a.md")),
@@ -801,6 +888,7 @@ mod tests {
                 message: "different rule same line".to_string(),
                 suggestion: None,
                 fix: None,
+                related: Vec::new(),
             },
             Diagnostic {
                 file: Arc::new(PathBuf::from("a.md")),
@@ -813,6 +901,7 @@ mod tests {
                 message: "different line".to_string(),
                 suggestion: None,
                 fix: None,
+                related: Vec::new(),
             },
         ];
 
@@ -913,6 +1002,14 @@ mod tests {
             Category::StaleFileTree,
             Category::CommandValidation,
             Category::TokenBudget,
+            Category::ImportChain,
+            Category::CursorRule,
+            Category::ClaudeSettings,
+            Category::SubagentDefinition,
+            Category::SlashCommand,
+            Category::SkillPackage,
+            Category::CopilotInstructions,
+            Category::MalformedFrontmatter,
             Category::InvalidSuppression,
             Category::UnusedSuppression,
         ];
@@ -1002,6 +1099,7 @@ mod tests {
             message: "test".to_string(),
             suggestion: None,
             fix: None,
+            related: Vec::new(),
         };
         let json = serde_json::to_value(&d).unwrap();
         assert!(
@@ -1044,6 +1142,7 @@ mod tests {
                     new_text: String::new(),
                 }],
             })),
+            related: Vec::new(),
         };
         let json = serde_json::to_value(&d).unwrap();
         let obj = json.as_object().unwrap();
@@ -3611,3 +3611,562 @@ fn json_output_column_field_omitted_for_dead_refs() {
         );
     }
 }
+
+#[test]
+fn import_chain_reports_missing_and_circular_imports() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::create_dir_all(root.join("docs")).unwrap();
+    fs::write(
+        root.join("CLAUDE.md"),
+        "# Project\n\nSee @docs/rules.md and @docs/missing.md for details.\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("docs/rules.md"),
+        "# Rules\n\nAlso @../CLAUDE.md\n",
+    )
+    .unwrap();
+
+    let parsed = json_output(&["check", &root.display().to_string(), "--format", "json"]);
+    let diagnostics = parsed["diagnostics"].as_array().unwrap();
+
+    assert!(diagnostics.iter().any(|d| {
+        d["category"] == "dead-reference"
+            && d["message"]
+                .as_str()
+                .unwrap()
+                .contains("Import \"@docs/missing.md\" does not exist")
+    }));
+    assert!(diagnostics.iter().any(|d| {
+        d["category"] == "import-chain"
+            && d["message"]
+                .as_str()
+                .unwrap()
+                .contains("CLAUDE.md → rules.md → CLAUDE.md")
+    }));
+}
+
+#[test]
+fn import_chain_cache_tracks_unscanned_imports() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::create_dir_all(root.join("docs")).unwrap();
+    fs::write(root.join("CLAUDE.md"), "# Project\n\nSee @docs/rules.md\n").unwrap();
+    fs::write(
+        root.join("docs/rules.md"),
+        "# Rules\n\nAlso @../CLAUDE.md\n",
+    )
+    .unwrap();
+
+    let has_cycle = || {
+        let parsed = json_output(&["check", &root.display().to_string(), "--format", "json"]);
+        parsed["diagnostics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|d| d["category"] == "import-chain")
+    };
+    assert!(has_cycle());
+    assert!(root.join(".spectralint-cache.json").exists());
+
+    fs::write(root.join("docs/rules.md"), "# Rules\n\nNo imports.\n").unwrap();
+    assert!(
+        !has_cycle(),
+        "cached cycle should be dropped after the edit"
+    );
+}
+
+fn git(root: &std::path::Path, args: &[&str]) {
+    let status = std::process::Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success(), "git {args:?} failed");
+}
+
+fn dead_reference_lines(args: &[&str]) -> Vec<u64> {
+    let parsed = json_output(args);
+    parsed["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|d| d["category"] == "dead-reference")
+        .map(|d| d["line"].as_u64().unwrap())
+        .collect()
+}
+
+#[test]
+fn since_reports_only_changed_lines() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::write(root.join("CLAUDE.md"), "# Project\n\nRead `docs/old.md`.\n").unwrap();
+    git(root, &["init", "-q"]);
+    git(root, &["add", "."]);
+    git(root, &["commit", "-q", "-m", "init"]);
+    fs::write(
+        root.join("CLAUDE.md"),
+        "# Project\n\nRead `docs/old.md`.\n\nRead `docs/new.md`.\n",
+    )
+    .unwrap();
+
+    let path = root.display().to_string();
+    let all = dead_reference_lines(&["check", &path, "--format", "json", "--no-cache"]);
+    assert_eq!(all, vec![3, 5]);
+    let changed = dead_reference_lines(&[
+        "check",
+        &path,
+        "--format",
+        "json",
+        "--no-cache",
+        "--since",
+        "HEAD",
+    ]);
+    assert_eq!(changed, vec![5]);
+}
+
+#[test]
+fn since_ignores_user_diff_prefix_settings() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::write(root.join("CLAUDE.md"), "# Project\n\nRead `docs/old.md`.\n").unwrap();
+    git(root, &["init", "-q"]);
+    git(root, &["config", "diff.noprefix", "true"]);
+    git(root, &["add", "."]);
+    git(root, &["commit", "-q", "-m", "init"]);
+    fs::write(
+        root.join("CLAUDE.md"),
+        "# Project\n\nRead `docs/old.md`.\n\nRead `docs/new.md`.\n",
+    )
+    .unwrap();
+
+    let changed = dead_reference_lines(&[
+        "check",
+        &root.display().to_string(),
+        "--format",
+        "json",
+        "--no-cache",
+        "--since",
+        "HEAD",
+    ]);
+    assert_eq!(changed, vec![5]);
+}
+
+#[test]
+fn since_treats_untracked_files_as_changed() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::write(root.join("CLAUDE.md"), "# Project\n\nRead `docs/old.md`.\n").unwrap();
+    git(root, &["init", "-q"]);
+    git(root, &["add", "."]);
+    git(root, &["commit", "-q", "-m", "init"]);
+    fs::write(root.join("AGENTS.md"), "# Agents\n\nRead `docs/new.md`.\n").unwrap();
+
+    let parsed = json_output(&[
+        "check",
+        &root.display().to_string(),
+        "--format",
+        "json",
+        "--no-cache",
+        "--since",
+        "HEAD",
+    ]);
+    let files: Vec<&str> = parsed["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|d| d["category"] == "dead-reference")
+        .map(|d| d["file"].as_str().unwrap())
+        .collect();
+    assert_eq!(files.len(), 1, "{files:?}");
+    assert!(files[0].ends_with("AGENTS.md"));
+}
+
+#[test]
+fn since_fails_without_merge_base() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::write(root.join("CLAUDE.md"), "# Project\n").unwrap();
+    git(root, &["init", "-q"]);
+    git(root, &["add", "."]);
+    git(root, &["commit", "-q", "-m", "init"]);
+
+    let output = cmd()
+        .args([
+            "check",
+            &root.display().to_string(),
+            "--no-cache",
+            "--since",
+            "no-such-ref",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("no-such-ref"), "{stderr}");
+}
+
+#[test]
+fn staged_checks_index_content() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::write(root.join("CLAUDE.md"), "# Project\n\nSome notes.\n").unwrap();
+    git(root, &["init", "-q"]);
+    git(root, &["add", "."]);
+    git(root, &["commit", "-q", "-m", "init"]);
+    fs::write(
+        root.join("CLAUDE.md"),
+        "# Project\n\nSome notes.\n\nRead `docs/staged.md`.\n",
+    )
+    .unwrap();
+    git(root, &["add", "CLAUDE.md"]);
+    // Unstaged edit on top: must not be seen by --staged.
+    fs::write(
+        root.join("CLAUDE.md"),
+        "# Project\n\nRead `docs/unstaged.md`.\n\nRead `docs/staged.md`.\n",
+    )
+    .unwrap();
+
+    let parsed = json_output(&[
+        "check",
+        &root.display().to_string(),
+        "--format",
+        "json",
+        "--no-cache",
+        "--staged",
+    ]);
+    let messages: Vec<&str> = parsed["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|d| d["category"] == "dead-reference")
+        .map(|d| d["message"].as_str().unwrap())
+        .collect();
+    assert_eq!(messages.len(), 1, "{messages:?}");
+    assert!(messages[0].contains("docs/staged.md"));
+}
+
+#[test]
+fn gitignored_local_settings_are_checked() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::create_dir_all(root.join(".claude")).unwrap();
+    fs::write(root.join(".gitignore"), ".claude/settings.local.json\n").unwrap();
+    fs::write(root.join("CLAUDE.md"), "# Project\n").unwrap();
+    fs::write(
+        root.join(".claude/settings.local.json"),
+        "{\"permissions\": {\"allow\": [\"Bash(npm run test\"]}}",
+    )
+    .unwrap();
+
+    let parsed = json_output(&[
+        "check",
+        &root.display().to_string(),
+        "--format",
+        "json",
+        "--no-cache",
+    ]);
+    let files: Vec<&str> = parsed["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|d| d["category"] == "claude-settings")
+        .map(|d| d["file"].as_str().unwrap())
+        .collect();
+    assert!(!files.is_empty(), "{parsed}");
+    assert!(files.iter().all(|f| f.ends_with("settings.local.json")));
+}
+
+fn dead_reference_files(output: &std::process::Output) -> Vec<String> {
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    parsed["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|d| d["category"] == "dead-reference")
+        .map(|d| d["file"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn check_explicit_files_reports_only_those_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::write(root.join(".spectralintrc.toml"), "").unwrap();
+    fs::create_dir_all(root.join("docs")).unwrap();
+    fs::write(root.join("CLAUDE.md"), "See [a](./missing-a.md).\n").unwrap();
+    fs::write(root.join("AGENTS.md"), "See [b](./missing-b.md).\n").unwrap();
+    fs::write(root.join("docs/notes.md"), "See [c](./missing-c.md).\n").unwrap();
+
+    let output = cmd()
+        .current_dir(root)
+        .args([
+            "check",
+            "AGENTS.md",
+            "docs/notes.md",
+            "--format",
+            "json",
+            "--no-cache",
+        ])
+        .output()
+        .unwrap();
+    let mut files = dead_reference_files(&output);
+    files.sort();
+    // docs/notes.md is outside `include` but checked because it was named.
+    assert_eq!(files, vec!["AGENTS.md", "docs/notes.md"]);
+}
+
+#[test]
+fn check_explicit_file_under_nested_config_uses_outer_root() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::create_dir_all(root.join("pkg")).unwrap();
+    fs::write(root.join(".spectralintrc.toml"), "").unwrap();
+    fs::write(root.join("pkg/.spectralintrc.toml"), "").unwrap();
+    fs::write(root.join("README.md"), "# Readme\n").unwrap();
+    fs::write(
+        root.join("pkg/CLAUDE.md"),
+        "See [readme](../README.md) and [gone](./gone.md).\n",
+    )
+    .unwrap();
+
+    let output = cmd()
+        .current_dir(root)
+        .args(["check", "pkg/CLAUDE.md", "--format", "json", "--no-cache"])
+        .output()
+        .unwrap();
+    assert_eq!(dead_reference_files(&output), vec!["pkg/CLAUDE.md"]);
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let messages: Vec<&str> = parsed["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|d| d["category"] == "dead-reference")
+        .map(|d| d["message"].as_str().unwrap())
+        .collect();
+    assert!(messages[0].contains("gone.md"), "{messages:?}");
+}
+
+#[test]
+fn check_stdin_resolves_references_against_project_tree() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::write(root.join(".spectralintrc.toml"), "").unwrap();
+    fs::write(root.join("CLAUDE.md"), "See [gone](./gone.md).\n").unwrap();
+    fs::create_dir_all(root.join("docs")).unwrap();
+    fs::write(root.join("docs/guide.md"), "# Guide\n").unwrap();
+
+    let output = cmd()
+        .current_dir(root)
+        .args([
+            "check",
+            "--stdin",
+            "--stdin-filename",
+            "CLAUDE.md",
+            "--format",
+            "json",
+            "--no-cache",
+        ])
+        .write_stdin("See [guide](./docs/guide.md) and [old](./docs/old.md).\n")
+        .output()
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let messages: Vec<&str> = parsed["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|d| d["category"] == "dead-reference")
+        .map(|d| d["message"].as_str().unwrap())
+        .collect();
+    assert_eq!(messages.len(), 1, "{messages:?}");
+    assert!(messages[0].contains("docs/old.md"));
+}
+
+#[test]
+fn check_stdin_requires_filename() {
+    cmd()
+        .args(["check", "--stdin"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--stdin-filename"));
+}
+
+#[test]
+fn config_check_reports_unknown_keys_with_suggestions() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join(".spectralintrc.toml"),
+        "[checkers.file_size]\nmax_line = 500\n",
+    )
+    .unwrap();
+
+    cmd()
+        .args(["config", "check", &dir.path().display().to_string()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            ".spectralintrc.toml:2: Unknown key `checkers.file_size.max_line` (did you mean `max_lines`?)",
+        ));
+}
+
+#[test]
+fn config_check_passes_valid_config() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join(".spectralintrc.toml"),
+        "[checkers.file_size]\nmax_lines = 500\n",
+    )
+    .unwrap();
+
+    cmd()
+        .args(["config", "check", &dir.path().display().to_string()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No config problems found"));
+}
+
+#[test]
+fn config_schema_prints_json_schema() {
+    let output = cmd().args(["config", "schema"]).output().unwrap();
+    assert!(output.status.success());
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        schema["$schema"],
+        serde_json::json!("http://json-schema.org/draft-07/schema#")
+    );
+    assert!(
+        schema["definitions"]["checkers"]["properties"]["token_budget"]["properties"]
+            ["warn_tokens"]
+            .is_object()
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn check_runs_plugins_with_suppression_and_fix() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::write(
+        root.join(".spectralintrc.toml"),
+        "[[plugins]]\nname = \"security\"\ncommand = [\"sh\", \"tools/plugin.sh\"]\n",
+    )
+    .unwrap();
+    fs::create_dir_all(root.join("tools")).unwrap();
+    fs::write(
+        root.join("tools/plugin.sh"),
+        r#"cat > /dev/null
+echo '{"diagnostics": [
+  {"line": 3, "category": "secret-name", "message": "Names a secret",
+   "fix": {"description": "Redact", "replacements":
+     [{"line": 3, "start_col": 8, "end_col": 19, "new_text": "[redacted]"}]}},
+  {"line": 6, "category": "secret-name", "message": "Names a secret"}
+]}'
+"#,
+    )
+    .unwrap();
+    fs::write(
+        root.join("CLAUDE.md"),
+        "# Rules\n\nUse the staging key.\n\n\
+         <!-- spectralint-disable-next-line custom:secret-name -->\n\
+         Use the staging key again.\n",
+    )
+    .unwrap();
+
+    let output = cmd()
+        .current_dir(root)
+        .args(["check", "--format", "json", "--no-cache"])
+        .output()
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let found: Vec<(u64, &str)> = parsed["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| (d["line"].as_u64().unwrap(), d["category"].as_str().unwrap()))
+        .collect();
+    assert_eq!(found, vec![(3, "custom:secret-name")]);
+
+    cmd()
+        .current_dir(root)
+        .args(["check", "--fix", "--no-cache"])
+        .output()
+        .unwrap();
+    let fixed = fs::read_to_string(root.join("CLAUDE.md")).unwrap();
+    assert!(fixed.contains("Use the [redacted].\n"), "{fixed}");
+}
+
+#[test]
+fn check_timings_in_json_output() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("CLAUDE.md"), "# Rules\n\nRun the tests.\n").unwrap();
+
+    let output = cmd()
+        .current_dir(dir.path())
+        .args(["check", "--format", "json", "--no-cache", "--timings"])
+        .output()
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let timings = &parsed["timings"];
+    for phase in [
+        "scan_ms",
+        "parse_ms",
+        "checks_ms",
+        "suppression_ms",
+        "output_ms",
+    ] {
+        assert!(
+            timings[phase].as_f64().is_some(),
+            "missing {phase}: {timings}"
+        );
+    }
+    let checkers: Vec<&str> = timings["checkers"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["name"].as_str().unwrap())
+        .collect();
+    assert!(checkers.contains(&"dead-reference"), "{checkers:?}");
+}
+
+#[test]
+fn check_timings_in_text_output() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("CLAUDE.md"), "# Rules\n\nRun the tests.\n").unwrap();
+
+    cmd()
+        .current_dir(dir.path())
+        .args(["check", "--no-cache", "--timings"])
+        .assert()
+        .stdout(predicate::str::contains("Timings"))
+        .stdout(predicate::str::contains("naming-inconsistency"));
+}
+
+#[test]
+fn check_sarif_regions_carry_match_columns() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join("CLAUDE.md"),
+        "# Rules\n\nRead /home/alice/notes first.\n",
+    )
+    .unwrap();
+
+    let output = cmd()
+        .current_dir(dir.path())
+        .args(["check", "--format", "sarif", "--no-cache"])
+        .output()
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let results = parsed["runs"][0]["results"].as_array().unwrap();
+    let absolute_path = results
+        .iter()
+        .find(|r| r["ruleId"] == "absolute-path")
+        .expect("absolute-path result");
+    let region = &absolute_path["locations"][0]["physicalLocation"]["region"];
+    assert_eq!(region["startLine"], 3);
+    assert_eq!(region["startColumn"], 6);
+    assert_eq!(region["endColumn"], 17);
+}